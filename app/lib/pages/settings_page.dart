@@ -116,6 +116,37 @@ class _SettingsPageState extends ConsumerState<SettingsPage> {
             ),
           ),
 
+          const SizedBox(height: 32),
+
+          // Date Format Section
+          _SectionHeader(title: 'Date Format'),
+          const SizedBox(height: 12),
+          SegmentedButton<String>(
+            segments: kSupportedLocales.entries.map((entry) {
+              return ButtonSegment<String>(
+                value: entry.key,
+                label: Text(entry.value),
+              );
+            }).toList(),
+            selected: {settings.locale},
+            onSelectionChanged: (selection) {
+              ref.read(settingsProvider.notifier).setLocale(selection.first);
+            },
+          ),
+          const SizedBox(height: 8),
+          SwitchListTile(
+            contentPadding: EdgeInsets.zero,
+            title: const Text(
+              '12-hour clock',
+              style: TextStyle(color: AppTheme.beige, fontSize: 15),
+            ),
+            value: settings.twelveHour,
+            activeThumbColor: AppTheme.gold,
+            onChanged: (value) {
+              ref.read(settingsProvider.notifier).setTwelveHour(value);
+            },
+          ),
+
           const SizedBox(height: 48),
 
           // App Info
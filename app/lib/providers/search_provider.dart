@@ -1,4 +1,5 @@
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:app/providers/settings_provider.dart';
 import 'package:app/src/rust/api/simple.dart';
 
 /// Holds the current search state: query, selected country, and results.
@@ -53,9 +54,12 @@ class SearchNotifier extends Notifier<SearchState> {
     state = state.copyWith(isLoading: true, error: null);
 
     try {
+      final settings = ref.read(settingsProvider);
       final matches = await searchTeam(
         team: query,
         country: state.selectedCountry,
+        locale: settings.locale,
+        twelveHour: settings.twelveHour,
       );
       state = state.copyWith(results: matches, isLoading: false);
     } catch (e) {
@@ -4,22 +4,44 @@ import 'package:app/src/rust/api/simple.dart';
 
 const _kFavoriteTeamKey = 'favorite_team';
 const _kDefaultCountryKey = 'default_country';
+const _kLocaleKey = 'locale';
+const _kTwelveHourKey = 'twelve_hour';
 
-/// Holds user settings: favorite team and default country.
+/// Languages kickoff dates can be written in.
+const kSupportedLocales = {
+  'en': 'English',
+  'fr': 'Français',
+  'de': 'Deutsch',
+  'es': 'Español',
+};
+
+/// Holds user settings: favorite team, default country and how kickoff
+/// dates are written.
 class SettingsState {
   final String? favoriteTeam;
   final Country defaultCountry;
+  final String locale;
+  final bool twelveHour;
 
-  const SettingsState({this.favoriteTeam, this.defaultCountry = Country.uk});
+  const SettingsState({
+    this.favoriteTeam,
+    this.defaultCountry = Country.uk,
+    this.locale = 'en',
+    this.twelveHour = false,
+  });
 
   SettingsState copyWith({
     String? favoriteTeam,
     Country? defaultCountry,
+    String? locale,
+    bool? twelveHour,
     bool clearFavorite = false,
   }) {
     return SettingsState(
       favoriteTeam: clearFavorite ? null : (favoriteTeam ?? this.favoriteTeam),
       defaultCountry: defaultCountry ?? this.defaultCountry,
+      locale: locale ?? this.locale,
+      twelveHour: twelveHour ?? this.twelveHour,
     );
   }
 }
@@ -38,6 +60,8 @@ class SettingsNotifier extends Notifier<SettingsState> {
     state = SettingsState(
       favoriteTeam: team,
       defaultCountry: Country.values[countryIndex],
+      locale: prefs.getString(_kLocaleKey) ?? 'en',
+      twelveHour: prefs.getBool(_kTwelveHourKey) ?? false,
     );
   }
 
@@ -57,6 +81,18 @@ class SettingsNotifier extends Notifier<SettingsState> {
     await prefs.setInt(_kDefaultCountryKey, country.index);
     state = state.copyWith(defaultCountry: country);
   }
+
+  Future<void> setLocale(String locale) async {
+    final prefs = await SharedPreferences.getInstance();
+    await prefs.setString(_kLocaleKey, locale);
+    state = state.copyWith(locale: locale);
+  }
+
+  Future<void> setTwelveHour(bool twelveHour) async {
+    final prefs = await SharedPreferences.getInstance();
+    await prefs.setBool(_kTwelveHourKey, twelveHour);
+    state = state.copyWith(twelveHour: twelveHour);
+  }
 }
 
 final settingsProvider = NotifierProvider<SettingsNotifier, SettingsState>(
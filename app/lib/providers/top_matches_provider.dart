@@ -1,7 +1,11 @@
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:app/providers/settings_provider.dart';
 import 'package:app/src/rust/api/simple.dart';
 
 /// Fetches top matches from LiveSoccerTV via the Rust FFI bridge.
+/// Refetches when the date format settings change.
 final topMatchesProvider = FutureProvider<List<TopMatch>>((ref) async {
-  return await fetchTopMatches();
+  final locale = ref.watch(settingsProvider.select((s) => s.locale));
+  final twelveHour = ref.watch(settingsProvider.select((s) => s.twelveHour));
+  return await fetchTopMatches(locale: locale, twelveHour: twelveHour);
 });
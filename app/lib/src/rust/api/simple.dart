@@ -6,14 +6,28 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `kickoff_format`
+
+/// `locale` is a language tag such as "fr"; unknown tags fall back to English.
 Future<List<Match>> searchTeam({
   required String team,
   required Country country,
-}) =>
-    RustLib.instance.api.crateApiSimpleSearchTeam(team: team, country: country);
+  required String locale,
+  required bool twelveHour,
+}) => RustLib.instance.api.crateApiSimpleSearchTeam(
+  team: team,
+  country: country,
+  locale: locale,
+  twelveHour: twelveHour,
+);
 
-Future<List<TopMatch>> fetchTopMatches() =>
-    RustLib.instance.api.crateApiSimpleFetchTopMatches();
+Future<List<TopMatch>> fetchTopMatches({
+  required String locale,
+  required bool twelveHour,
+}) => RustLib.instance.api.crateApiSimpleFetchTopMatches(
+  locale: locale,
+  twelveHour: twelveHour,
+);
 
 enum Country { uk, us, fr }
 
@@ -22,6 +36,7 @@ class Match {
   final String competition;
   final String date;
   final String time;
+  final DateTime? kickoff;
   final List<String> channels;
 
   const Match({
@@ -29,6 +44,7 @@ class Match {
     required this.competition,
     required this.date,
     required this.time,
+    this.kickoff,
     required this.channels,
   });
 
@@ -38,6 +54,7 @@ class Match {
       competition.hashCode ^
       date.hashCode ^
       time.hashCode ^
+      kickoff.hashCode ^
       channels.hashCode;
 
   @override
@@ -49,25 +66,43 @@ class Match {
           competition == other.competition &&
           date == other.date &&
           time == other.time &&
+          kickoff == other.kickoff &&
           channels == other.channels;
 }
 
+enum MatchStatus { upcoming, live, finished, postponed }
+
 class TopMatch {
   final String teams;
   final String date;
   final String time;
+  final DateTime? kickoff;
   final String matchUrl;
+  final String? competition;
+  final List<String> channels;
+  final MatchStatus status;
 
   const TopMatch({
     required this.teams,
     required this.date,
     required this.time,
+    this.kickoff,
     required this.matchUrl,
+    this.competition,
+    required this.channels,
+    required this.status,
   });
 
   @override
   int get hashCode =>
-      teams.hashCode ^ date.hashCode ^ time.hashCode ^ matchUrl.hashCode;
+      teams.hashCode ^
+      date.hashCode ^
+      time.hashCode ^
+      kickoff.hashCode ^
+      matchUrl.hashCode ^
+      competition.hashCode ^
+      channels.hashCode ^
+      status.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -77,5 +112,9 @@ class TopMatch {
           teams == other.teams &&
           date == other.date &&
           time == other.time &&
-          matchUrl == other.matchUrl;
+          kickoff == other.kickoff &&
+          matchUrl == other.matchUrl &&
+          competition == other.competition &&
+          channels == other.channels &&
+          status == other.status;
 }
@@ -77,13 +77,18 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  Future<List<TopMatch>> crateApiSimpleFetchTopMatches();
+  Future<List<TopMatch>> crateApiSimpleFetchTopMatches({
+    required String locale,
+    required bool twelveHour,
+  });
 
   Future<void> crateApiSimpleInitApp();
 
   Future<List<Match>> crateApiSimpleSearchTeam({
     required String team,
     required Country country,
+    required String locale,
+    required bool twelveHour,
   });
 }
 
@@ -96,11 +101,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<List<TopMatch>> crateApiSimpleFetchTopMatches() {
+  Future<List<TopMatch>> crateApiSimpleFetchTopMatches({
+    required String locale,
+    required bool twelveHour,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(locale, serializer);
+          sse_encode_bool(twelveHour, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -113,14 +123,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiSimpleFetchTopMatchesConstMeta,
-        argValues: [],
+        argValues: [locale, twelveHour],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta get kCrateApiSimpleFetchTopMatchesConstMeta =>
-      const TaskConstMeta(debugName: "fetch_top_matches", argNames: []);
+      const TaskConstMeta(
+        debugName: "fetch_top_matches",
+        argNames: ["locale", "twelveHour"],
+      );
 
   @override
   Future<void> crateApiSimpleInitApp() {
@@ -153,6 +166,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Future<List<Match>> crateApiSimpleSearchTeam({
     required String team,
     required Country country,
+    required String locale,
+    required bool twelveHour,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -160,6 +175,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(team, serializer);
           sse_encode_country(country, serializer);
+          sse_encode_String(locale, serializer);
+          sse_encode_bool(twelveHour, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -172,7 +189,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiSimpleSearchTeamConstMeta,
-        argValues: [team, country],
+        argValues: [team, country, locale, twelveHour],
         apiImpl: this,
       ),
     );
@@ -180,15 +197,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiSimpleSearchTeamConstMeta => const TaskConstMeta(
     debugName: "search_team",
-    argNames: ["team", "country"],
+    argNames: ["team", "country", "locale", "twelveHour"],
   );
 
+  @protected
+  DateTime dco_decode_Chrono_Utc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as String;
   }
 
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Chrono_Utc(raw);
+  }
+
   @protected
   Country dco_decode_country(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -201,6 +236,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as int;
   }
 
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -229,28 +270,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Match dco_decode_match(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return Match(
       teams: dco_decode_String(arr[0]),
       competition: dco_decode_String(arr[1]),
       date: dco_decode_String(arr[2]),
       time: dco_decode_String(arr[3]),
-      channels: dco_decode_list_String(arr[4]),
+      kickoff: dco_decode_opt_box_autoadd_Chrono_Utc(arr[4]),
+      channels: dco_decode_list_String(arr[5]),
     );
   }
 
+  @protected
+  MatchStatus dco_decode_match_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MatchStatus.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_Chrono_Utc(raw);
+  }
+
   @protected
   TopMatch dco_decode_top_match(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
     return TopMatch(
       teams: dco_decode_String(arr[0]),
       date: dco_decode_String(arr[1]),
       time: dco_decode_String(arr[2]),
-      matchUrl: dco_decode_String(arr[3]),
+      kickoff: dco_decode_opt_box_autoadd_Chrono_Utc(arr[3]),
+      matchUrl: dco_decode_String(arr[4]),
+      competition: dco_decode_opt_String(arr[5]),
+      channels: dco_decode_list_String(arr[6]),
+      status: dco_decode_match_status(arr[7]),
     );
   }
 
@@ -266,6 +330,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return;
   }
 
+  @protected
+  DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true);
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -273,6 +344,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Chrono_Utc(deserializer));
+  }
+
   @protected
   Country sse_decode_country(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -286,6 +369,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getInt32();
   }
 
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -336,28 +425,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_competition = sse_decode_String(deserializer);
     var var_date = sse_decode_String(deserializer);
     var var_time = sse_decode_String(deserializer);
+    var var_kickoff = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
     var var_channels = sse_decode_list_String(deserializer);
     return Match(
       teams: var_teams,
       competition: var_competition,
       date: var_date,
       time: var_time,
+      kickoff: var_kickoff,
       channels: var_channels,
     );
   }
 
+  @protected
+  MatchStatus sse_decode_match_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MatchStatus.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Chrono_Utc(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   TopMatch sse_decode_top_match(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_teams = sse_decode_String(deserializer);
     var var_date = sse_decode_String(deserializer);
     var var_time = sse_decode_String(deserializer);
+    var var_kickoff = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
     var var_matchUrl = sse_decode_String(deserializer);
+    var var_competition = sse_decode_opt_String(deserializer);
+    var var_channels = sse_decode_list_String(deserializer);
+    var var_status = sse_decode_match_status(deserializer);
     return TopMatch(
       teams: var_teams,
       date: var_date,
       time: var_time,
+      kickoff: var_kickoff,
       matchUrl: var_matchUrl,
+      competition: var_competition,
+      channels: var_channels,
+      status: var_status,
     );
   }
 
@@ -373,9 +503,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_64(
+      PlatformInt64Util.from(self.microsecondsSinceEpoch),
+      serializer,
+    );
   }
 
   @protected
@@ -384,6 +517,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_Chrono_Utc(
+    DateTime self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_Utc(self, serializer);
+  }
+
   @protected
   void sse_encode_country(Country self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -396,6 +544,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putInt32(self);
   }
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -443,16 +597,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.competition, serializer);
     sse_encode_String(self.date, serializer);
     sse_encode_String(self.time, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.kickoff, serializer);
     sse_encode_list_String(self.channels, serializer);
   }
 
+  @protected
+  void sse_encode_match_status(MatchStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_Utc(
+    DateTime? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Chrono_Utc(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_top_match(TopMatch self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.teams, serializer);
     sse_encode_String(self.date, serializer);
     sse_encode_String(self.time, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Utc(self.kickoff, serializer);
     sse_encode_String(self.matchUrl, serializer);
+    sse_encode_opt_String(self.competition, serializer);
+    sse_encode_list_String(self.channels, serializer);
+    sse_encode_match_status(self.status, serializer);
   }
 
   @protected
@@ -465,10 +653,4 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
 }
@@ -20,15 +20,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  @protected
+  DateTime dco_decode_Chrono_Utc(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw);
+
   @protected
   Country dco_decode_country(dynamic raw);
 
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
@@ -44,6 +56,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Match dco_decode_match(dynamic raw);
 
+  @protected
+  MatchStatus dco_decode_match_status(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw);
+
   @protected
   TopMatch dco_decode_top_match(dynamic raw);
 
@@ -53,15 +74,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
+
   @protected
   Country sse_decode_country(SseDeserializer deserializer);
 
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
@@ -77,6 +110,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Match sse_decode_match(SseDeserializer deserializer);
 
+  @protected
+  MatchStatus sse_decode_match_status(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TopMatch sse_decode_top_match(SseDeserializer deserializer);
 
@@ -87,17 +131,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer);
 
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_Chrono_Utc(
+    DateTime self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_country(Country self, SseSerializer serializer);
 
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
@@ -116,6 +172,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_match(Match self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_match_status(MatchStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_Utc(
+    DateTime? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_top_match(TopMatch self, SseSerializer serializer);
 
@@ -124,9 +192,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
 }
 
 // Section: wire_class
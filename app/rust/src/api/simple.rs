@@ -1,5 +1,9 @@
+use chrono::{DateTime, Utc};
 use foot_info_core::client::FootballClient;
-pub use foot_info_core::models::{Country, Match, TopMatch};
+pub use foot_info_core::models::{Country, Match, MatchStatus, TopMatch};
+use foot_info_core::providers::livesoccertv;
+use foot_info_core::utils::format::{HourCycle, KickoffFormat, Locale};
+use foot_info_core::utils::time::DisplayZone;
 
 // Instruct flutter_rust_bridge to generate transparent bindings for our core structs
 // instead of treating them as Opaque pointers
@@ -9,6 +13,7 @@ pub struct _Match {
     pub competition: String,
     pub date: String,
     pub time: String,
+    pub kickoff: Option<DateTime<Utc>>,
     pub channels: Vec<String>,
 }
 
@@ -17,7 +22,19 @@ pub struct _TopMatch {
     pub teams: String,
     pub date: String,
     pub time: String,
+    pub kickoff: Option<DateTime<Utc>>,
     pub match_url: String,
+    pub competition: Option<String>,
+    pub channels: Vec<String>,
+    pub status: MatchStatus,
+}
+
+#[flutter_rust_bridge::frb(mirror(MatchStatus))]
+pub enum _MatchStatus {
+    Upcoming,
+    Live,
+    Finished,
+    Postponed,
 }
 
 #[flutter_rust_bridge::frb(mirror(Country))]
@@ -32,12 +49,40 @@ pub fn init_app() {
     flutter_rust_bridge::setup_default_user_utils();
 }
 
-pub async fn search_team(team: String, country: Country) -> Vec<Match> {
+/// `locale` is a language tag such as "fr"; unknown tags fall back to English.
+pub async fn search_team(team: String, country: Country, locale: String, twelve_hour: bool) -> Vec<Match> {
     let client = FootballClient::new();
-    client.search_team(&team, country).await.unwrap_or_default()
+    let mut matches = client.search_team(&team, country).await.unwrap_or_default();
+
+    let format = kickoff_format(&locale, twelve_hour);
+    let now = Utc::now();
+    for m in &mut matches {
+        if let Some(kickoff) = m.kickoff {
+            m.date = format.day(kickoff, country.source_zone(), now);
+            m.time = format.time(kickoff, country.source_zone());
+        }
+    }
+    matches
 }
 
-pub async fn fetch_top_matches() -> Vec<TopMatch> {
+pub async fn fetch_top_matches(locale: String, twelve_hour: bool) -> Vec<TopMatch> {
     let client = FootballClient::new();
-    client.fetch_top_matches().await.unwrap_or_default()
+    let mut matches = client.fetch_top_matches().await.unwrap_or_default();
+
+    let format = kickoff_format(&locale, twelve_hour);
+    let now = Utc::now();
+    for m in &mut matches {
+        if let Some(kickoff) = m.kickoff {
+            m.date = format.day(kickoff, livesoccertv::SOURCE_ZONE, now);
+            m.time = format.time(kickoff, livesoccertv::SOURCE_ZONE);
+        }
+    }
+    matches
+}
+
+/// Dates and times are rendered here rather than in Dart so the app matches
+/// the TUI's wording.
+fn kickoff_format(locale: &str, twelve_hour: bool) -> KickoffFormat {
+    let hour_cycle = if twelve_hour { HourCycle::H12 } else { HourCycle::H24 };
+    KickoffFormat::new(Locale::parse(locale).unwrap_or_default(), hour_cycle, DisplayZone::Local)
 }
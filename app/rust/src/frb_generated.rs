@@ -67,12 +67,16 @@ fn wire__crate__api__simple__fetch_top_matches_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_locale = <String>::sse_decode(&mut deserializer);
+            let api_twelve_hour = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::simple::fetch_top_matches().await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::simple::fetch_top_matches(api_locale, api_twelve_hour)
+                                .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -139,12 +143,20 @@ fn wire__crate__api__simple__search_team_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_team = <String>::sse_decode(&mut deserializer);
             let api_country = <crate::api::simple::Country>::sse_decode(&mut deserializer);
+            let api_locale = <String>::sse_decode(&mut deserializer);
+            let api_twelve_hour = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::simple::search_team(api_team, api_country).await,
+                            crate::api::simple::search_team(
+                                api_team,
+                                api_country,
+                                api_locale,
+                                api_twelve_hour,
+                            )
+                            .await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -165,6 +177,7 @@ const _: fn() = || {
         let _: String = Match.competition;
         let _: String = Match.date;
         let _: String = Match.time;
+        let _: Option<chrono::DateTime<chrono::Utc>> = Match.kickoff;
         let _: Vec<String> = Match.channels;
     }
     {
@@ -172,12 +185,29 @@ const _: fn() = || {
         let _: String = TopMatch.teams;
         let _: String = TopMatch.date;
         let _: String = TopMatch.time;
+        let _: Option<chrono::DateTime<chrono::Utc>> = TopMatch.kickoff;
         let _: String = TopMatch.match_url;
+        let _: Option<String> = TopMatch.competition;
+        let _: Vec<String> = TopMatch.channels;
+        let _: crate::api::simple::MatchStatus = TopMatch.status;
     }
 };
 
 // Section: dart2rust
 
+impl SseDecode for chrono::DateTime<chrono::Utc> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i64>::sse_decode(deserializer);
+        return chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
+            chrono::DateTime::from_timestamp_micros(inner)
+                .expect("invalid or out-of-range datetime")
+                .naive_utc(),
+            chrono::Utc,
+        );
+    }
+}
+
 impl SseDecode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -186,6 +216,13 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
 impl SseDecode for crate::api::simple::Country {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -206,6 +243,13 @@ impl SseDecode for i32 {
     }
 }
 
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -261,29 +305,75 @@ impl SseDecode for crate::api::simple::Match {
         let mut var_competition = <String>::sse_decode(deserializer);
         let mut var_date = <String>::sse_decode(deserializer);
         let mut var_time = <String>::sse_decode(deserializer);
+        let mut var_kickoff = <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
         let mut var_channels = <Vec<String>>::sse_decode(deserializer);
         return crate::api::simple::Match {
             teams: var_teams,
             competition: var_competition,
             date: var_date,
             time: var_time,
+            kickoff: var_kickoff,
             channels: var_channels,
         };
     }
 }
 
+impl SseDecode for crate::api::simple::MatchStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::MatchStatus::Upcoming,
+            1 => crate::api::simple::MatchStatus::Live,
+            2 => crate::api::simple::MatchStatus::Finished,
+            3 => crate::api::simple::MatchStatus::Postponed,
+            _ => unreachable!("Invalid variant for MatchStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<chrono::DateTime<chrono::Utc>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<chrono::DateTime<chrono::Utc>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for crate::api::simple::TopMatch {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_teams = <String>::sse_decode(deserializer);
         let mut var_date = <String>::sse_decode(deserializer);
         let mut var_time = <String>::sse_decode(deserializer);
+        let mut var_kickoff = <Option<chrono::DateTime<chrono::Utc>>>::sse_decode(deserializer);
         let mut var_matchUrl = <String>::sse_decode(deserializer);
+        let mut var_competition = <Option<String>>::sse_decode(deserializer);
+        let mut var_channels = <Vec<String>>::sse_decode(deserializer);
+        let mut var_status = <crate::api::simple::MatchStatus>::sse_decode(deserializer);
         return crate::api::simple::TopMatch {
             teams: var_teams,
             date: var_date,
             time: var_time,
+            kickoff: var_kickoff,
             match_url: var_matchUrl,
+            competition: var_competition,
+            channels: var_channels,
+            status: var_status,
         };
     }
 }
@@ -300,13 +390,6 @@ impl SseDecode for () {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
-}
-
 fn pde_ffi_dispatcher_primary_impl(
     func_id: i32,
     port: flutter_rust_bridge::for_generated::MessagePort,
@@ -367,6 +450,7 @@ impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::simple::Match> {
             self.0.competition.into_into_dart().into_dart(),
             self.0.date.into_into_dart().into_dart(),
             self.0.time.into_into_dart().into_dart(),
+            self.0.kickoff.into_into_dart().into_dart(),
             self.0.channels.into_into_dart().into_dart(),
         ]
         .into_dart()
@@ -384,13 +468,40 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::simple::Match>>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::simple::MatchStatus> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            crate::api::simple::MatchStatus::Upcoming => 0.into_dart(),
+            crate::api::simple::MatchStatus::Live => 1.into_dart(),
+            crate::api::simple::MatchStatus::Finished => 2.into_dart(),
+            crate::api::simple::MatchStatus::Postponed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::simple::MatchStatus>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::simple::MatchStatus>>
+    for crate::api::simple::MatchStatus
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::simple::MatchStatus> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::simple::TopMatch> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.0.teams.into_into_dart().into_dart(),
             self.0.date.into_into_dart().into_dart(),
             self.0.time.into_into_dart().into_dart(),
+            self.0.kickoff.into_into_dart().into_dart(),
             self.0.match_url.into_into_dart().into_dart(),
+            self.0.competition.into_into_dart().into_dart(),
+            self.0.channels.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -407,6 +518,13 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::simple::TopMatch>>
     }
 }
 
+impl SseEncode for chrono::DateTime<chrono::Utc> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.timestamp_micros(), serializer);
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -414,6 +532,13 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
 impl SseEncode for crate::api::simple::Country {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -438,6 +563,13 @@ impl SseEncode for i32 {
     }
 }
 
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -485,17 +617,60 @@ impl SseEncode for crate::api::simple::Match {
         <String>::sse_encode(self.competition, serializer);
         <String>::sse_encode(self.date, serializer);
         <String>::sse_encode(self.time, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.kickoff, serializer);
         <Vec<String>>::sse_encode(self.channels, serializer);
     }
 }
 
+impl SseEncode for crate::api::simple::MatchStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::MatchStatus::Upcoming => 0,
+                crate::api::simple::MatchStatus::Live => 1,
+                crate::api::simple::MatchStatus::Finished => 2,
+                crate::api::simple::MatchStatus::Postponed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<chrono::DateTime<chrono::Utc>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <chrono::DateTime<chrono::Utc>>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for crate::api::simple::TopMatch {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.teams, serializer);
         <String>::sse_encode(self.date, serializer);
         <String>::sse_encode(self.time, serializer);
+        <Option<chrono::DateTime<chrono::Utc>>>::sse_encode(self.kickoff, serializer);
         <String>::sse_encode(self.match_url, serializer);
+        <Option<String>>::sse_encode(self.competition, serializer);
+        <Vec<String>>::sse_encode(self.channels, serializer);
+        <crate::api::simple::MatchStatus>::sse_encode(self.status, serializer);
     }
 }
 
@@ -511,13 +686,6 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
-    }
-}
-
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -0,0 +1,202 @@
+mod store;
+
+pub use store::{league_key, team_key, Snapshot, SnapshotStore};
+
+use crate::export::slug;
+use crate::models::{LeagueFixture, Match};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A match as remembered between two looks at a team or competition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Listing {
+    /// Identity across looks: the match page when known, otherwise the
+    /// competition, teams and kickoff day. A listing whose key is gone is
+    /// matched on its teams instead, so a moved match is rescheduled rather
+    /// than removed and added.
+    pub key: String,
+    pub teams: String,
+    pub competition: String,
+    pub date: String,
+    pub time: String,
+    pub kickoff: Option<DateTime<Utc>>,
+    pub channels: Vec<String>,
+}
+
+impl Listing {
+    pub fn from_match(m: &Match) -> Self {
+        Self {
+            key: match_key(m),
+            teams: m.teams.clone(),
+            competition: m.competition.clone(),
+            date: m.date.clone(),
+            time: m.time.clone(),
+            kickoff: m.kickoff,
+            channels: m.channels.clone(),
+        }
+    }
+
+    pub fn from_fixture(f: &LeagueFixture, competition: &str) -> Self {
+        Self {
+            key: fixture_key(f),
+            teams: format!("{} v {}", f.home_team, f.away_team),
+            competition: competition.to_string(),
+            date: f.date.clone(),
+            time: f.time.clone(),
+            kickoff: f.kickoff,
+            channels: f.channels.clone(),
+        }
+    }
+
+    /// Same kickoff: compared as instants when both are known, since the
+    /// text depends on the display zone of the look.
+    fn same_kickoff(&self, other: &Listing) -> bool {
+        match (self.kickoff, other.kickoff) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.date == other.date && self.time == other.time,
+        }
+    }
+
+    /// Same match under another key: the same teams in the same competition.
+    fn same_pairing(&self, other: &Listing) -> bool {
+        self.teams == other.teams && self.competition == other.competition
+    }
+}
+
+/// Competition, teams and kickoff day, which keeps a pairing listed twice
+/// in one competition, such as a replay, apart.
+pub fn match_key(m: &Match) -> String {
+    format!("{}-{}-{}", slug(&m.competition), slug(&m.teams), day(m.kickoff, &m.date))
+}
+
+/// Fixtures link to their match page, which outlives a new kickoff.
+pub fn fixture_key(f: &LeagueFixture) -> String {
+    if f.match_url.is_empty() {
+        slug(&format!("{} v {} {}", f.home_team, f.away_team, day(f.kickoff, &f.date)))
+    } else {
+        slug(&f.match_url)
+    }
+}
+
+/// UTC day of the kickoff, or the listed date when it wasn't understood.
+fn day(kickoff: Option<DateTime<Utc>>, date: &str) -> String {
+    kickoff.map_or_else(|| slug(date), |k| k.format("%Y%m%d").to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    /// No longer listed although it hasn't kicked off yet.
+    Removed,
+    Rescheduled {
+        from_date: String,
+        from_time: String,
+        from_kickoff: Option<DateTime<Utc>>,
+    },
+    ChannelsChanged { added: Vec<String>, removed: Vec<String> },
+}
+
+impl ChangeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "new",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Rescheduled { .. } => "rescheduled",
+            ChangeKind::ChannelsChanged { .. } => "channels changed",
+        }
+    }
+}
+
+/// One difference between two looks. `listing` is the current state, or
+/// the last one seen for a removed match.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+    pub listing: Listing,
+}
+
+/// What changed from `previous` to `current`. A match rescheduled with new
+/// channels yields both changes. Matches that dropped off after kicking off
+/// by `now` were played, not removed.
+pub fn diff(previous: &[Listing], current: &[Listing], now: DateTime<Utc>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    // Each current listing's previous self: same key first, then, for a
+    // match moved to another day, a leftover listing of the same pairing
+    let mut claimed = vec![false; previous.len()];
+    let mut earlier: Vec<Option<usize>> = current
+        .iter()
+        .map(|listing| previous.iter().position(|p| p.key == listing.key))
+        .collect();
+    for &i in earlier.iter().flatten() {
+        claimed[i] = true;
+    }
+    for (listing, found) in current.iter().zip(earlier.iter_mut()) {
+        if found.is_none() {
+            *found = (0..previous.len()).find(|&i| !claimed[i] && previous[i].same_pairing(listing));
+            if let Some(i) = *found {
+                claimed[i] = true;
+            }
+        }
+    }
+
+    for (listing, found) in current.iter().zip(&earlier) {
+        let Some(before) = found.map(|i| &previous[i]) else {
+            changes.push(Change {
+                kind: ChangeKind::Added,
+                listing: listing.clone(),
+            });
+            continue;
+        };
+
+        if !listing.same_kickoff(before) {
+            changes.push(Change {
+                kind: ChangeKind::Rescheduled {
+                    from_date: before.date.clone(),
+                    from_time: before.time.clone(),
+                    from_kickoff: before.kickoff,
+                },
+                listing: listing.clone(),
+            });
+        }
+
+        let added = missing_from(&listing.channels, &before.channels);
+        let removed = missing_from(&before.channels, &listing.channels);
+        if !added.is_empty() || !removed.is_empty() {
+            changes.push(Change {
+                kind: ChangeKind::ChannelsChanged { added, removed },
+                listing: listing.clone(),
+            });
+        }
+    }
+
+    for (before, claimed) in previous.iter().zip(claimed) {
+        let played = before.kickoff.is_some_and(|k| k <= now);
+        if !played && !claimed {
+            changes.push(Change {
+                kind: ChangeKind::Removed,
+                listing: before.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+fn missing_from(channels: &[String], other: &[String]) -> Vec<String> {
+    channels.iter().filter(|c| !other.contains(c)).cloned().collect()
+}
+
+/// "1 new, 2 rescheduled" — counts per kind, in a fixed order.
+pub fn summarize(changes: &[Change]) -> String {
+    ["new", "rescheduled", "channels changed", "removed"]
+        .into_iter()
+        .filter_map(|label| {
+            let count = changes.iter().filter(|c| c.kind.label() == label).count();
+            (count > 0).then(|| format!("{} {}", count, label))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
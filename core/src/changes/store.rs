@@ -0,0 +1,68 @@
+use super::{diff, Change, Listing};
+use crate::export::slug;
+use crate::models::Country;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The listings of one look, as saved for the next.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub taken_at: DateTime<Utc>,
+    pub listings: Vec<Listing>,
+}
+
+/// Last-seen listings, one JSON file per team or competition in `dir`.
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+/// Store key of a team searched in `country`, e.g. "team-uk-arsenal".
+pub fn team_key(team: &str, country: Country) -> String {
+    format!("team-{}-{}", slug(&country.to_string()), slug(team))
+}
+
+/// Store key of a competition page, e.g. "league-england-premier-league":
+/// the path below `/competitions/`, which tells apart same-named leagues.
+pub fn league_key(url: &str) -> String {
+    let path = url.split_once("/competitions/").map_or(url, |(_, path)| path);
+    format!("league-{}", slug(path))
+}
+
+impl SnapshotStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    /// The last snapshot under `key`; an unreadable file counts as none.
+    pub fn load(&self, key: &str) -> Option<Snapshot> {
+        let content = fs::read_to_string(self.path(key)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn save(&self, key: &str, snapshot: &Snapshot) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Written aside and renamed, so an interrupted save never leaves
+        // half a snapshot behind.
+        let tmp = self.dir.join(format!("{}.json.tmp", key));
+        fs::write(&tmp, serde_json::to_string_pretty(snapshot)?)?;
+        fs::rename(tmp, self.path(key))
+    }
+
+    /// Changes since the last look under `key`, then saves `listings` as
+    /// the new last look. The very first look reports no changes.
+    pub fn record(&self, key: &str, listings: Vec<Listing>, now: DateTime<Utc>) -> io::Result<Vec<Change>> {
+        let changes = match self.load(key) {
+            Some(previous) => diff(&previous.listings, &listings, now),
+            None => Vec::new(),
+        };
+        self.save(key, &Snapshot { taken_at: now, listings })?;
+        Ok(changes)
+    }
+}
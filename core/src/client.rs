@@ -1,25 +1,52 @@
 use crate::error::AppError;
-use crate::models::{Country, LeagueStats, Match, TopMatch};
+use crate::models::{
+    Country, FixturesPage, LeagueStats, Match, MatchDetails, PageCursor, TopMatch,
+};
 use crate::providers::{
     matchstv::MatchsTvProvider, wheresthematch::WheresTheMatchProvider,
     worldsoccertalk::WorldSoccerTalkProvider, FootballProvider,
 };
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::time::DisplayZone;
+use chrono::NaiveDate;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FootballClient {
     providers: Vec<Arc<dyn FootballProvider>>,
+    zone: DisplayZone,
 }
 
 impl FootballClient {
     pub fn new() -> Self {
-        Self {
-            providers: vec![
-                Arc::new(WheresTheMatchProvider),
-                Arc::new(WorldSoccerTalkProvider),
-                Arc::new(MatchsTvProvider),
+        Self::with_zone(DisplayZone::Local)
+    }
+
+    /// A client whose providers render kickoff times in `zone`.
+    pub fn with_zone(zone: DisplayZone) -> Self {
+        Self::with_clock(zone, Arc::new(SystemClock))
+    }
+
+    /// Like [`FootballClient::with_zone`], with `clock` deciding what "today"
+    /// is when sites list dates without a year.
+    pub fn with_clock(zone: DisplayZone, clock: Arc<dyn Clock>) -> Self {
+        Self::with_providers(
+            vec![
+                Arc::new(WheresTheMatchProvider::new(zone)),
+                Arc::new(WorldSoccerTalkProvider::new(zone, clock.clone())),
+                Arc::new(MatchsTvProvider::new(zone, clock)),
             ],
-        }
+            zone,
+        )
+    }
+
+    /// A client searching the given providers, e.g. stand-ins in tests.
+    pub fn with_providers(providers: Vec<Arc<dyn FootballProvider>>, zone: DisplayZone) -> Self {
+        Self { providers, zone }
+    }
+
+    pub fn zone(&self) -> DisplayZone {
+        self.zone
     }
 
     pub fn providers(&self) -> &[Arc<dyn FootballProvider>] {
@@ -27,11 +54,25 @@ impl FootballClient {
     }
 
     pub async fn fetch_top_matches(&self) -> Result<Vec<TopMatch>, AppError> {
-        crate::providers::livesoccertv::fetch_top_matches().await
+        crate::providers::livesoccertv::fetch_top_matches(self.zone).await
+    }
+
+    pub async fn fetch_schedule(&self, date: NaiveDate) -> Result<Vec<TopMatch>, AppError> {
+        crate::providers::livesoccertv::fetch_schedule(date, self.zone).await
     }
 
     pub async fn fetch_league_stats(&self, competition_url: &str) -> Result<LeagueStats, AppError> {
-        crate::providers::league_stats::fetch_league_stats(competition_url).await
+        crate::providers::league_stats::fetch_league_stats(competition_url, self.zone).await
+    }
+
+    pub async fn fetch_league_fixtures_page(&self, cursor: &PageCursor) -> Result<FixturesPage, AppError> {
+        crate::providers::league_stats::fetch_fixtures_page(cursor, self.zone).await
+    }
+
+    /// Details of one match, from a LiveSoccerTV match URL such as
+    /// [`TopMatch::match_url`].
+    pub async fn fetch_match_details(&self, match_url: &str) -> Result<MatchDetails, AppError> {
+        crate::providers::match_details::fetch_match_details(match_url, self.zone).await
     }
 
     pub async fn search_team(&self, team: &str, provider: Country) -> Result<Vec<Match>, AppError> {
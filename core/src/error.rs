@@ -14,3 +14,15 @@ pub enum AppError {
     #[error("No matches scheduled for team: {0}")]
     NoMatchesScheduled(String),
 }
+
+impl AppError {
+    /// Stable machine-readable name of the error kind, e.g. for API clients.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AppError::Network(_) => "network",
+            AppError::ProviderError(_) => "provider_error",
+            AppError::TeamNotFound(_) => "team_not_found",
+            AppError::NoMatchesScheduled(_) => "no_matches_scheduled",
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::export::{match_uids, slug};
+use crate::models::Match;
+use crate::utils::clock::Clock;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::HashMap;
+
+/// When each listing of one feed last changed, kept between generations of
+/// that feed so an entry's `updated` only moves when its kickoff or channels
+/// do. Keep one per feed: the same match lists different channels in each
+/// country.
+#[derive(Debug, Default)]
+pub struct Revisions {
+    seen: HashMap<String, (String, DateTime<Utc>)>,
+}
+
+impl Revisions {
+    /// `now` for a listing not seen before or whose `content` differs from
+    /// last time; otherwise the time it was last seen changing.
+    fn updated(&mut self, id: String, content: &str, now: DateTime<Utc>) -> DateTime<Utc> {
+        let entry = self.seen.entry(id).or_insert_with(|| (content.to_string(), now));
+        if entry.0 != content {
+            *entry = (content.to_string(), now);
+        }
+        entry.1
+    }
+
+    /// Forgets listings that dropped out of the feed.
+    fn keep(&mut self, ids: &[String]) {
+        self.seen.retain(|id, _| ids.contains(id));
+    }
+}
+
+/// An Atom feed of a team's listings, one entry per match. Entry ids are
+/// stable across refreshes; each entry's `updated` is when `revisions` first
+/// saw its current content, and the feed's is the latest of those.
+pub fn matches_to_atom(team: &str, matches: &[Match], revisions: &mut Revisions, clock: &dyn Clock) -> String {
+    let now = clock.now();
+    let ids = match_uids(matches);
+    revisions.keep(&ids);
+    let entries: Vec<_> = matches
+        .iter()
+        .zip(ids)
+        .map(|(m, id)| {
+            let channels = if m.channels.is_empty() {
+                "No TV info".to_string()
+            } else {
+                m.channels.join(", ")
+            };
+            let content = format!("{} {} · {} · {}", m.date, m.time, m.competition, channels);
+            let updated = revisions.updated(id.clone(), &format!("{}\n{}", m.teams, content), now);
+            (m, id, content, updated)
+        })
+        .collect();
+    let updated = entries.iter().map(|(_, _, _, updated)| *updated).max().unwrap_or(now);
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str(&format!("  <id>urn:foot-info:feed:{}</id>\n", slug(team)));
+    xml.push_str(&format!("  <title>{} on TV</title>\n", escape(team)));
+    xml.push_str(&format!("  <updated>{}</updated>\n", timestamp(updated)));
+    xml.push_str("  <author><name>foot-info</name></author>\n");
+
+    for (m, id, content, updated) in entries {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <id>urn:foot-info:match:{}</id>\n", id));
+        xml.push_str(&format!("    <title>{}</title>\n", escape(&m.teams)));
+        xml.push_str(&format!("    <updated>{}</updated>\n", timestamp(updated)));
+        xml.push_str(&format!("    <content type=\"text\">{}</content>\n", escape(&content)));
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
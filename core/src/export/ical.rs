@@ -0,0 +1,152 @@
+use crate::export::{match_uids, season, slug, unique};
+use crate::models::{LeagueFixture, Match};
+use crate::utils::clock::Clock;
+use chrono::{DateTime, Duration, Utc};
+
+/// Length of the calendar slot booked per match.
+const DEFAULT_DURATION_MINUTES: i64 = 120;
+
+/// RFC 5545 caps content lines at 75 octets; longer ones are folded.
+const MAX_LINE_OCTETS: usize = 75;
+
+struct Event {
+    uid: String,
+    start: DateTime<Utc>,
+    summary: String,
+    description: String,
+}
+
+/// A calendar of search results. Matches whose kickoff couldn't be parsed
+/// are left out, there being nowhere to place them.
+///
+/// UIDs derive from the competition, the season and the teams, so
+/// re-importing after a kickoff change, even to another day, updates the
+/// event in place.
+pub fn matches_to_ical(name: &str, matches: &[Match], clock: &dyn Clock) -> String {
+    let events = matches
+        .iter()
+        .zip(match_uids(matches))
+        .filter_map(|(m, uid)| {
+            let start = m.kickoff?;
+            Some(Event {
+                uid,
+                start,
+                summary: m.teams.clone(),
+                description: describe(Some(&m.competition), &m.channels),
+            })
+        })
+        .collect::<Vec<_>>();
+    write_calendar(name, &events, clock)
+}
+
+/// A calendar of league fixtures, keyed on each fixture's match URL when
+/// the page provides one, otherwise on the competition, season and teams.
+pub fn fixtures_to_ical(competition: &str, fixtures: &[LeagueFixture], clock: &dyn Clock) -> String {
+    let summaries: Vec<_> = fixtures
+        .iter()
+        .map(|f| format!("{} v {}", f.home_team, f.away_team))
+        .collect();
+    let uids = unique(fixtures.iter().zip(&summaries).map(|(f, summary)| {
+        if f.match_url.is_empty() {
+            [slug(competition), f.kickoff.map(season).unwrap_or_default(), slug(summary)]
+                .into_iter()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join("-")
+        } else {
+            slug(&f.match_url)
+        }
+    }));
+    let events = fixtures
+        .iter()
+        .zip(summaries.into_iter().zip(uids))
+        .filter_map(|(f, (summary, uid))| {
+            let start = f.kickoff?;
+            Some(Event {
+                uid,
+                start,
+                summary,
+                description: describe(Some(competition), &f.channels),
+            })
+        })
+        .collect::<Vec<_>>();
+    write_calendar(competition, &events, clock)
+}
+
+/// File name for a calendar, e.g. "arsenal.ics" for "Arsenal".
+pub fn file_name(name: &str) -> String {
+    match slug(name) {
+        s if s.is_empty() => "foot-info.ics".to_string(),
+        s => format!("{}.ics", s),
+    }
+}
+
+fn describe(competition: Option<&str>, channels: &[String]) -> String {
+    let mut lines = Vec::new();
+    if let Some(competition) = competition.filter(|c| !c.is_empty()) {
+        lines.push(format!("Competition: {}", competition));
+    }
+    if channels.is_empty() {
+        lines.push("Channels: No TV info".to_string());
+    } else {
+        lines.push(format!("Channels: {}", channels.join(", ")));
+    }
+    lines.join("\n")
+}
+
+fn write_calendar(name: &str, events: &[Event], clock: &dyn Clock) -> String {
+    let stamp = ical_time(clock.now());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//foot-info//foot-info//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape(name)),
+    ];
+
+    for event in events {
+        let end = event.start + Duration::minutes(DEFAULT_DURATION_MINUTES);
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}@foot-info", event.uid),
+            format!("DTSTAMP:{}", stamp),
+            format!("DTSTART:{}", ical_time(event.start)),
+            format!("DTEND:{}", ical_time(end)),
+            format!("SUMMARY:{}", escape(&event.summary)),
+            format!("DESCRIPTION:{}", escape(&event.description)),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+fn ical_time(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes TEXT values: backslashes, separators and line breaks.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Terminates a content line with CRLF, folding it onto continuation lines
+/// (which start with a space) without splitting a UTF-8 character.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 8);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            octets = 1;
+        }
+        out.push(c);
+        octets += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
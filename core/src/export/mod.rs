@@ -0,0 +1,51 @@
+pub mod atom;
+pub mod ical;
+
+use crate::models::Match;
+use chrono::{DateTime, Datelike, Utc};
+use std::collections::HashMap;
+
+/// Identifies each listed match across refreshes by its competition, season
+/// and teams, so a kickoff moved to another day, or a changed channel list,
+/// keeps the same id, while next season's meeting gets one of its own.
+pub(crate) fn match_uids(matches: &[Match]) -> Vec<String> {
+    unique(matches.iter().map(|m| {
+        [slug(&m.competition), m.kickoff.map(season).unwrap_or_default(), slug(&m.teams)]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }))
+}
+
+/// The season a kickoff falls in, as "2025-26": seasons run from July to
+/// June, so a game moved over New Year stays in its season.
+pub(crate) fn season(kickoff: DateTime<Utc>) -> String {
+    let start = if kickoff.month() >= 7 { kickoff.year() } else { kickoff.year() - 1 };
+    format!("{}-{:02}", start, (start + 1) % 100)
+}
+
+/// `ids` with "-2", "-3"… appended to repeats, in order: the rare pairing
+/// listed twice in one competition (a replay, say) keeps distinct ids.
+pub(crate) fn unique(ids: impl Iterator<Item = String>) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    ids.map(|id| {
+        let count = seen.entry(id.clone()).or_default();
+        *count += 1;
+        match *count {
+            1 => id,
+            n => format!("{}-{}", id, n),
+        }
+    })
+    .collect()
+}
+
+/// Lowercase ASCII letters and digits, everything else collapsed into
+/// single dashes.
+pub(crate) fn slug(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
@@ -0,0 +1,366 @@
+//! Every fetched listing, fixture, table row and top scorer, kept in a
+//! local SQLite database with when and where it was seen.
+
+use crate::models::{split_sides, Country, LeagueFixture, LeagueStats, Match};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+pub use rusqlite::Error;
+pub type Result<T> = rusqlite::Result<T>;
+
+pub use crate::utils::channels::is_free_to_air;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS matches (
+    seen_at TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    country TEXT NOT NULL,
+    teams TEXT NOT NULL,
+    competition TEXT NOT NULL,
+    date TEXT NOT NULL,
+    time TEXT NOT NULL,
+    kickoff TEXT,
+    channels TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS fixtures (
+    seen_at TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    competition TEXT NOT NULL,
+    home_team TEXT NOT NULL,
+    away_team TEXT NOT NULL,
+    date TEXT NOT NULL,
+    time TEXT NOT NULL,
+    kickoff TEXT,
+    score TEXT,
+    channels TEXT NOT NULL,
+    match_url TEXT NOT NULL,
+    matchday INTEGER
+);
+CREATE TABLE IF NOT EXISTS standings (
+    seen_at TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    competition TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    team TEXT NOT NULL,
+    played INTEGER NOT NULL,
+    won INTEGER NOT NULL,
+    drawn INTEGER NOT NULL,
+    lost INTEGER NOT NULL,
+    goals_for INTEGER NOT NULL,
+    goals_against INTEGER NOT NULL,
+    points INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS scorers (
+    seen_at TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    competition TEXT NOT NULL,
+    player TEXT NOT NULL,
+    team TEXT NOT NULL,
+    goals INTEGER NOT NULL,
+    penalties INTEGER NOT NULL,
+    assists INTEGER,
+    appearances INTEGER,
+    minutes INTEGER
+);
+CREATE INDEX IF NOT EXISTS matches_teams ON matches (teams);
+CREATE INDEX IF NOT EXISTS standings_team ON standings (competition, team);
+";
+
+/// A channel that showed a team, counted once per match.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Broadcaster {
+    pub channel: String,
+    pub matches: usize,
+    pub last_seen: DateTime<Utc>,
+}
+
+/// How many of a team's recorded matches had a free-to-air channel.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct FreeTvShare {
+    pub matches: usize,
+    pub on_free_tv: usize,
+}
+
+impl FreeTvShare {
+    pub fn percent(&self) -> Option<f64> {
+        (self.matches > 0).then(|| self.on_free_tv as f64 * 100.0 / self.matches as f64)
+    }
+}
+
+/// A team's place in a table when it was fetched.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TablePosition {
+    pub seen_at: DateTime<Utc>,
+    pub position: u8,
+    pub played: u8,
+    pub points: u16,
+}
+
+pub struct History {
+    conn: Connection,
+}
+
+impl History {
+    /// Opens the database at `path`, creating it and its tables as needed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Records a team search as returned by `provider` for `country`.
+    pub fn record_matches(&self, provider: &str, country: Country, matches: &[Match], seen_at: DateTime<Utc>) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        {
+            let mut insert = tx.prepare_cached(
+                "INSERT INTO matches (seen_at, provider, country, teams, competition, date, time, kickoff, channels)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            )?;
+            for m in matches {
+                insert.execute(params![
+                    timestamp(seen_at),
+                    provider,
+                    country.to_string(),
+                    m.teams,
+                    m.competition,
+                    m.date,
+                    m.time,
+                    m.kickoff.map(timestamp),
+                    channels_json(&m.channels),
+                ])?;
+            }
+        }
+        tx.commit()
+    }
+
+    /// Records a competition page: its fixtures, table and top scorers.
+    pub fn record_league(&self, provider: &str, stats: &LeagueStats, seen_at: DateTime<Utc>) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        insert_fixtures(&tx, provider, &stats.competition, &stats.fixtures, seen_at)?;
+        let seen_at = timestamp(seen_at);
+        {
+            let mut insert = tx.prepare_cached(
+                "INSERT INTO standings (seen_at, provider, competition, position, team, played, won, drawn, lost, goals_for, goals_against, points)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            )?;
+            for row in &stats.table {
+                insert.execute(params![
+                    seen_at,
+                    provider,
+                    stats.competition,
+                    row.position,
+                    row.team,
+                    row.played,
+                    row.won,
+                    row.drawn,
+                    row.lost,
+                    row.goals_for,
+                    row.goals_against,
+                    row.points,
+                ])?;
+            }
+
+            let mut insert = tx.prepare_cached(
+                "INSERT INTO scorers (seen_at, provider, competition, player, team, goals, penalties, assists, appearances, minutes)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            )?;
+            for s in &stats.top_scorers {
+                insert.execute(params![
+                    seen_at,
+                    provider,
+                    stats.competition,
+                    s.player,
+                    s.team,
+                    s.goals,
+                    s.penalties,
+                    s.assists,
+                    s.appearances,
+                    s.minutes,
+                ])?;
+            }
+        }
+        tx.commit()
+    }
+
+    /// Records a further page of a competition's fixtures.
+    pub fn record_fixtures(&self, provider: &str, competition: &str, fixtures: &[LeagueFixture], seen_at: DateTime<Utc>) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        insert_fixtures(&tx, provider, competition, fixtures, seen_at)?;
+        tx.commit()
+    }
+
+    /// Channels that showed `team`, most frequent first.
+    pub fn broadcasters(&self, team: &str) -> Result<Vec<Broadcaster>> {
+        let mut counts: HashMap<String, Broadcaster> = HashMap::new();
+        for appearance in self.appearances(team)? {
+            for channel in appearance.channels {
+                let entry = counts.entry(channel.clone()).or_insert(Broadcaster {
+                    channel,
+                    matches: 0,
+                    last_seen: appearance.seen_at,
+                });
+                entry.matches += 1;
+                entry.last_seen = entry.last_seen.max(appearance.seen_at);
+            }
+        }
+
+        let mut broadcasters: Vec<Broadcaster> = counts.into_values().collect();
+        broadcasters.sort_by(|a, b| b.matches.cmp(&a.matches).then_with(|| a.channel.cmp(&b.channel)));
+        Ok(broadcasters)
+    }
+
+    pub fn free_tv_share(&self, team: &str) -> Result<FreeTvShare> {
+        let appearances = self.appearances(team)?;
+        Ok(FreeTvShare {
+            matches: appearances.len(),
+            on_free_tv: appearances
+                .iter()
+                .filter(|a| a.channels.iter().any(|c| is_free_to_air(c)))
+                .count(),
+        })
+    }
+
+    /// Names of the competitions whose tables were recorded, as their pages
+    /// title them.
+    pub fn competitions(&self) -> Result<Vec<String>> {
+        let mut select = self.conn.prepare("SELECT DISTINCT competition FROM standings ORDER BY competition")?;
+        let names = select.query_map([], |row| row.get(0))?;
+        names.collect()
+    }
+
+    /// `team`'s place in `competition` at each fetch where it changed, oldest
+    /// first. Both names match case-insensitively.
+    pub fn table_positions(&self, competition: &str, team: &str) -> Result<Vec<TablePosition>> {
+        let mut select = self.conn.prepare(
+            "SELECT seen_at, position, played, points FROM standings
+             WHERE lower(competition) = lower(?1) AND lower(team) = lower(?2)
+             ORDER BY seen_at",
+        )?;
+        let rows = select.query_map(params![competition.trim(), team.trim()], |row| {
+            Ok(TablePosition {
+                seen_at: parse_timestamp(&row.get::<_, String>(0)?),
+                position: row.get(1)?,
+                played: row.get(2)?,
+                points: row.get(3)?,
+            })
+        })?;
+
+        let mut positions: Vec<TablePosition> = Vec::new();
+        for position in rows {
+            let position = position?;
+            let unchanged = positions.last().is_some_and(|last| {
+                (last.position, last.played, last.points) == (position.position, position.played, position.points)
+            });
+            if !unchanged {
+                positions.push(position);
+            }
+        }
+        Ok(positions)
+    }
+
+    /// Every match `team` played in across searches and competition pages,
+    /// once each, with the channels of its latest sighting. The name has to
+    /// match a side whole: "Arsenal" isn't "Arsenal Women".
+    fn appearances(&self, team: &str) -> Result<Vec<Appearance>> {
+        // Searches store both sides in one text, so they're only narrowed
+        // down here and told apart below
+        let mut select = self.conn.prepare(
+            "SELECT teams, coalesce(substr(kickoff, 1, 10), date), channels, seen_at FROM matches
+             WHERE instr(lower(teams), lower(?1)) > 0
+             UNION ALL
+             SELECT home_team || ' v ' || away_team, coalesce(substr(kickoff, 1, 10), date), channels, seen_at FROM fixtures
+             WHERE lower(home_team) = lower(?1) OR lower(away_team) = lower(?1)",
+        )?;
+        let team = team.trim().to_lowercase();
+        let rows = select.query_map(params![team], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+
+        // Keyed by the sides rather than the text, which reads "Arsenal v
+        // Chelsea" on one site and "Arsenal - Chelsea" on another
+        let mut latest: HashMap<(String, String, String), Appearance> = HashMap::new();
+        for row in rows {
+            let (teams, day, channels, seen_at) = row?;
+            let Some((home, away)) = split_sides(&teams) else {
+                continue;
+            };
+            let (home, away) = (home.to_lowercase(), away.to_lowercase());
+            if home != team && away != team {
+                continue;
+            }
+            let appearance = Appearance {
+                channels: serde_json::from_str(&channels).unwrap_or_default(),
+                seen_at: parse_timestamp(&seen_at),
+            };
+            let key = (home, away, day);
+            if latest.get(&key).is_none_or(|known| known.seen_at <= appearance.seen_at) {
+                latest.insert(key, appearance);
+            }
+        }
+        Ok(latest.into_values().collect())
+    }
+}
+
+fn insert_fixtures(
+    conn: &Connection,
+    provider: &str,
+    competition: &str,
+    fixtures: &[LeagueFixture],
+    seen_at: DateTime<Utc>,
+) -> Result<()> {
+    let mut insert = conn.prepare_cached(
+        "INSERT INTO fixtures (seen_at, provider, competition, home_team, away_team, date, time, kickoff, score, channels, match_url, matchday)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+    )?;
+    for f in fixtures {
+        insert.execute(params![
+            timestamp(seen_at),
+            provider,
+            competition,
+            f.home_team,
+            f.away_team,
+            f.date,
+            f.time,
+            f.kickoff.map(timestamp),
+            f.score,
+            channels_json(&f.channels),
+            f.match_url,
+            f.matchday,
+        ])?;
+    }
+    Ok(())
+}
+
+struct Appearance {
+    channels: Vec<String>,
+    seen_at: DateTime<Utc>,
+}
+
+fn timestamp(dt: DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+fn parse_timestamp(text: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(text)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_default()
+}
+
+fn channels_json(channels: &[String]) -> String {
+    serde_json::to_string(channels).unwrap_or_else(|_| "[]".to_string())
+}
@@ -1,5 +1,9 @@
+pub mod changes;
 pub mod client;
 pub mod error;
+pub mod export;
+#[cfg(feature = "history")]
+pub mod history;
 pub mod models;
 pub mod providers;
 pub mod utils;
@@ -1,32 +1,135 @@
-#[derive(Debug, Clone)]
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize)]
 pub struct Match {
     pub teams: String,
     pub competition: String,
     pub date: String,
     pub time: String,
+    /// Kickoff instant, when the listing could be parsed. `date` and `time`
+    /// remain as plain text for when it couldn't.
+    pub kickoff: Option<DateTime<Utc>>,
     pub channels: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+impl Match {
+    /// Home and away team, split from "Arsenal v Chelsea", "Arsenal vs
+    /// Chelsea" or "Arsenal - Chelsea"; `None` for listings that don't name
+    /// two sides.
+    pub fn sides(&self) -> Option<(&str, &str)> {
+        split_sides(&self.teams)
+    }
+}
+
+/// Home and away team of a "teams" text, as [`Match::sides`] splits them.
+pub fn split_sides(teams: &str) -> Option<(&str, &str)> {
+    [" v ", " vs ", " - "].iter().find_map(|separator| {
+        let (home, away) = teams.split_once(separator)?;
+        let (home, away) = (home.trim(), away.trim());
+        (!home.is_empty() && !away.is_empty()).then_some((home, away))
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TopMatch {
     pub teams: String,
     pub date: String,
     pub time: String,
+    pub kickoff: Option<DateTime<Utc>>,
     pub match_url: String,
+    /// e.g. "Europe - UEFA Champions League"; `None` when the schedule
+    /// listing didn't include this match.
+    pub competition: Option<String>,
+    pub channels: Vec<String>,
+    pub status: MatchStatus,
 }
 
-#[derive(Debug, Clone)]
+impl TopMatch {
+    /// Match id from the URL fragment, e.g. "5303719" for
+    /// "/match/bayern-munchen-vs-eintracht-frankfurt/197o3#5303719".
+    pub fn match_id(&self) -> Option<&str> {
+        self.match_url
+            .split_once('#')
+            .map(|(_, id)| id)
+            .filter(|id| !id.is_empty())
+    }
+}
+
+/// Everything a single LiveSoccerTV match page lists.
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchDetails {
+    pub teams: String,
+    pub competition: Option<String>,
+    pub date: String,
+    pub time: String,
+    pub kickoff: Option<DateTime<Utc>>,
+    pub status: MatchStatus,
+    /// Every broadcaster listed for the match, across all countries.
+    pub channels: Vec<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum MatchStatus {
+    #[default]
+    Upcoming,
+    Live,
+    Finished,
+    Postponed,
+}
+
+impl MatchStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MatchStatus::Upcoming => "Upcoming",
+            MatchStatus::Live => "Live",
+            MatchStatus::Finished => "FT",
+            MatchStatus::Postponed => "Postponed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct LeagueFixture {
     pub home_team: String,
     pub away_team: String,
     pub date: String,
     pub time: String,
+    pub kickoff: Option<DateTime<Utc>>,
     pub score: Option<String>,
     pub channels: Vec<String>,
     pub match_url: String,
+    pub matchday: Option<u16>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageDirection {
+    Previous,
+    Next,
+}
+
+/// Cursor for the "Prev." / "Next" fixture links of a competition page.
+/// Holds the arguments the page passes to its own pagination script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor {
+    pub direction: PageDirection,
+    pub competition_id: String,
+    pub offset: i32,
+    pub anchor_time: String,
+    pub anchor_match: String,
+}
+
+/// One block of fixtures loaded through a `PageCursor`.
 #[derive(Debug, Clone)]
+pub struct FixturesPage {
+    pub fixtures: Vec<LeagueFixture>,
+    pub previous_page: Option<PageCursor>,
+    pub next_page: Option<PageCursor>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct StandingRow {
     pub position: u8,
     pub team: String,
@@ -39,31 +142,180 @@ pub struct StandingRow {
     pub goal_diff: i16,
     pub points: u16,
     pub form: Vec<char>,
+    pub zone: Option<Zone>,
 }
 
-#[derive(Debug, Clone)]
+/// Broad category of a highlighted band in a league table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum ZoneKind {
+    Promotion,
+    Qualification,
+    Playoff,
+    Relegation,
+}
+
+/// A highlighted band in a league table, e.g. "Champions League" or "Relegation".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Zone {
+    pub kind: ZoneKind,
+    pub label: String,
+}
+
+impl Zone {
+    /// Builds a zone from the legend label shown by the source site.
+    /// Returns `None` for an empty label.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.trim();
+        if label.is_empty() {
+            return None;
+        }
+
+        let lower = label.to_lowercase();
+        let kind = if lower.contains("relegation") {
+            ZoneKind::Relegation
+        } else if lower.contains("playoff") || lower.contains("play-off") || lower.contains("play off") {
+            ZoneKind::Playoff
+        } else if lower.contains("promotion") {
+            ZoneKind::Promotion
+        } else {
+            ZoneKind::Qualification
+        };
+
+        Some(Self {
+            kind,
+            label: label.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TopScorer {
     pub player: String,
     pub team: String,
-    pub goals: u8,
-    pub penalties: u8,
+    pub goals: u16,
+    pub penalties: u16,
+    pub assists: Option<u16>,
+    pub appearances: Option<u16>,
+    pub minutes: Option<u32>,
 }
 
-#[derive(Debug, Clone)]
+impl TopScorer {
+    pub fn non_penalty_goals(&self) -> u16 {
+        self.goals.saturating_sub(self.penalties)
+    }
+
+    /// Goals plus assists, when the page lists assists.
+    pub fn goal_contributions(&self) -> Option<u16> {
+        self.assists.map(|a| self.goals.saturating_add(a))
+    }
+
+    pub fn goals_per_game(&self) -> Option<f64> {
+        match self.appearances {
+            Some(apps) if apps > 0 => Some(self.goals as f64 / apps as f64),
+            _ => None,
+        }
+    }
+
+    pub fn minutes_per_goal(&self) -> Option<f64> {
+        match self.minutes {
+            Some(mins) if self.goals > 0 => Some(mins as f64 / self.goals as f64),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct LeagueStats {
     pub competition: String,
     pub fixtures: Vec<LeagueFixture>,
     pub table: Vec<StandingRow>,
     pub top_scorers: Vec<TopScorer>,
+    #[serde(skip)]
+    pub previous_page: Option<PageCursor>,
+    #[serde(skip)]
+    pub next_page: Option<PageCursor>,
+}
+
+impl LeagueStats {
+    /// Distinct matchdays among the loaded fixtures, in ascending order.
+    pub fn matchdays(&self) -> Vec<u16> {
+        let mut days: Vec<u16> = self.fixtures.iter().filter_map(|f| f.matchday).collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// Loaded fixtures belonging to the given matchday.
+    pub fn fixtures_in_matchday(&self, matchday: u16) -> Vec<&LeagueFixture> {
+        self.fixtures
+            .iter()
+            .filter(|f| f.matchday == Some(matchday))
+            .collect()
+    }
+
+    /// Rows of the table currently sitting in a zone of the given kind.
+    pub fn teams_in_zone(&self, kind: ZoneKind) -> Vec<&StandingRow> {
+        self.table
+            .iter()
+            .filter(|row| row.zone.as_ref().is_some_and(|z| z.kind == kind))
+            .collect()
+    }
+
+    /// Rows of the table currently sitting in the zone with the given label (case-insensitive).
+    pub fn teams_in_zone_labelled(&self, label: &str) -> Vec<&StandingRow> {
+        self.table
+            .iter()
+            .filter(|row| {
+                row.zone
+                    .as_ref()
+                    .is_some_and(|z| z.label.eq_ignore_ascii_case(label))
+            })
+            .collect()
+    }
+
+    /// Distinct zones present in the table, in table order.
+    pub fn zones(&self) -> Vec<&Zone> {
+        let mut zones: Vec<&Zone> = Vec::new();
+        for zone in self.table.iter().filter_map(|row| row.zone.as_ref()) {
+            if !zones.contains(&zone) {
+                zones.push(zone);
+            }
+        }
+        zones
+    }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+/// Stored as its lowercase code, e.g. "uk".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Country {
+    #[serde(alias = "gb")]
     UK,
     US,
     FR,
 }
 
+impl Country {
+    /// Parses a country code: "uk" (or "gb"), "us" (or "usa") or "fr".
+    pub fn parse(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().as_str() {
+            "uk" | "gb" => Some(Country::UK),
+            "us" | "usa" => Some(Country::US),
+            "fr" => Some(Country::FR),
+            _ => None,
+        }
+    }
+
+    /// Zone the country's provider lists its kickoff times in.
+    pub fn source_zone(&self) -> Tz {
+        match self {
+            Country::UK => chrono_tz::Europe::London,
+            Country::US => chrono_tz::US::Eastern,
+            Country::FR => chrono_tz::Europe::Paris,
+        }
+    }
+}
+
 impl std::fmt::Display for Country {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
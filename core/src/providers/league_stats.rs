@@ -1,20 +1,83 @@
 use crate::error::AppError;
-use crate::models::{LeagueFixture, LeagueStats, StandingRow, TopScorer};
-use chrono::{Local, TimeZone, Utc};
-use scraper::{Html, Selector};
+use crate::models::{
+    FixturesPage, LeagueFixture, LeagueStats, PageCursor, PageDirection, StandingRow, TopScorer,
+    Zone,
+};
+use crate::utils::time::{self, DisplayZone};
+use chrono_tz::US::Eastern;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+use url::form_urlencoded;
 use wreq::Client;
 use wreq_util::Emulation;
 
+/// Endpoint behind the "Prev." / "Next" links of a competition's fixture list.
+const PAGINATION_URL: &str = "https://www.livesoccertv.com/ajax/paginate/";
+
+/// Source of competition pages, as recorded alongside what they list.
+pub const PROVIDER_NAME: &str = "LiveSoccerTV";
+
+const COMPETITIONS_URL: &str = "https://www.livesoccertv.com/competitions/";
+
+/// Short names accepted wherever a competition URL is expected, with the
+/// page path under `/competitions/` each one stands for.
+pub const KNOWN_COMPETITIONS: &[(&str, &str)] = &[
+    ("premier-league", "england/premier-league"),
+    ("epl", "england/premier-league"),
+    ("championship", "england/championship"),
+    ("la-liga", "spain/primera-division"),
+    ("serie-a", "italy/serie-a"),
+    ("bundesliga", "germany/bundesliga"),
+    ("ligue-1", "france/ligue-1"),
+    ("eredivisie", "netherlands/eredivisie"),
+    ("primeira-liga", "portugal/primeira-liga"),
+    ("mls", "usa/major-league-soccer"),
+    ("champions-league", "international/uefa-champions-league"),
+    ("ucl", "international/uefa-champions-league"),
+    ("europa-league", "international/uefa-europa-league"),
+];
+
+/// Resolves a competition page URL from a full URL, a known short name such
+/// as "premier-league" or "ucl", or a `country/competition` path.
+pub fn competition_url(name_or_url: &str) -> Option<String> {
+    let input = name_or_url.trim();
+    if input.starts_with("http://") || input.starts_with("https://") {
+        return Some(input.to_string());
+    }
+
+    let key = input.trim_matches('/').to_lowercase().replace([' ', '_'], "-");
+    if key.is_empty() {
+        return None;
+    }
+    let path = KNOWN_COMPETITIONS
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, path)| path.to_string())
+        .or_else(|| key.contains('/').then_some(key))?;
+    Some(format!("{}{}/", COMPETITIONS_URL, path))
+}
+
 /// Fetches and parses a LiveSoccerTV competition page.
 ///
 /// Example URL: `https://www.livesoccertv.com/competitions/england/premier-league/`
-pub async fn fetch_league_stats(competition_url: &str) -> Result<LeagueStats, AppError> {
+pub async fn fetch_league_stats(competition_url: &str, zone: DisplayZone) -> Result<LeagueStats, AppError> {
+    let body = fetch_body(competition_url).await?;
+    parse_html(&body, zone)
+}
+
+/// Fetches the block of fixtures before or after the one a cursor was read from.
+pub async fn fetch_fixtures_page(cursor: &PageCursor, zone: DisplayZone) -> Result<FixturesPage, AppError> {
+    let body = fetch_body(&page_url(cursor)).await?;
+    Ok(parse_fixtures_page(&body, zone))
+}
+
+async fn fetch_body(url: &str) -> Result<String, AppError> {
     let client = Client::builder()
         .emulation(Emulation::Chrome136)
         .build()?;
 
     let response = client
-        .get(competition_url)
+        .get(url)
         .send()
         .await?;
 
@@ -35,29 +98,62 @@ pub async fn fetch_league_stats(competition_url: &str) -> Result<LeagueStats, Ap
         ));
     }
 
-    parse_html(&body)
+    Ok(body)
+}
+
+/// The request for the fixtures a cursor points at. Its values come from
+/// the page's scripts, so they're all encoded rather than trusted.
+pub fn page_url(cursor: &PageCursor) -> String {
+    let direction = match cursor.direction {
+        PageDirection::Previous => "previous",
+        PageDirection::Next => "next",
+    };
+    let query = form_urlencoded::Serializer::new(String::new())
+        .append_pair("dir", direction)
+        .append_pair("type", "competition")
+        .append_pair("id", &cursor.competition_id)
+        .append_pair("offset", &cursor.offset.to_string())
+        .append_pair("time", &cursor.anchor_time)
+        .append_pair("match", &cursor.anchor_match)
+        .finish();
+    format!("{}?{}", PAGINATION_URL, query)
 }
 
-fn timestamp_to_local(millis: i64) -> (String, String) {
-    match Utc.timestamp_millis_opt(millis).single() {
-        Some(dt) => {
-            let local = dt.with_timezone(&Local);
-            (
-                local.format("%a %d %b %Y").to_string(),
-                local.format("%H:%M").to_string(),
-            )
+/// Adds a freshly loaded page of fixtures to already loaded stats: older pages
+/// are prepended, newer ones appended, and the matching cursor is advanced.
+/// Matchdays are then inferred for any fixture the page did not number.
+pub fn merge_fixtures_page(stats: &mut LeagueStats, page: FixturesPage, direction: PageDirection) {
+    match direction {
+        PageDirection::Previous => {
+            let mut fixtures = page.fixtures;
+            fixtures.append(&mut stats.fixtures);
+            stats.fixtures = fixtures;
+            stats.previous_page = page.previous_page;
+        }
+        PageDirection::Next => {
+            stats.fixtures.extend(page.fixtures);
+            stats.next_page = page.next_page;
         }
-        None => ("Unknown".to_string(), "??:??".to_string()),
     }
+    infer_matchdays(&mut stats.fixtures, &stats.table);
+}
+
+/// LiveSoccerTV is a US site and lists kickoffs in Eastern time.
+fn timestamp_to_display(millis: i64, zone: DisplayZone) -> (String, String) {
+    time::convert_millis_to_display(millis, zone, Eastern)
+        .unwrap_or_else(|| ("Unknown".to_string(), "??:??".to_string()))
 }
 
-pub fn parse_html(body: &str) -> Result<LeagueStats, AppError> {
+pub fn parse_html(body: &str, zone: DisplayZone) -> Result<LeagueStats, AppError> {
     let document = Html::parse_document(body);
 
     let competition = parse_competition_name(&document);
-    let fixtures = parse_fixtures(&document);
+    let mut fixtures = parse_fixtures(&document, zone);
     let table = parse_table(&document);
     let top_scorers = parse_top_scorers(&document);
+    let (previous_page, next_page) = parse_pagination(&document);
+
+    infer_matchdays(&mut fixtures, &table);
 
     if fixtures.is_empty() && table.is_empty() {
         return Err(AppError::NoMatchesScheduled(
@@ -70,9 +166,161 @@ pub fn parse_html(body: &str) -> Result<LeagueStats, AppError> {
         fixtures,
         table,
         top_scorers,
+        previous_page,
+        next_page,
     })
 }
 
+/// Parses a fixtures block as returned by the pagination endpoint.
+pub fn parse_fixtures_page(body: &str, zone: DisplayZone) -> FixturesPage {
+    let document = Html::parse_document(body);
+    let (previous_page, next_page) = parse_pagination(&document);
+
+    FixturesPage {
+        fixtures: parse_fixtures(&document, zone),
+        previous_page,
+        next_page,
+    }
+}
+
+/// Reads the `paginate(...)` calls behind the "Prev." / "Next" links.
+fn parse_pagination(document: &Html) -> (Option<PageCursor>, Option<PageCursor>) {
+    let sel = Selector::parse("div.r_pagination div.pagination").expect("Invalid selector");
+
+    let mut previous = None;
+    let mut next = None;
+
+    for link in document.select(&sel) {
+        let Some(cursor) = link.value().attr("onclick").and_then(parse_paginate_call) else {
+            continue;
+        };
+        match cursor.direction {
+            PageDirection::Previous => previous = Some(cursor),
+            PageDirection::Next => next = Some(cursor),
+        }
+    }
+
+    (previous, next)
+}
+
+/// Parses `paginate('next','competition', '6','1','2026-04-11 12:30:00','Liverpool+vs+Fulham','1','_live')`.
+pub fn parse_paginate_call(onclick: &str) -> Option<PageCursor> {
+    let start = onclick.find("paginate(")? + "paginate(".len();
+    let end = onclick[start..].find(')')? + start;
+    let args: Vec<&str> = onclick[start..end]
+        .split(',')
+        .map(|a| a.trim().trim_matches('\'').trim_matches('"'))
+        .collect();
+
+    if args.len() < 6 || args[1] != "competition" {
+        return None;
+    }
+
+    let direction = match args[0] {
+        "previous" => PageDirection::Previous,
+        "next" => PageDirection::Next,
+        _ => return None,
+    };
+
+    Some(PageCursor {
+        direction,
+        competition_id: args[2].to_string(),
+        offset: args[3].parse().ok()?,
+        anchor_time: args[4].to_string(),
+        anchor_match: form_decoded(args[5]),
+    })
+}
+
+/// The page passes the anchor match already form-encoded, as
+/// "Liverpool+vs+Fulham".
+fn form_decoded(value: &str) -> String {
+    form_urlencoded::parse(value.as_bytes())
+        .next()
+        .map(|(text, _)| text.into_owned())
+        .unwrap_or_default()
+}
+
+/// Extracts a round number from header text such as "Matchday 30",
+/// "Round 12", "Gameweek 7" or "Journée 25".
+pub fn parse_round_number(text: &str) -> Option<u16> {
+    const KEYWORDS: [&str; 8] = [
+        "matchday", "gameweek", "round", "week", "journée", "journee", "jornada", "spieltag",
+    ];
+
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| c.is_whitespace() || c == ':' || c == '-' || c == ',')
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    words.windows(2).find_map(|pair| {
+        if KEYWORDS.contains(&pair[0]) {
+            pair[1].parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+/// Fills in missing matchdays from the standings.
+///
+/// LiveSoccerTV landing pages rarely label rounds, so each team's games are
+/// counted outwards from its `played` total: its latest result is matchday
+/// `played`, the one before `played - 1`, its next fixture `played + 1`, and so on.
+/// A fixture takes the higher of its two teams' counts. Fixtures that already
+/// carry a matchday are left alone, and nothing is inferred without a table.
+pub fn infer_matchdays(fixtures: &mut [LeagueFixture], table: &[StandingRow]) {
+    if table.is_empty() {
+        return;
+    }
+
+    let played: HashMap<&str, u16> = table
+        .iter()
+        .map(|row| (row.team.as_str(), u16::from(row.played)))
+        .collect();
+
+    let mut inferred: Vec<Option<u16>> = vec![None; fixtures.len()];
+
+    // Results, newest first, count down from the games played
+    let mut counters: HashMap<&str, u16> = played.clone();
+    for (i, fixture) in fixtures.iter().enumerate().rev() {
+        if fixture.score.is_none() {
+            continue;
+        }
+        inferred[i] = count_matchday(&mut counters, fixture, |n| n.saturating_sub(1));
+    }
+
+    // Upcoming fixtures, oldest first, count up from the next game
+    let mut counters: HashMap<&str, u16> = played.iter().map(|(t, p)| (*t, p + 1)).collect();
+    for (i, fixture) in fixtures.iter().enumerate() {
+        if fixture.score.is_some() {
+            continue;
+        }
+        inferred[i] = count_matchday(&mut counters, fixture, |n| n + 1);
+    }
+
+    for (fixture, matchday) in fixtures.iter_mut().zip(inferred) {
+        if fixture.matchday.is_none() {
+            fixture.matchday = matchday.filter(|&m| m > 0);
+        }
+    }
+}
+
+fn count_matchday(
+    counters: &mut HashMap<&str, u16>,
+    fixture: &LeagueFixture,
+    step: impl Fn(u16) -> u16,
+) -> Option<u16> {
+    let mut matchday = None;
+    for team in [fixture.home_team.as_str(), fixture.away_team.as_str()] {
+        if let Some(count) = counters.get_mut(team) {
+            matchday = matchday.max(Some(*count));
+            *count = step(*count);
+        }
+    }
+    matchday
+}
+
 fn parse_competition_name(document: &Html) -> String {
     let sel = Selector::parse("h1").expect("Invalid selector");
     document
@@ -82,7 +330,7 @@ fn parse_competition_name(document: &Html) -> String {
         .unwrap_or_else(|| "Unknown Competition".to_string())
 }
 
-fn parse_fixtures(document: &Html) -> Vec<LeagueFixture> {
+fn parse_fixtures(document: &Html, zone: DisplayZone) -> Vec<LeagueFixture> {
     let mut fixtures = Vec::new();
 
     // LiveSoccerTV uses a table with class "schedules" for fixtures.
@@ -99,13 +347,18 @@ fn parse_fixtures(document: &Html) -> Vec<LeagueFixture> {
     };
 
     let mut current_date = String::new();
+    let mut current_matchday: Option<u16> = None;
 
     for row in table.select(&tr_sel) {
         let classes: Vec<&str> = row.value().classes().collect();
 
-        // Date header row
-        if classes.contains(&"dheader") {
-            current_date = row.text().collect::<String>().trim().to_string();
+        // Header rows carry either a date or a round label ("Matchday 30")
+        if classes.contains(&"dheader") || classes.contains(&"drow") {
+            let text = row.text().collect::<String>().trim().to_string();
+            match parse_round_number(&text) {
+                Some(round) => current_matchday = Some(round),
+                None => current_date = text,
+            }
             continue;
         }
 
@@ -116,12 +369,13 @@ fn parse_fixtures(document: &Html) -> Vec<LeagueFixture> {
 
         // Time cell — try `span.ts[dv]` for unix timestamp, fall back to text
         let time_cell = &cells[0];
-        let (date, time) = time_cell
+        let millis = time_cell
             .select(&span_ts_sel)
             .next()
             .and_then(|s| s.value().attr("dv"))
-            .and_then(|dv| dv.parse::<i64>().ok())
-            .map(timestamp_to_local)
+            .and_then(|dv| dv.parse::<i64>().ok());
+        let (date, time) = millis
+            .map(|millis| timestamp_to_display(millis, zone))
             .unwrap_or_else(|| {
                 let t = time_cell.text().collect::<String>().trim().to_string();
                 (current_date.clone(), t)
@@ -184,9 +438,11 @@ fn parse_fixtures(document: &Html) -> Vec<LeagueFixture> {
             away_team,
             date,
             time,
+            kickoff: millis.and_then(time::millis_to_utc),
             score,
             channels,
             match_url,
+            matchday: current_matchday,
         });
     }
 
@@ -205,6 +461,8 @@ fn parse_table(document: &Html) -> Vec<StandingRow> {
         None => return rows,
     };
 
+    let legend = parse_zone_legend(document);
+
     for (i, row) in table.select(&tr_sel).enumerate() {
         let cells: Vec<_> = row.select(&td_sel).collect();
         if cells.len() < 9 {
@@ -233,6 +491,8 @@ fn parse_table(document: &Html) -> Vec<StandingRow> {
 
         let offset = if cells.len() > 1 && cells[1].text().collect::<String>().trim().is_empty() { 1 } else { 0 };
 
+        let zone = parse_zone(&cells[0], &legend);
+
         rows.push(StandingRow {
             position: text(0).parse::<u8>().unwrap_or((i as u8) + 1),
             team: text(1 + offset),
@@ -245,17 +505,114 @@ fn parse_table(document: &Html) -> Vec<StandingRow> {
             goal_diff: parse_i16(8 + offset),
             points: parse_u16(9 + offset),
             form,
+            zone,
         });
     }
 
     rows
 }
 
+/// Reads the colour legend under the standings table, mapping each
+/// `tbl_*` class to its label (e.g. `tbl_red` -> "Relegation").
+fn parse_zone_legend(document: &Html) -> HashMap<String, String> {
+    let legend_sel = Selector::parse("div.m5").expect("Invalid selector");
+
+    document
+        .select(&legend_sel)
+        .filter_map(|div| {
+            let class = div.value().classes().find(|c| c.starts_with("tbl_"))?;
+            let label = div.text().collect::<String>().trim().to_string();
+            if label.is_empty() {
+                None
+            } else {
+                Some((class.to_string(), label))
+            }
+        })
+        .collect()
+}
+
+/// Zone for a standings row, taken from the position cell's `title`
+/// or, when that is empty, from the legend entry matching its `tbl_*` class.
+fn parse_zone(position_cell: &ElementRef, legend: &HashMap<String, String>) -> Option<Zone> {
+    let el = position_cell.value();
+
+    if let Some(zone) = el.attr("title").and_then(Zone::from_label) {
+        return Some(zone);
+    }
+
+    el.classes()
+        .filter(|c| c.starts_with("tbl_"))
+        .find_map(|c| legend.get(c))
+        .and_then(|label| Zone::from_label(label))
+}
+
+/// Column positions of the top scorers table, read from its header row.
+struct ScorerColumns {
+    player: usize,
+    team: usize,
+    goals: usize,
+    penalties: Option<usize>,
+    assists: Option<usize>,
+    appearances: Option<usize>,
+    minutes: Option<usize>,
+}
+
+impl ScorerColumns {
+    /// Layout of the plain LiveSoccerTV table: Player | Team | Goals | Penalties.
+    fn default_layout() -> Self {
+        Self {
+            player: 0,
+            team: 1,
+            goals: 2,
+            penalties: Some(3),
+            assists: None,
+            appearances: None,
+            minutes: None,
+        }
+    }
+
+    /// Maps header labels (text or `title`) to columns by whole words, so
+    /// short mobile labels such as "G", "Pen", "A" and "MP" are recognised
+    /// too. Derived columns ("Non-penalty goals", "Goals per game", "xG")
+    /// are left out; the counts they come from are what's read.
+    fn from_headers(headers: &[String]) -> Self {
+        let mut columns = Self::default_layout();
+        columns.penalties = None;
+
+        for (idx, header) in headers.iter().enumerate() {
+            let h = header.to_lowercase();
+            let words: Vec<&str> = h.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+            let has = |names: &[&str]| words.iter().any(|w| names.contains(w));
+
+            if has(&["non", "per", "ratio", "average", "avg", "xg", "expected"]) {
+                continue;
+            } else if has(&["player", "name"]) {
+                columns.player = idx;
+            } else if has(&["team", "club"]) {
+                columns.team = idx;
+            } else if has(&["penalties", "penalty", "pen", "pens", "pk"]) {
+                columns.penalties = Some(idx);
+            } else if has(&["assists", "assist", "a", "ast"]) {
+                columns.assists = Some(idx);
+            } else if has(&["minutes", "minute", "min", "mins"]) {
+                columns.minutes = Some(idx);
+            } else if has(&["appearances", "apps", "matches", "played", "mp", "gp"]) {
+                columns.appearances = Some(idx);
+            } else if has(&["goals", "goal", "g"]) {
+                columns.goals = idx;
+            }
+        }
+
+        columns
+    }
+}
+
 fn parse_top_scorers(document: &Html) -> Vec<TopScorer> {
     let mut scorers = Vec::new();
 
     let table_sel = Selector::parse("table#topscorers-table, table#top_scorers, table.scorers").expect("Invalid selector");
     let tr_sel = Selector::parse("tbody tr").expect("Invalid selector");
+    let th_sel = Selector::parse("th").expect("Invalid selector");
     let td_sel = Selector::parse("td").expect("Invalid selector");
 
     let table = match document.select(&table_sel).next() {
@@ -263,19 +620,49 @@ fn parse_top_scorers(document: &Html) -> Vec<TopScorer> {
         None => return scorers,
     };
 
+    // Header labels combine the visible text with the `title` tooltip
+    let headers: Vec<String> = table
+        .select(&th_sel)
+        .map(|th| {
+            let text = th.text().collect::<Vec<_>>().join(" ");
+            format!("{} {}", text.trim(), th.value().attr("title").unwrap_or(""))
+        })
+        .collect();
+
+    let columns = if headers.is_empty() {
+        ScorerColumns::default_layout()
+    } else {
+        ScorerColumns::from_headers(&headers)
+    };
+
     for row in table.select(&tr_sel) {
         let cells: Vec<_> = row.select(&td_sel).collect();
         if cells.len() < 3 {
             continue;
         }
 
-        let text = |idx: usize| cells[idx].text().collect::<String>().trim().to_string();
+        let text = |idx: usize| {
+            cells
+                .get(idx)
+                .map(|c| c.text().collect::<String>().trim().to_string())
+                .unwrap_or_default()
+        };
+        // Counts may carry thousands separators or a unit ("1,234 min"); a
+        // decimal such as "0.75" isn't one
+        let number = |idx: usize| -> Option<u32> {
+            let cell = text(idx);
+            cell.split_whitespace().next()?.replace(',', "").parse().ok()
+        };
+        let count = |idx: usize| number(idx).and_then(|n| u16::try_from(n).ok());
 
         scorers.push(TopScorer {
-            player: text(0),
-            team: text(1),
-            goals: text(2).parse().unwrap_or(0),
-            penalties: if cells.len() > 3 { text(3).parse().unwrap_or(0) } else { 0 },
+            player: text(columns.player),
+            team: text(columns.team),
+            goals: count(columns.goals).unwrap_or(0),
+            penalties: columns.penalties.and_then(count).unwrap_or(0),
+            assists: columns.assists.and_then(count),
+            appearances: columns.appearances.and_then(count),
+            minutes: columns.minutes.and_then(number),
         });
     }
 
@@ -288,7 +675,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_fetch_live() {
-        let res = fetch_league_stats("https://www.livesoccertv.com/competitions/england/premier-league/").await;
+        let res = fetch_league_stats(
+            "https://www.livesoccertv.com/competitions/england/premier-league/",
+            DisplayZone::Local,
+        )
+        .await;
         println!("RESULT: {:?}", res);
         assert!(res.is_ok());
     }
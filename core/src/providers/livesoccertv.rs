@@ -1,21 +1,40 @@
 use crate::error::AppError;
-use crate::models::TopMatch;
-use chrono::{Local, TimeZone, Utc};
-use scraper::{Html, Selector};
+use crate::models::{MatchStatus, TopMatch};
+use crate::utils::time::{self, DisplayZone};
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+use chrono_tz::US::Eastern;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
 use wreq::Client;
 use wreq_util::Emulation;
 
 const LIVESOCCERTV_URL: &str = "https://www.livesoccertv.com/schedules/";
 
+/// Zone LiveSoccerTV lists kickoffs in, for schedules and league pages alike.
+pub const SOURCE_ZONE: Tz = Eastern;
+
+pub async fn fetch_top_matches(zone: DisplayZone) -> Result<Vec<TopMatch>, AppError> {
+    let body = fetch_page(LIVESOCCERTV_URL).await?;
+    parse_html(&body, zone)
+}
+
+/// Every match listed on the schedule for `date`, not just the featured ones.
+pub async fn fetch_schedule(date: NaiveDate, zone: DisplayZone) -> Result<Vec<TopMatch>, AppError> {
+    let url = format!("{}{}/", LIVESOCCERTV_URL, date.format("%Y-%m-%d"));
+    let body = fetch_page(&url).await?;
+    parse_schedule(&body, date, zone)
+}
+
 /// Fetches the HTML using wreq with Chrome TLS emulation (bypasses Cloudflare fingerprinting).
-pub async fn fetch_top_matches() -> Result<Vec<TopMatch>, AppError> {
+pub(crate) async fn fetch_page(url: &str) -> Result<String, AppError> {
     let client = Client::builder()
         .emulation(Emulation::Chrome136)
         .build()
         .map_err(|e| AppError::NoMatchesScheduled(format!("Failed to build HTTP client: {}", e)))?;
 
     let response = client
-        .get(LIVESOCCERTV_URL)
+        .get(url)
         .send()
         .await
         .map_err(|e| AppError::NoMatchesScheduled(format!("Request failed: {}", e)))?;
@@ -38,25 +57,17 @@ pub async fn fetch_top_matches() -> Result<Vec<TopMatch>, AppError> {
         ));
     }
 
-    parse_html(&body)
+    Ok(body)
 }
 
-/// Converts a Unix millisecond timestamp to a local date string like "Sat 21 Feb 2026"
-/// and time string like "18:30".
-fn timestamp_to_local(millis: i64) -> (String, String) {
-    let utc_dt = Utc.timestamp_millis_opt(millis);
-    match utc_dt.single() {
-        Some(dt) => {
-            let local_dt = dt.with_timezone(&Local);
-            let date = local_dt.format("%a %d %b %Y").to_string();
-            let time = local_dt.format("%H:%M").to_string();
-            (date, time)
-        }
-        None => ("Unknown date".to_string(), "??:??".to_string()),
-    }
+/// Converts a Unix millisecond timestamp to a date string like "Sat 21 Feb 2026"
+/// and time string like "18:30". LiveSoccerTV itself lists kickoffs in Eastern time.
+fn timestamp_to_display(millis: i64, zone: DisplayZone) -> (String, String) {
+    time::convert_millis_to_display(millis, zone, SOURCE_ZONE)
+        .unwrap_or_else(|| ("Unknown date".to_string(), "??:??".to_string()))
 }
 
-pub fn parse_html(body: &str) -> Result<Vec<TopMatch>, AppError> {
+pub fn parse_html(body: &str, zone: DisplayZone) -> Result<Vec<TopMatch>, AppError> {
     let document = Html::parse_document(body);
 
     let fheader_selector = Selector::parse("div.fheader").expect("Invalid selector");
@@ -93,11 +104,12 @@ pub fn parse_html(body: &str) -> Result<Vec<TopMatch>, AppError> {
                                 let span = el_ref.select(&span_selector).next();
 
                                 // Extract date and time from the `dv` attribute (Unix millis)
-                                let (date, time) = span
+                                let millis = span
                                     .as_ref()
                                     .and_then(|s| s.value().attr("dv"))
-                                    .and_then(|dv| dv.parse::<i64>().ok())
-                                    .map(timestamp_to_local)
+                                    .and_then(|dv| dv.parse::<i64>().ok());
+                                let (date, time) = millis
+                                    .map(|millis| timestamp_to_display(millis, zone))
                                     .unwrap_or_else(|| {
                                         // Fallback: use the text content of the span
                                         let fallback_date = span
@@ -125,7 +137,11 @@ pub fn parse_html(body: &str) -> Result<Vec<TopMatch>, AppError> {
                                             teams,
                                             date,
                                             time,
+                                            kickoff: millis.and_then(time::millis_to_utc),
                                             match_url,
+                                            competition: None,
+                                            channels: Vec::new(),
+                                            status: MatchStatus::Upcoming,
                                         });
                                     }
                                 }
@@ -145,5 +161,169 @@ pub fn parse_html(body: &str) -> Result<Vec<TopMatch>, AppError> {
         ));
     }
 
+    // The top matches block only links to the match; competition, channels
+    // and status come from the same match's row in the schedule listing.
+    let listed: HashMap<String, TopMatch> = parse_schedule_rows(&document, zone)
+        .into_iter()
+        .filter_map(|row| Some((row.match_id()?.to_string(), row)))
+        .collect();
+    for m in &mut matches {
+        if let Some(row) = m.match_id().and_then(|id| listed.get(id)) {
+            m.competition = row.competition.clone();
+            m.channels = row.channels.clone();
+            m.status = row.status;
+        }
+    }
+
     Ok(matches)
 }
+
+/// Parses a `/schedules/YYYY-MM-DD/` page into every listed match, in page
+/// order. Rows without a kick-off timestamp (e.g. postponed) keep `date`.
+pub fn parse_schedule(body: &str, date: NaiveDate, zone: DisplayZone) -> Result<Vec<TopMatch>, AppError> {
+    let document = Html::parse_document(body);
+    let mut matches = parse_schedule_rows(&document, zone);
+
+    if matches.is_empty() {
+        return Err(AppError::NoMatchesScheduled(format!(
+            "No matches listed for {}",
+            date.format("%a %d %b %Y")
+        )));
+    }
+
+    for m in &mut matches {
+        if m.date.is_empty() {
+            m.date = date.format("%a %d %b %Y").to_string();
+        }
+    }
+
+    Ok(matches)
+}
+
+/// Reads every match row of `table.schedules`. Competition header rows
+/// (`tr.sortable_comp`) apply to every match row below them.
+fn parse_schedule_rows(document: &Html, zone: DisplayZone) -> Vec<TopMatch> {
+    let row_selector = Selector::parse("table.schedules tr").expect("Invalid selector");
+    let comp_selector = Selector::parse("span.flag").expect("Invalid selector");
+    let link_selector = Selector::parse("td#match a").expect("Invalid selector");
+    let kickoff_selector = Selector::parse("span.ts").expect("Invalid selector");
+    let channel_selector = Selector::parse("td#channels a").expect("Invalid selector");
+
+    let mut rows = Vec::new();
+    let mut competition: Option<String> = None;
+
+    for tr in document.select(&row_selector) {
+        let classes: Vec<&str> = tr.value().classes().collect();
+
+        if classes.contains(&"sortable_comp") {
+            competition = tr
+                .select(&comp_selector)
+                .next()
+                .map(|span| span.text().collect::<String>().trim().to_string())
+                .filter(|name| !name.is_empty());
+            continue;
+        }
+
+        if !classes.contains(&"matchrow") {
+            continue;
+        }
+        let Some(link) = tr.select(&link_selector).next() else {
+            continue;
+        };
+        let teams = schedule_teams(&link);
+        if teams.is_empty() {
+            continue;
+        }
+
+        let millis = tr
+            .select(&kickoff_selector)
+            .find_map(|ts| ts.value().attr("dv"))
+            .and_then(|dv| dv.parse::<i64>().ok());
+        let (date, time) = millis
+            .map(|millis| timestamp_to_display(millis, zone))
+            .unwrap_or_else(|| (String::new(), "??:??".to_string()));
+
+        let channels = tr
+            .select(&channel_selector)
+            .map(|a| {
+                a.value()
+                    .attr("title")
+                    .map(str::to_string)
+                    .unwrap_or_else(|| a.text().collect::<String>())
+                    .trim()
+                    .to_string()
+            })
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        rows.push(TopMatch {
+            teams,
+            date,
+            time,
+            kickoff: millis.and_then(time::millis_to_utc),
+            match_url: link.value().attr("href").unwrap_or("").to_string(),
+            competition: competition.clone(),
+            channels,
+            status: parse_status(&tr),
+        });
+    }
+
+    rows
+}
+
+/// "Home - Away", the same form the top matches block uses. The link title
+/// reads "Home vs Away"; its text may carry the score in between instead.
+fn schedule_teams(link: &ElementRef) -> String {
+    let sides = link
+        .value()
+        .attr("title")
+        .and_then(|title| title.split_once(" vs "))
+        .map(|(home, away)| (home.trim().to_string(), away.trim().to_string()));
+
+    match sides {
+        Some((home, away)) if !home.is_empty() && !away.is_empty() => {
+            format!("{} - {}", home, away)
+        }
+        _ => link
+            .text()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(" vs ", " - "),
+    }
+}
+
+/// Postponed matches replace the kick-off time with "Postp.", finished ones
+/// carry a `livecell ft` marker and anything else whose kick-off span is
+/// flagged `started` is in progress.
+pub(crate) fn parse_status(row: &ElementRef) -> MatchStatus {
+    let timecell_selector = Selector::parse("span.timecell").expect("Invalid selector");
+    let livecell_selector = Selector::parse("span.livecell").expect("Invalid selector");
+    let kickoff_selector = Selector::parse("span.ts").expect("Invalid selector");
+
+    let postponed = row.select(&timecell_selector).any(|cell| {
+        let title = cell.value().attr("title").unwrap_or("").to_lowercase();
+        let text = cell.text().collect::<String>().to_lowercase();
+        title.contains("postponed") || text.contains("postp")
+    });
+    if postponed {
+        return MatchStatus::Postponed;
+    }
+
+    let finished = row
+        .select(&livecell_selector)
+        .any(|cell| cell.value().classes().any(|c| c == "ft"));
+    if finished {
+        return MatchStatus::Finished;
+    }
+
+    let started = row
+        .select(&kickoff_selector)
+        .any(|ts| ts.value().classes().any(|c| c == "started"));
+    if started {
+        MatchStatus::Live
+    } else {
+        MatchStatus::Upcoming
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::AppError;
+use crate::models::MatchDetails;
+use crate::providers::livesoccertv::{self, SOURCE_ZONE};
+use crate::utils::time::{self, DisplayZone};
+use scraper::{Html, Selector};
+
+const LIVESOCCERTV_ORIGIN: &str = "https://www.livesoccertv.com";
+
+/// Fetches and parses a LiveSoccerTV match page. Accepts the relative
+/// `match_url` carried by [`TopMatch`](crate::models::TopMatch) as well as
+/// a full URL.
+pub async fn fetch_match_details(match_url: &str, zone: DisplayZone) -> Result<MatchDetails, AppError> {
+    let url = absolute_url(match_url);
+    let body = livesoccertv::fetch_page(&url).await?;
+    parse_html(&body, &url, zone)
+}
+
+/// "/match/arsenal-vs-chelsea/17gw2#200" becomes a full livesoccertv.com URL;
+/// anything else is returned as is.
+pub fn absolute_url(match_url: &str) -> String {
+    let match_url = match_url.trim();
+    if match_url.starts_with('/') {
+        format!("{}{}", LIVESOCCERTV_ORIGIN, match_url)
+    } else {
+        match_url.to_string()
+    }
+}
+
+pub fn parse_html(body: &str, url: &str, zone: DisplayZone) -> Result<MatchDetails, AppError> {
+    let document = Html::parse_document(body);
+
+    let teams = page_teams(&document).ok_or_else(|| {
+        AppError::ProviderError(format!("No match found at {}", url))
+    })?;
+
+    let kickoff_selector = Selector::parse("span.ts").expect("Invalid selector");
+    let millis = document
+        .select(&kickoff_selector)
+        .find_map(|ts| ts.value().attr("dv"))
+        .and_then(|dv| dv.parse::<i64>().ok());
+    let (date, time) = millis
+        .and_then(|millis| time::convert_millis_to_display(millis, zone, SOURCE_ZONE))
+        .unwrap_or_else(|| ("Unknown date".to_string(), "??:??".to_string()));
+
+    Ok(MatchDetails {
+        teams,
+        competition: page_competition(&document),
+        date,
+        time,
+        kickoff: millis.and_then(time::millis_to_utc),
+        status: livesoccertv::parse_status(&document.root_element()),
+        channels: page_channels(&document),
+        url: url.to_string(),
+    })
+}
+
+/// "Home - Away" from the page heading, falling back to the `<title>`,
+/// which reads "Home vs Away Live Stream & TV Listings ...".
+fn page_teams(document: &Html) -> Option<String> {
+    let heading_selector = Selector::parse("h1").expect("Invalid selector");
+    let title_selector = Selector::parse("title").expect("Invalid selector");
+
+    document
+        .select(&heading_selector)
+        .chain(document.select(&title_selector))
+        .map(|el| el.text().collect::<Vec<_>>().join(" "))
+        .find_map(|text| {
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            let (home, rest) = text.split_once(" vs ")?;
+            let away = rest
+                .split([',', '|'])
+                .next()
+                .unwrap_or(rest)
+                .split(" Live")
+                .next()
+                .unwrap_or(rest)
+                .trim();
+            let home = home.trim();
+            (!home.is_empty() && !away.is_empty()).then(|| format!("{} - {}", home, away))
+        })
+}
+
+/// The breadcrumb links the country ("/competitions/england/") before the
+/// competition itself, so the deepest link wins.
+fn page_competition(document: &Html) -> Option<String> {
+    let link_selector = Selector::parse("a[href*='/competitions/']").expect("Invalid selector");
+
+    document
+        .select(&link_selector)
+        .filter_map(|a| {
+            let name = a.text().collect::<String>().trim().to_string();
+            let depth = a.value().attr("href")?.trim_matches('/').split('/').count();
+            (!name.is_empty()).then_some((depth, name))
+        })
+        .rev()
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, name)| name)
+}
+
+/// Broadcasters from the channel listing table, or from every channel link
+/// on the page when the table is missing. Duplicates are dropped; the same
+/// channel often airs a match in several countries.
+fn page_channels(document: &Html) -> Vec<String> {
+    let table_selector = Selector::parse("table#wc_channels a[href*='/channels/']").expect("Invalid selector");
+    let link_selector = Selector::parse("a[href*='/channels/']").expect("Invalid selector");
+
+    let mut links: Vec<_> = document.select(&table_selector).collect();
+    if links.is_empty() {
+        links = document.select(&link_selector).collect();
+    }
+
+    let mut channels: Vec<String> = Vec::new();
+    for a in links {
+        let name = a
+            .value()
+            .attr("title")
+            .map(str::to_string)
+            .unwrap_or_else(|| a.text().collect::<String>())
+            .trim()
+            .to_string();
+        if !name.is_empty() && !channels.contains(&name) {
+            channels.push(name);
+        }
+    }
+    channels
+}
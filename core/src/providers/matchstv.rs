@@ -2,12 +2,32 @@ use super::FootballProvider;
 use crate::error::AppError;
 use crate::models::{Country, Match};
 use async_trait::async_trait;
-use chrono::{Datelike, Local, NaiveDate, NaiveTime, TimeZone};
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::dates;
+use crate::utils::time::{self, DisplayZone};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
 use chrono_tz::Europe::Paris;
 use scraper::{Html, Selector};
+use std::sync::Arc;
 use wreq::StatusCode;
 
-pub struct MatchsTvProvider;
+pub struct MatchsTvProvider {
+    zone: DisplayZone,
+    clock: Arc<dyn Clock>,
+}
+
+impl MatchsTvProvider {
+    pub fn new(zone: DisplayZone, clock: Arc<dyn Clock>) -> Self {
+        Self { zone, clock }
+    }
+}
+
+impl Default for MatchsTvProvider {
+    fn default() -> Self {
+        Self::new(DisplayZone::default(), Arc::new(SystemClock))
+    }
+}
 
 #[async_trait]
 impl FootballProvider for MatchsTvProvider {
@@ -19,10 +39,14 @@ impl FootballProvider for MatchsTvProvider {
         "Matchs.tv Scraper"
     }
 
-    async fn fetch_matches_channels(&self, team_name: &str) -> Result<Vec<Match>, AppError> {
+    fn team_url(&self, team_name: &str) -> String {
         // Pattern: https://matchs.tv/club/manchester-united/
         let formatted_name = team_name.trim().to_lowercase().replace(" ", "-");
-        let url = format!("https://matchs.tv/club/{}/", formatted_name);
+        format!("https://matchs.tv/club/{}/", formatted_name)
+    }
+
+    async fn fetch_matches_channels(&self, team_name: &str) -> Result<Vec<Match>, AppError> {
+        let url = self.team_url(team_name);
 
         let client = wreq::Client::builder()
             .emulation(wreq_util::Emulation::Chrome136)
@@ -37,14 +61,15 @@ impl FootballProvider for MatchsTvProvider {
 
         let body = response.text().await?;
 
-        parse_html(&body, team_name)
+        parse_html(&body, team_name, self.zone, self.clock.as_ref())
     }
 }
 
 /// Parse raw HTML from Matchs.tv and extract match data.
 /// Separated from the HTTP layer for testability.
-pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
+pub fn parse_html(body: &str, team_name: &str, zone: DisplayZone, clock: &dyn Clock) -> Result<Vec<Match>, AppError> {
     let document = Html::parse_document(body);
+    let today = clock.today_in(Paris);
 
     let mut matches = Vec::new();
 
@@ -67,7 +92,7 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
                 .join(" ")
                 .trim()
                 .to_string();
-            if let Some((formatted, naive)) = parse_french_date(&raw_date) {
+            if let Some((formatted, naive)) = parse_french_date(&raw_date, today) {
                 current_date_str = formatted;
                 current_naive_date = Some(naive);
             } else {
@@ -85,14 +110,9 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
                 .trim()
                 .to_string();
 
-            let (date_display, time_display) = if let Some(naive_date) = current_naive_date {
-                if let Some((local_date, local_time)) =
-                    convert_french_time_to_local(naive_date, &raw_time)
-                {
-                    (local_date, local_time)
-                } else {
-                    (current_date_str.clone(), raw_time)
-                }
+            let kickoff = current_naive_date.and_then(|date| french_kickoff(date, &raw_time));
+            let (date_display, time_display) = if let Some(paris_datetime) = kickoff {
+                time::format_in_zone(&paris_datetime, zone, Paris)
             } else {
                 (current_date_str.clone(), raw_time)
             };
@@ -127,6 +147,7 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
                     competition,
                     date: date_display,
                     time: time_display,
+                    kickoff: kickoff.map(|dt| dt.with_timezone(&Utc)),
                     channels,
                 });
             }
@@ -140,54 +161,34 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
     Ok(matches)
 }
 
-pub fn parse_french_date(french_date: &str) -> Option<(String, NaiveDate)> {
-    // Input: "samedi 7 février"
+/// Parses a year-less header such as "samedi 7 février", placing it in the
+/// year inferred from `today`.
+pub fn parse_french_date(french_date: &str, today: NaiveDate) -> Option<(String, NaiveDate)> {
     let parts: Vec<&str> = french_date.split_whitespace().collect();
     if parts.len() < 3 {
         return None;
     }
 
     let day_num = parts[1].parse::<u32>().ok()?;
-    let month_str = parts[2].to_lowercase();
-
-    let month = match month_str.as_str() {
-        "janvier" => 1,
-        "février" | "fevrier" => 2,
-        "mars" => 3,
-        "avril" => 4,
-        "mai" => 5,
-        "juin" => 6,
-        "juillet" => 7,
-        "août" | "aout" => 8,
-        "septembre" => 9,
-        "octobre" => 10,
-        "novembre" => 11,
-        "décembre" | "decembre" => 12,
-        _ => return None,
-    };
-
-    let current_date = Local::now().date_naive();
-    let current_year = current_date.year();
-
-    let mut date = NaiveDate::from_ymd_opt(current_year, month, day_num)?;
-
-    if date < current_date - chrono::Duration::days(30) {
-        date = NaiveDate::from_ymd_opt(current_year + 1, month, day_num)?;
-    }
+    let month = dates::month_from_name(parts[2])?;
+    let date = dates::infer_year(month, day_num, today)?;
 
     Some((date.format("%a %d %b %Y").to_string(), date))
 }
 
-pub fn convert_french_time_to_local(date: NaiveDate, time_str: &str) -> Option<(String, String)> {
+/// Reads a "21h00" kickoff on `date`, Paris time.
+pub fn french_kickoff(date: NaiveDate, time_str: &str) -> Option<DateTime<Tz>> {
     let clean_time = time_str.replace("h", ":");
     let time = NaiveTime::parse_from_str(&clean_time, "%H:%M").ok()?;
 
-    let naive_datetime = date.and_time(time);
-    let paris_datetime = Paris.from_local_datetime(&naive_datetime).single()?;
-    let local_time = paris_datetime.with_timezone(&Local);
+    dates::kickoff_in(Paris, date, time)
+}
 
-    Some((
-        local_time.format("%a %d %b %Y").to_string(),
-        local_time.format("%H:%M").to_string(),
-    ))
+pub fn convert_french_time_to_display(
+    date: NaiveDate,
+    time_str: &str,
+    zone: DisplayZone,
+) -> Option<(String, String)> {
+    let paris_datetime = french_kickoff(date, time_str)?;
+    Some(time::format_in_zone(&paris_datetime, zone, Paris))
 }
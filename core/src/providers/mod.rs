@@ -4,6 +4,7 @@ use async_trait::async_trait;
 
 pub mod league_stats;
 pub mod livesoccertv;
+pub mod match_details;
 pub mod matchstv;
 pub mod wheresthematch;
 pub mod worldsoccertalk;
@@ -12,6 +13,11 @@ pub mod worldsoccertalk;
 #[async_trait]
 pub trait FootballProvider: Send + Sync {
     async fn fetch_matches_channels(&self, team: &str) -> Result<Vec<Match>, AppError>;
+    /// The page `team`'s listings are scraped from; empty when the provider
+    /// doesn't say.
+    fn team_url(&self, _team: &str) -> String {
+        String::new()
+    }
     fn country(&self) -> Country;
     fn name(&self) -> &str;
 }
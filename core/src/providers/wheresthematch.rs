@@ -1,12 +1,23 @@
 use super::FootballProvider;
 use crate::error::AppError;
 use crate::models::{Country, Match};
-use crate::utils::time;
+use crate::utils::time::{self, DisplayZone};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::London;
 use scraper::{Html, Selector};
 use wreq::StatusCode;
 
-pub struct WheresTheMatchProvider;
+#[derive(Default)]
+pub struct WheresTheMatchProvider {
+    zone: DisplayZone,
+}
+
+impl WheresTheMatchProvider {
+    pub fn new(zone: DisplayZone) -> Self {
+        Self { zone }
+    }
+}
 
 #[async_trait]
 impl FootballProvider for WheresTheMatchProvider {
@@ -18,12 +29,16 @@ impl FootballProvider for WheresTheMatchProvider {
         "WheresTheMatch Scraper"
     }
 
-    async fn fetch_matches_channels(&self, team_name: &str) -> Result<Vec<Match>, AppError> {
+    fn team_url(&self, team_name: &str) -> String {
         let formatted_name = team_name.trim().replace(" ", "-");
-        let url = format!(
+        format!(
             "https://www.wheresthematch.com/Football/{}.asp",
             formatted_name
-        );
+        )
+    }
+
+    async fn fetch_matches_channels(&self, team_name: &str) -> Result<Vec<Match>, AppError> {
+        let url = self.team_url(team_name);
 
         let client = wreq::Client::builder()
             .emulation(wreq_util::Emulation::Chrome136)
@@ -45,13 +60,14 @@ impl FootballProvider for WheresTheMatchProvider {
 
         let body = response.text().await?;
 
-        parse_html(&body, team_name)
+        parse_html(&body, team_name, self.zone)
     }
 }
 
 /// Parse raw HTML from WheresTheMatch and extract match data.
 /// Separated from the HTTP layer for testability.
-pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
+/// Kickoffs come as UTC timestamps; the site itself shows UK time.
+pub fn parse_html(body: &str, team_name: &str, zone: DisplayZone) -> Result<Vec<Match>, AppError> {
     // SPECIFIC CHECK FOR "Invalid URL Format"
     if body.contains("Invalid URL Format") {
         return Err(AppError::TeamNotFound(team_name.to_string()));
@@ -90,10 +106,17 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
                 .map(|el| el.text().collect::<Vec<_>>().join(" ").trim().to_string())
                 .unwrap_or_default();
 
+            let kickoff = row
+                .select(&time_selector)
+                .next()
+                .and_then(|el| el.value().attr("content"))
+                .and_then(|iso| DateTime::parse_from_rfc3339(iso).ok())
+                .map(|dt| dt.with_timezone(&Utc));
+
             let (date, time) = if let Some(time_el) = row.select(&time_selector).next() {
                 let iso_string = time_el.value().attr("content");
                 if let Some(iso) = iso_string {
-                    if let Some((local_date, local_time)) = time::convert_utc_to_local(iso) {
+                    if let Some((local_date, local_time)) = time::convert_utc_to_display(iso, zone, London) {
                         (local_date, local_time)
                     } else {
                         parse_text_date(time_el)
@@ -119,6 +142,7 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
                 competition,
                 date,
                 time,
+                kickoff,
                 channels,
             });
         }
@@ -1,12 +1,31 @@
 use super::FootballProvider;
 use crate::error::AppError;
 use crate::models::{Country, Match};
-use crate::utils::time;
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::time::{self, DisplayZone};
+use chrono_tz::US::Eastern;
 use async_trait::async_trait;
+use chrono::Utc;
 use scraper::{Html, Selector};
+use std::sync::Arc;
 use wreq::StatusCode;
 
-pub struct WorldSoccerTalkProvider;
+pub struct WorldSoccerTalkProvider {
+    zone: DisplayZone,
+    clock: Arc<dyn Clock>,
+}
+
+impl WorldSoccerTalkProvider {
+    pub fn new(zone: DisplayZone, clock: Arc<dyn Clock>) -> Self {
+        Self { zone, clock }
+    }
+}
+
+impl Default for WorldSoccerTalkProvider {
+    fn default() -> Self {
+        Self::new(DisplayZone::default(), Arc::new(SystemClock))
+    }
+}
 
 #[async_trait]
 impl FootballProvider for WorldSoccerTalkProvider {
@@ -18,12 +37,16 @@ impl FootballProvider for WorldSoccerTalkProvider {
         "WorldSoccerTalk Scraper"
     }
 
-    async fn fetch_matches_channels(&self, team_name: &str) -> Result<Vec<Match>, AppError> {
+    fn team_url(&self, team_name: &str) -> String {
         let formatted_name = team_name.trim().to_lowercase().replace(" ", "-");
-        let url = format!(
+        format!(
             "https://worldsoccertalk.com/teams/{}-tv-schedule/",
             formatted_name
-        );
+        )
+    }
+
+    async fn fetch_matches_channels(&self, team_name: &str) -> Result<Vec<Match>, AppError> {
+        let url = self.team_url(team_name);
 
         let client = wreq::Client::builder()
             .emulation(wreq_util::Emulation::Chrome136)
@@ -38,14 +61,15 @@ impl FootballProvider for WorldSoccerTalkProvider {
 
         let body = response.text().await?;
 
-        parse_html(&body, team_name)
+        parse_html(&body, team_name, self.zone, self.clock.as_ref())
     }
 }
 
 /// Parse raw HTML from WorldSoccerTalk and extract match data.
 /// Separated from the HTTP layer for testability.
-pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
+pub fn parse_html(body: &str, team_name: &str, zone: DisplayZone, clock: &dyn Clock) -> Result<Vec<Match>, AppError> {
     let document = Html::parse_document(body);
+    let today = clock.today_in(Eastern);
 
     // Check if we actually have a schedule
     let date_selector = Selector::parse("h3.text-stvsDate").unwrap();
@@ -78,11 +102,10 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
                     .map(|el| el.text().collect::<Vec<_>>().join(" ").trim().to_string())
                     .unwrap_or_else(|| "Unknown Time".to_string());
 
-                // Try to convert ET to Local
-                let (date, time) = if let Some((local_date, local_time)) =
-                    time::convert_et_to_local(&current_date_str, &raw_time)
-                {
-                    (local_date, local_time)
+                // Try to convert ET to the display zone
+                let kickoff = time::et_kickoff(&current_date_str, &raw_time, today);
+                let (date, time) = if let Some(et_datetime) = kickoff {
+                    time::format_in_zone(&et_datetime, zone, Eastern)
                 } else {
                     // Fallback to raw string if parsing fails
                     (current_date_str.clone(), raw_time)
@@ -125,6 +148,7 @@ pub fn parse_html(body: &str, team_name: &str) -> Result<Vec<Match>, AppError> {
                     competition,
                     date,
                     time,
+                    kickoff: kickoff.map(|dt| dt.with_timezone(&Utc)),
                     channels,
                 });
             }
@@ -0,0 +1,46 @@
+/// Free-to-air channels of the covered countries, lowercase.
+const FREE_TO_AIR: &[&str] = &[
+    "bbc one", "bbc two", "bbc three", "bbc iplayer", "bbc sport", "itv1", "itv4", "itvx", "stv",
+    "channel 4", "channel 5", "s4c", "cbs", "nbc", "fox", "abc", "telemundo", "univision", "unimas",
+    "tf1", "france 2", "france 3", "m6", "w9", "l'equipe", "l'équipe",
+];
+
+/// Parts of the names of streaming services, lowercase.
+const STREAMING: &[&str] = &[
+    "prime video", "amazon", "dazn", "peacock", "paramount+", "espn+", "fubo", "apple tv", "discovery+",
+    "youtube", "sling", "now tv", "stream", "tnt sports app", "sky go", "canal+ app",
+];
+
+pub fn is_free_to_air(channel: &str) -> bool {
+    FREE_TO_AIR.contains(&channel.trim().to_lowercase().as_str())
+}
+
+/// How a channel is watched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelKind {
+    FreeToAir,
+    Streaming,
+    /// Subscription TV, and whatever isn't known to be one of the others.
+    Pay,
+}
+
+impl ChannelKind {
+    pub fn of(channel: &str) -> Self {
+        let name = channel.trim().to_lowercase();
+        if is_free_to_air(&name) {
+            ChannelKind::FreeToAir
+        } else if STREAMING.iter().any(|s| name.contains(s)) {
+            ChannelKind::Streaming
+        } else {
+            ChannelKind::Pay
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ChannelKind::FreeToAir => "free-to-air",
+            ChannelKind::Streaming => "streaming",
+            ChannelKind::Pay => "pay TV",
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveTime, TimeZone};
+use chrono_tz::Tz;
+
+/// How far in the past a year-less date may lie before it is read as next
+/// year's. Schedules keep recently played games around for a while, so a
+/// date a few weeks back is still "this season" rather than next year.
+pub const PAST_WINDOW_DAYS: i64 = 30;
+
+/// Month number for an English or French month name, full or abbreviated,
+/// with or without accents.
+pub fn month_from_name(name: &str) -> Option<u32> {
+    let month = match name.trim().trim_end_matches('.').to_lowercase().as_str() {
+        "january" | "jan" | "janvier" | "janv" => 1,
+        "february" | "feb" | "février" | "fevrier" | "févr" | "fevr" => 2,
+        "march" | "mar" | "mars" => 3,
+        "april" | "apr" | "avril" | "avr" => 4,
+        "may" | "mai" => 5,
+        "june" | "jun" | "juin" => 6,
+        "july" | "jul" | "juillet" | "juil" => 7,
+        "august" | "aug" | "août" | "aout" => 8,
+        "september" | "sep" | "sept" | "septembre" => 9,
+        "october" | "oct" | "octobre" => 10,
+        "november" | "nov" | "novembre" => 11,
+        "december" | "dec" | "décembre" | "decembre" | "déc" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// Places a year-less `month`/`day` in the year that makes it the earliest
+/// date no more than [`PAST_WINDOW_DAYS`] before `today`.
+///
+/// Looking at last year as well as next year matters around New Year: on
+/// 5 January, "28 December" is last week's game, not one eleven months away.
+/// Returns `None` for impossible dates such as 31 April. 29 February only
+/// exists in leap years, so it takes the first one among last, this and
+/// next year that fits the window, and `None` when none of them does.
+pub fn infer_year(month: u32, day: u32, today: NaiveDate) -> Option<NaiveDate> {
+    let cutoff = today - Duration::days(PAST_WINDOW_DAYS);
+    (today.year() - 1..=today.year() + 1)
+        .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+        .find(|date| *date >= cutoff)
+}
+
+/// Attaches `tz` to a wall-clock kickoff.
+///
+/// Around DST changes a local time can happen twice or not at all. A repeated
+/// time takes its first occurrence; a time skipped by the spring-forward jump
+/// is moved an hour forward, past the jump.
+pub fn kickoff_in(tz: Tz, date: NaiveDate, time: NaiveTime) -> Option<DateTime<Tz>> {
+    let naive = date.and_time(time);
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Some(dt),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest),
+        LocalResult::None => tz.from_local_datetime(&(naive + Duration::hours(1))).earliest(),
+    }
+}
@@ -0,0 +1,295 @@
+use crate::models::MatchStatus;
+use crate::utils::time::DisplayZone;
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use chrono_tz::Tz;
+
+/// Language kickoff dates are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+    De,
+    Es,
+}
+
+impl Locale {
+    /// Parses a language tag such as "fr", "fr-FR" or "de_AT".
+    pub fn parse(tag: &str) -> Option<Self> {
+        let language = tag.trim().split(['-', '_']).next()?.to_lowercase();
+        match language.as_str() {
+            "en" => Some(Locale::En),
+            "fr" => Some(Locale::Fr),
+            "de" => Some(Locale::De),
+            "es" => Some(Locale::Es),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+            Locale::De => "de",
+            Locale::Es => "es",
+        }
+    }
+
+    fn weekday(&self, index: usize) -> &'static str {
+        const EN: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        const FR: [&str; 7] = ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."];
+        const DE: [&str; 7] = ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."];
+        const ES: [&str; 7] = ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"];
+        match self {
+            Locale::En => EN[index],
+            Locale::Fr => FR[index],
+            Locale::De => DE[index],
+            Locale::Es => ES[index],
+        }
+    }
+
+    fn month(&self, index: usize) -> &'static str {
+        const EN: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+        const FR: [&str; 12] = [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ];
+        const DE: [&str; 12] = [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+            "Nov.", "Dez.",
+        ];
+        const ES: [&str; 12] = [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ];
+        match self {
+            Locale::En => EN[index],
+            Locale::Fr => FR[index],
+            Locale::De => DE[index],
+            Locale::Es => ES[index],
+        }
+    }
+
+    fn relative_day(&self, day: RelativeDay) -> &'static str {
+        match (self, day) {
+            (Locale::En, RelativeDay::Yesterday) => "Yesterday",
+            (Locale::En, RelativeDay::Today) => "Today",
+            (Locale::En, RelativeDay::Tonight) => "Tonight",
+            (Locale::En, RelativeDay::Tomorrow) => "Tomorrow",
+            (Locale::Fr, RelativeDay::Yesterday) => "Hier",
+            (Locale::Fr, RelativeDay::Today) => "Aujourd'hui",
+            (Locale::Fr, RelativeDay::Tonight) => "Ce soir",
+            (Locale::Fr, RelativeDay::Tomorrow) => "Demain",
+            (Locale::De, RelativeDay::Yesterday) => "Gestern",
+            (Locale::De, RelativeDay::Today) => "Heute",
+            (Locale::De, RelativeDay::Tonight) => "Heute Abend",
+            (Locale::De, RelativeDay::Tomorrow) => "Morgen",
+            (Locale::Es, RelativeDay::Yesterday) => "Ayer",
+            (Locale::Es, RelativeDay::Today) => "Hoy",
+            (Locale::Es, RelativeDay::Tonight) => "Esta noche",
+            (Locale::Es, RelativeDay::Tomorrow) => "Mañana",
+        }
+    }
+
+    fn day_unit(&self) -> &'static str {
+        match self {
+            Locale::Fr => "j",
+            Locale::De => "T",
+            Locale::En | Locale::Es => "d",
+        }
+    }
+}
+
+/// 24-hour ("20:45") or 12-hour ("8:45 PM") clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HourCycle {
+    #[default]
+    H24,
+    H12,
+}
+
+impl HourCycle {
+    /// Parses "24h" / "12h" (the "h" is optional).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().trim_end_matches(['h', 'H']) {
+            "24" => Some(HourCycle::H24),
+            "12" => Some(HourCycle::H12),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RelativeDay {
+    Yesterday,
+    Today,
+    Tonight,
+    Tomorrow,
+}
+
+/// Kickoffs from this hour on are "tonight" rather than "today".
+const EVENING_HOUR: u32 = 18;
+
+/// Renders kickoff instants for display. Providers keep the instant in UTC;
+/// everything shown to the user goes through here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KickoffFormat {
+    pub locale: Locale,
+    pub hour_cycle: HourCycle,
+    pub zone: DisplayZone,
+}
+
+impl KickoffFormat {
+    pub fn new(locale: Locale, hour_cycle: HourCycle, zone: DisplayZone) -> Self {
+        Self {
+            locale,
+            hour_cycle,
+            zone,
+        }
+    }
+
+    /// Full date, e.g. "Sat 21 Feb 2026", "sam. 21 févr. 2026",
+    /// "Sa., 21. Feb. 2026" or "sáb, 21 feb 2026". `source` is the zone
+    /// the provider's site lists its times in.
+    pub fn date(&self, kickoff: DateTime<Utc>, source: Tz) -> String {
+        let local = self.zone.convert(&kickoff, source);
+        let weekday = self.locale.weekday(local.weekday().num_days_from_monday() as usize);
+        let month = self.locale.month(local.month0() as usize);
+        match self.locale {
+            Locale::En => format!("{} {:02} {} {}", weekday, local.day(), month, local.year()),
+            Locale::Fr => format!("{} {} {} {}", weekday, local.day(), month, local.year()),
+            Locale::De => format!("{}, {}. {} {}", weekday, local.day(), month, local.year()),
+            Locale::Es => format!("{}, {} {} {}", weekday, local.day(), month, local.year()),
+        }
+    }
+
+    /// Kickoff time, e.g. "20:45", "20h45" in French, or "8:45 PM".
+    pub fn time(&self, kickoff: DateTime<Utc>, source: Tz) -> String {
+        let local = self.zone.convert(&kickoff, source);
+        match (self.hour_cycle, self.locale) {
+            (HourCycle::H24, Locale::Fr) => format!("{:02}h{:02}", local.hour(), local.minute()),
+            (HourCycle::H24, _) => format!("{:02}:{:02}", local.hour(), local.minute()),
+            (HourCycle::H12, locale) => {
+                let (pm, hour) = local.hour12();
+                let suffix = match (locale, pm) {
+                    (Locale::Es, false) => "a. m.",
+                    (Locale::Es, true) => "p. m.",
+                    (_, false) => "AM",
+                    (_, true) => "PM",
+                };
+                format!("{}:{:02} {}", hour, local.minute(), suffix)
+            }
+        }
+    }
+
+    /// "Today", "Tonight", "Tomorrow" or "Yesterday" relative to `now`,
+    /// otherwise the full date.
+    pub fn day(&self, kickoff: DateTime<Utc>, source: Tz, now: DateTime<Utc>) -> String {
+        match self.relative_day(kickoff, source, now) {
+            Some(day) => self.locale.relative_day(day).to_string(),
+            None => self.date(kickoff, source),
+        }
+    }
+
+    /// Day and time together, e.g. "Tonight 20:45" or "Tomorrow 15:00".
+    pub fn relative(&self, kickoff: DateTime<Utc>, source: Tz, now: DateTime<Utc>) -> String {
+        format!("{} {}", self.day(kickoff, source, now), self.time(kickoff, source))
+    }
+
+    fn relative_day(&self, kickoff: DateTime<Utc>, source: Tz, now: DateTime<Utc>) -> Option<RelativeDay> {
+        let local = self.zone.convert(&kickoff, source);
+        let today = self.zone.convert(&now, source).date_naive();
+        match (local.date_naive() - today).num_days() {
+            -1 => Some(RelativeDay::Yesterday),
+            0 if local.hour() >= EVENING_HOUR => Some(RelativeDay::Tonight),
+            0 => Some(RelativeDay::Today),
+            1 => Some(RelativeDay::Tomorrow),
+            _ => None,
+        }
+    }
+}
+
+/// Kickoff to final whistle, allowing for half time and stoppage time.
+const MATCH_MINUTES: i64 = 115;
+
+/// Kickoffs at most this far away count as imminent.
+const IMMINENT_MINUTES: i64 = 60;
+
+/// Where a match stands relative to now: "in 2h 15m", "LIVE" or
+/// "finished 1h ago".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Countdown {
+    /// Time left until kickoff.
+    Upcoming(Duration),
+    Live,
+    /// Time since the estimated final whistle.
+    Finished(Duration),
+    Postponed,
+}
+
+impl Countdown {
+    /// A status reported by the site wins; otherwise the phase is inferred
+    /// from the clock, since `Upcoming` is also what unknown statuses
+    /// default to.
+    pub fn at(kickoff: DateTime<Utc>, now: DateTime<Utc>, status: MatchStatus) -> Self {
+        let final_whistle = kickoff + Duration::minutes(MATCH_MINUTES);
+        match status {
+            MatchStatus::Postponed => Countdown::Postponed,
+            MatchStatus::Live => Countdown::Live,
+            MatchStatus::Finished => Countdown::Finished((now - final_whistle).max(Duration::zero())),
+            MatchStatus::Upcoming if now < kickoff => Countdown::Upcoming(kickoff - now),
+            MatchStatus::Upcoming if now < final_whistle => Countdown::Live,
+            MatchStatus::Upcoming => Countdown::Finished(now - final_whistle),
+        }
+    }
+
+    /// Kicks off within the next hour.
+    pub fn is_imminent(&self) -> bool {
+        matches!(self, Countdown::Upcoming(left) if *left <= Duration::minutes(IMMINENT_MINUTES))
+    }
+
+    pub fn label(&self, locale: Locale) -> String {
+        match (self, locale) {
+            (Countdown::Upcoming(left), Locale::Fr) => format!("dans {}", until(*left, locale)),
+            (Countdown::Upcoming(left), Locale::Es) => format!("en {}", until(*left, locale)),
+            (Countdown::Upcoming(left), _) => format!("in {}", until(*left, locale)),
+            (Countdown::Live, Locale::Fr) => "EN DIRECT".to_string(),
+            (Countdown::Live, Locale::Es) => "EN VIVO".to_string(),
+            (Countdown::Live, _) => "LIVE".to_string(),
+            (Countdown::Finished(ago), Locale::En) => format!("finished {} ago", since(*ago, locale)),
+            (Countdown::Finished(ago), Locale::Fr) => format!("terminé il y a {}", since(*ago, locale)),
+            (Countdown::Finished(ago), Locale::De) => format!("vor {} beendet", since(*ago, locale)),
+            (Countdown::Finished(ago), Locale::Es) => format!("terminó hace {}", since(*ago, locale)),
+            (Countdown::Postponed, Locale::En) => "Postponed".to_string(),
+            (Countdown::Postponed, Locale::Fr) => "Reporté".to_string(),
+            (Countdown::Postponed, Locale::De) => "Verschoben".to_string(),
+            (Countdown::Postponed, Locale::Es) => "Aplazado".to_string(),
+        }
+    }
+}
+
+/// Two largest units, rounded up so a match never reads "in 0m" before
+/// it starts: "2d 3h", "2h 15m", "45m".
+fn until(left: Duration, locale: Locale) -> String {
+    let minutes = (left.num_seconds() + 59) / 60;
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours, minutes) {
+        (0, 0, m) => format!("{}m", m),
+        (0, h, 0) => format!("{}h", h),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, 0, _) => format!("{}{}", d, locale.day_unit()),
+        (d, h, _) => format!("{}{} {}h", d, locale.day_unit(), h),
+    }
+}
+
+/// Largest unit only, rounded down: "45m", "1h", "2d".
+fn since(ago: Duration, locale: Locale) -> String {
+    if ago.num_days() > 0 {
+        format!("{}{}", ago.num_days(), locale.day_unit())
+    } else if ago.num_hours() > 0 {
+        format!("{}h", ago.num_hours())
+    } else {
+        format!("{}m", ago.num_minutes())
+    }
+}
@@ -1 +1,5 @@
+pub mod channels;
+pub mod clock;
+pub mod dates;
+pub mod format;
 pub mod time;
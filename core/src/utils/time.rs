@@ -1,32 +1,95 @@
-use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Datelike};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use crate::utils::dates;
+use chrono_tz::Tz;
 use chrono_tz::US::Eastern;
 
+/// Zone kickoff times are rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayZone {
+    /// The machine's zone.
+    #[default]
+    Local,
+    /// A configured IANA zone, e.g. `Europe/Bucharest`.
+    Named(Tz),
+    /// Whatever zone the source site lists its times in.
+    Source,
+}
+
+impl DisplayZone {
+    /// Parses an IANA name; "local" and "source" select the other variants.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "" | "local" => Some(DisplayZone::Local),
+            "source" => Some(DisplayZone::Source),
+            _ => name.trim().parse::<Tz>().ok().map(DisplayZone::Named),
+        }
+    }
+
+    /// `dt` as seen in this zone, where `source` is the zone the provider's
+    /// site uses.
+    pub fn convert<Z: TimeZone>(&self, dt: &DateTime<Z>, source: Tz) -> DateTime<FixedOffset> {
+        match self {
+            DisplayZone::Local => dt.with_timezone(&Local).fixed_offset(),
+            DisplayZone::Named(tz) => dt.with_timezone(tz).fixed_offset(),
+            DisplayZone::Source => dt.with_timezone(&source).fixed_offset(),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            DisplayZone::Local => "local time".to_string(),
+            DisplayZone::Named(tz) => tz.name().to_string(),
+            DisplayZone::Source => "broadcaster's time".to_string(),
+        }
+    }
+}
+
+/// Formats `dt` as ("Sat 21 Feb 2026", "18:30") in `zone`, where `source`
+/// is the zone the provider's site uses.
+pub fn format_in_zone<Z: TimeZone>(dt: &DateTime<Z>, zone: DisplayZone, source: Tz) -> (String, String) {
+    let local = zone.convert(dt, source);
+    (
+        local.format("%a %d %b %Y").to_string(),
+        local.format("%H:%M").to_string(),
+    )
+}
 
-pub fn convert_utc_to_local(iso_string: &str) -> Option<(String, String)> {
+/// Parses a Unix millisecond timestamp, as found in LiveSoccerTV's `dv`
+/// attributes.
+pub fn millis_to_utc(millis: i64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(millis).single()
+}
+
+/// Converts a Unix millisecond timestamp, as found in LiveSoccerTV's `dv`
+/// attributes.
+pub fn convert_millis_to_display(millis: i64, zone: DisplayZone, source: Tz) -> Option<(String, String)> {
+    let dt = millis_to_utc(millis)?;
+    Some(format_in_zone(&dt, zone, source))
+}
+
+pub fn convert_utc_to_display(iso_string: &str, zone: DisplayZone, source: Tz) -> Option<(String, String)> {
     let parsed_utc = DateTime::parse_from_rfc3339(iso_string).ok()?;
-    let local_time: DateTime<Local> = parsed_utc.with_timezone(&Local);
-    Some((
-        local_time.format("%a %d %b %Y").to_string(),
-        local_time.format("%H:%M").to_string()
-    ))
+    Some(format_in_zone(&parsed_utc, zone, source))
 }
-pub fn convert_et_to_local(date_str: &str, time_str: &str) -> Option<(String, String)> {
+
+/// Reads a WorldSoccerTalk kickoff such as ("Saturday, February 21",
+/// "9:30 AM ET"), inferring the year relative to `today` in New York.
+pub fn et_kickoff(date_str: &str, time_str: &str, today: NaiveDate) -> Option<DateTime<Tz>> {
     let clean_time = time_str.trim().trim_end_matches(" ET").trim();
     let time = NaiveTime::parse_from_str(clean_time, "%I:%M %p").ok()?;
-    let current_date = Local::now().date_naive();
-    let current_year = current_date.year();
 
-    let date_with_year_str = format!("{}, {}", date_str, current_year);
-    let mut date = NaiveDate::parse_from_str(&date_with_year_str, "%A, %B %d, %Y").ok()?;
-    if date < current_date - chrono::Duration::days(30) {
-        date = date.with_year(current_year + 1)?;
-    }
-    let naive_datetime = date.and_time(time);
-    let et_datetime = Eastern.from_local_datetime(&naive_datetime).single()?;
-    let local_time: DateTime<Local> = et_datetime.with_timezone(&Local);
-
-    Some((
-        local_time.format("%a %d %b %Y").to_string(),
-        local_time.format("%H:%M").to_string()
-    ))
+    // The weekday is ignored: it only holds for one year, which is exactly
+    // what is being inferred.
+    let month_day = date_str.split_once(',').map_or(date_str, |(_, rest)| rest);
+    let mut parts = month_day.split_whitespace();
+    let month = dates::month_from_name(parts.next()?)?;
+    let day = parts.next()?.parse::<u32>().ok()?;
+
+    let date = dates::infer_year(month, day, today)?;
+    dates::kickoff_in(Eastern, date, time)
+}
+
+pub fn convert_et_to_display(date_str: &str, time_str: &str, zone: DisplayZone, today: NaiveDate) -> Option<(String, String)> {
+    let et_datetime = et_kickoff(date_str, time_str, today)?;
+    Some(format_in_zone(&et_datetime, zone, Eastern))
 }
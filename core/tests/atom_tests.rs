@@ -0,0 +1,97 @@
+use foot_info_core::export::atom::{self, Revisions};
+use foot_info_core::models::Match;
+use foot_info_core::utils::clock::FixedClock;
+
+fn clock() -> FixedClock {
+    FixedClock("2026-02-10T08:00:00Z".parse().unwrap())
+}
+
+fn feed(team: &str, matches: &[Match]) -> String {
+    atom::matches_to_atom(team, matches, &mut Revisions::default(), &clock())
+}
+
+fn sample_match(kickoff: &str, channels: &[&str]) -> Match {
+    Match {
+        teams: "Arsenal v Chelsea".into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: Some(kickoff.parse().unwrap()),
+        channels: channels.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+fn tag<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+    let open = format!("<{}>", name);
+    let close = format!("</{}>", name);
+    xml.split(open.as_str())
+        .skip(1)
+        .filter_map(|rest| rest.split_once(close.as_str()).map(|(inner, _)| inner))
+        .collect()
+}
+
+#[test]
+fn test_matches_to_atom_writes_one_entry_per_match() {
+    let feed = feed("Arsenal", &[sample_match("2026-02-21T15:00:00Z", &["Sky Sports", "NBC"])]);
+
+    assert!(feed.starts_with("<?xml"));
+    assert!(feed.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+    assert_eq!(tag(&feed, "title"), ["Arsenal on TV", "Arsenal v Chelsea"]);
+    assert_eq!(tag(&feed, "updated"), ["2026-02-10T08:00:00Z", "2026-02-10T08:00:00Z"]);
+    assert_eq!(
+        tag(&feed, "id"),
+        ["urn:foot-info:feed:arsenal", "urn:foot-info:match:premier-league-2025-26-arsenal-v-chelsea"]
+    );
+    assert!(feed.contains("<content type=\"text\">Sat 21 Feb 2026 15:00 · Premier League · Sky Sports, NBC</content>"));
+}
+
+#[test]
+fn test_entry_id_survives_kickoff_and_channel_changes() {
+    let before = feed("Arsenal", &[sample_match("2026-02-21T15:00:00Z", &["Sky Sports"])]);
+    let after = feed("Arsenal", &[sample_match("2026-02-21T17:30:00Z", &["TNT Sports"])]);
+
+    assert_eq!(tag(&before, "id"), tag(&after, "id"));
+    assert_ne!(before, after);
+}
+
+#[test]
+fn test_entry_updated_moves_only_when_the_listing_changes() {
+    let mut revisions = Revisions::default();
+    let later = FixedClock("2026-02-12T09:30:00Z".parse().unwrap());
+    let arsenal = sample_match("2026-02-21T15:00:00Z", &["Sky Sports"]);
+    let mut spurs = sample_match("2026-02-28T12:30:00Z", &["Sky Sports"]);
+    spurs.teams = "Tottenham v Arsenal".into();
+
+    atom::matches_to_atom("Arsenal", &[arsenal.clone(), spurs.clone()], &mut revisions, &clock());
+    spurs.channels = vec!["TNT Sports".into()];
+    let feed = atom::matches_to_atom("Arsenal", &[arsenal, spurs], &mut revisions, &later);
+
+    assert_eq!(
+        tag(&feed, "updated"),
+        ["2026-02-12T09:30:00Z", "2026-02-10T08:00:00Z", "2026-02-12T09:30:00Z"]
+    );
+}
+
+#[test]
+fn test_listing_back_after_dropping_out_is_new_again() {
+    let mut revisions = Revisions::default();
+    let later = FixedClock("2026-02-12T09:30:00Z".parse().unwrap());
+    let arsenal = sample_match("2026-02-21T15:00:00Z", &["Sky Sports"]);
+
+    atom::matches_to_atom("Arsenal", std::slice::from_ref(&arsenal), &mut revisions, &clock());
+    atom::matches_to_atom("Arsenal", &[], &mut revisions, &clock());
+    let feed = atom::matches_to_atom("Arsenal", &[arsenal], &mut revisions, &later);
+
+    assert_eq!(tag(&feed, "updated"), ["2026-02-12T09:30:00Z", "2026-02-12T09:30:00Z"]);
+}
+
+#[test]
+fn test_text_is_escaped() {
+    let mut m = sample_match("2026-02-21T15:00:00Z", &[]);
+    m.teams = "Brighton & Hove Albion v <TBC>".into();
+    let feed = feed("Brighton & Hove Albion", &[m]);
+
+    assert!(feed.contains("<title>Brighton &amp; Hove Albion on TV</title>"));
+    assert!(feed.contains("<title>Brighton &amp; Hove Albion v &lt;TBC&gt;</title>"));
+    assert!(feed.contains("No TV info"));
+}
@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use foot_info_core::changes::{self, ChangeKind, Listing, SnapshotStore};
+use foot_info_core::models::{Country, LeagueFixture, Match};
+
+fn at(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+fn listing(teams: &str, kickoff: &str, channels: &[&str]) -> Listing {
+    Listing::from_match(&Match {
+        teams: teams.into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: Some(at(kickoff)),
+        channels: channels.iter().map(|c| c.to_string()).collect(),
+    })
+}
+
+fn kinds(changes: &[changes::Change]) -> Vec<(&str, &str)> {
+    changes.iter().map(|c| (c.kind.label(), c.listing.teams.as_str())).collect()
+}
+
+const NOW: &str = "2026-02-20T12:00:00Z";
+
+// ── diff tests ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_diff_reports_each_kind_of_change() {
+    let previous = vec![
+        listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"]),
+        listing("Arsenal v Spurs", "2026-02-28T15:00:00Z", &["TNT Sports"]),
+        listing("Arsenal v Everton", "2026-03-07T15:00:00Z", &[]),
+    ];
+    let current = vec![
+        listing("Arsenal v Chelsea", "2026-02-21T17:30:00Z", &["Sky Sports"]),
+        listing("Arsenal v Spurs", "2026-02-28T15:00:00Z", &["TNT Sports", "NBC"]),
+        listing("Arsenal v Fulham", "2026-03-14T15:00:00Z", &[]),
+    ];
+
+    let changes = changes::diff(&previous, &current, at(NOW));
+    assert_eq!(
+        kinds(&changes),
+        [
+            ("rescheduled", "Arsenal v Chelsea"),
+            ("channels changed", "Arsenal v Spurs"),
+            ("new", "Arsenal v Fulham"),
+            ("removed", "Arsenal v Everton"),
+        ]
+    );
+    assert_eq!(
+        changes[0].kind,
+        ChangeKind::Rescheduled {
+            from_date: "Sat 21 Feb 2026".into(),
+            from_time: "15:00".into(),
+            from_kickoff: Some(at("2026-02-21T15:00:00Z")),
+        }
+    );
+    assert_eq!(
+        changes[1].kind,
+        ChangeKind::ChannelsChanged {
+            added: vec!["NBC".into()],
+            removed: vec![],
+        }
+    );
+    assert_eq!(changes::summarize(&changes), "1 new, 1 rescheduled, 1 channels changed, 1 removed");
+}
+
+#[test]
+fn test_diff_of_identical_looks_is_empty() {
+    let look = vec![listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"])];
+    assert!(changes::diff(&look, &look, at(NOW)).is_empty());
+}
+
+#[test]
+fn test_played_matches_are_not_removed() {
+    let previous = vec![listing("Arsenal v Chelsea", "2026-02-19T15:00:00Z", &[])];
+    assert!(changes::diff(&previous, &[], at(NOW)).is_empty());
+}
+
+#[test]
+fn test_kickoff_text_in_another_zone_is_not_a_reschedule() {
+    let previous = vec![listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &[])];
+    let mut current = previous.clone();
+    current[0].time = "16:00".into();
+    assert!(changes::diff(&previous, &current, at(NOW)).is_empty());
+}
+
+#[test]
+fn test_repeated_pairings_are_told_apart() {
+    let previous = vec![
+        listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"]),
+        listing("Arsenal v Chelsea", "2026-04-11T15:00:00Z", &["TNT Sports"]),
+    ];
+    let mut current = previous.clone();
+    current[1] = listing("Arsenal v Chelsea", "2026-04-11T15:00:00Z", &["BBC One"]);
+    assert_ne!(previous[0].key, previous[1].key);
+
+    let changes = changes::diff(&previous, &current, at(NOW));
+    assert_eq!(kinds(&changes), [("channels changed", "Arsenal v Chelsea")]);
+    assert_eq!(changes[0].listing.channels, ["BBC One"]);
+}
+
+#[test]
+fn test_match_moved_to_another_day_is_rescheduled() {
+    let previous = vec![listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &[])];
+    let current = vec![listing("Arsenal v Chelsea", "2026-02-22T16:30:00Z", &[])];
+
+    assert_eq!(kinds(&changes::diff(&previous, &current, at(NOW))), [("rescheduled", "Arsenal v Chelsea")]);
+}
+
+#[test]
+fn test_fixtures_are_keyed_by_match_url() {
+    let fixture = |kickoff: &str| LeagueFixture {
+        home_team: "Burnley".into(),
+        away_team: "Chelsea".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: Some(at(kickoff)),
+        score: None,
+        channels: vec![],
+        match_url: "/match/123/burnley-v-chelsea/".into(),
+        matchday: Some(27),
+    };
+    let previous = [Listing::from_fixture(&fixture("2026-02-21T15:00:00Z"), "Premier League")];
+    let current = [Listing::from_fixture(&fixture("2026-02-22T14:00:00Z"), "Premier League")];
+
+    assert_eq!(kinds(&changes::diff(&previous, &current, at(NOW))), [("rescheduled", "Burnley v Chelsea")]);
+}
+
+// ── SnapshotStore tests ──────────────────────────────────────────────────
+
+#[test]
+fn test_store_reports_changes_since_last_look() {
+    let dir = std::env::temp_dir().join(format!("foot-info-snapshots-{}", std::process::id()));
+    let store = SnapshotStore::new(&dir);
+    let key = changes::team_key("Arsenal", Country::UK);
+    assert_eq!(key, "team-uk-arsenal");
+
+    let first = vec![listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"])];
+    assert!(store.record(&key, first.clone(), at(NOW)).unwrap().is_empty(), "First look has nothing to compare");
+
+    let second = vec![listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["TNT Sports"])];
+    let changes = store.record(&key, second.clone(), at(NOW)).unwrap();
+    assert_eq!(kinds(&changes), [("channels changed", "Arsenal v Chelsea")]);
+
+    // The second look is now the last one
+    assert!(store.record(&key, second, at(NOW)).unwrap().is_empty());
+    assert_eq!(store.load(&key).unwrap().taken_at, at(NOW));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_league_key_uses_competition_slug() {
+    assert_eq!(
+        changes::league_key("https://www.livesoccertv.com/competitions/england/premier-league/"),
+        "league-england-premier-league"
+    );
+}
@@ -0,0 +1,36 @@
+use foot_info_core::models::Match;
+use foot_info_core::utils::channels::{is_free_to_air, ChannelKind};
+
+fn listing(teams: &str) -> Match {
+    Match {
+        teams: teams.into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: None,
+        channels: vec![],
+    }
+}
+
+// ── Channel kinds ────────────────────────────────────────────────────────
+
+#[test]
+fn test_channel_kinds() {
+    assert!(is_free_to_air(" ITV1 "));
+    assert_eq!(ChannelKind::of("BBC One"), ChannelKind::FreeToAir);
+    assert_eq!(ChannelKind::of("Amazon Prime Video"), ChannelKind::Streaming);
+    assert_eq!(ChannelKind::of("Peacock"), ChannelKind::Streaming);
+    assert_eq!(ChannelKind::of("Sky Sports Main Event"), ChannelKind::Pay);
+    assert_eq!(ChannelKind::Pay.label(), "pay TV");
+}
+
+// ── Match sides ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_sides_split_every_provider_format() {
+    assert_eq!(listing("Arsenal v Chelsea").sides(), Some(("Arsenal", "Chelsea")));
+    assert_eq!(listing("Inter Miami vs LA Galaxy").sides(), Some(("Inter Miami", "LA Galaxy")));
+    assert_eq!(listing("Paris Saint-Germain - Lyon").sides(), Some(("Paris Saint-Germain", "Lyon")));
+    assert_eq!(listing("Some Match No Parens").sides(), None);
+    assert_eq!(listing("Arsenal v ").sides(), None);
+}
@@ -0,0 +1,192 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Europe::{London, Paris};
+use chrono_tz::UTC;
+use foot_info_core::providers::matchstv;
+use foot_info_core::utils::clock::{Clock, FixedClock};
+use foot_info_core::utils::dates;
+use foot_info_core::utils::time::{self, DisplayZone};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn utc(iso: &str) -> DateTime<Utc> {
+    iso.parse().unwrap()
+}
+
+// ── Year inference ───────────────────────────────────────────────────────
+
+#[test]
+fn test_infer_year_table() {
+    // (month, day, today, expected)
+    let cases = [
+        // Plain upcoming date in the current year
+        (3, 15, ymd(2026, 2, 10), Some(ymd(2026, 3, 15))),
+        // Recent past stays in the current year
+        (1, 20, ymd(2026, 2, 10), Some(ymd(2026, 1, 20))),
+        // Beyond the past window rolls to next year
+        (1, 5, ymd(2026, 2, 10), Some(ymd(2027, 1, 5))),
+        // December result seen in early January belongs to last year
+        (12, 28, ymd(2027, 1, 5), Some(ymd(2026, 12, 28))),
+        // ...unless it is older than the window
+        (12, 1, ymd(2027, 1, 5), Some(ymd(2027, 12, 1))),
+        // January fixture seen in late December belongs to next year
+        (1, 3, ymd(2026, 12, 20), Some(ymd(2027, 1, 3))),
+        // New Year's Eve seen on New Year's Day
+        (12, 31, ymd(2027, 1, 1), Some(ymd(2026, 12, 31))),
+        // Leap day resolves to the next leap year
+        (2, 29, ymd(2027, 6, 1), Some(ymd(2028, 2, 29))),
+        // Impossible dates
+        (4, 31, ymd(2026, 2, 10), None),
+        (13, 1, ymd(2026, 2, 10), None),
+    ];
+
+    for (month, day, today, expected) in cases {
+        assert_eq!(
+            dates::infer_year(month, day, today),
+            expected,
+            "{}/{} seen on {}",
+            day,
+            month,
+            today
+        );
+    }
+}
+
+#[test]
+fn test_month_from_name_table() {
+    let cases = [
+        ("February", Some(2)),
+        ("sep", Some(9)),
+        ("Sept.", Some(9)),
+        ("février", Some(2)),
+        ("fevrier", Some(2)),
+        ("août", Some(8)),
+        ("Décembre", Some(12)),
+        ("decembre", Some(12)),
+        ("randommonth", None),
+        ("", None),
+    ];
+
+    for (name, expected) in cases {
+        assert_eq!(dates::month_from_name(name), expected, "{:?}", name);
+    }
+}
+
+// ── DST handling ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_kickoff_in_across_dst_table() {
+    // (zone, date, local time, expected UTC instant)
+    let cases = [
+        // Winter and summer offsets
+        (Paris, ymd(2026, 1, 15), "21:00", "2026-01-15T20:00:00Z"),
+        (Paris, ymd(2026, 7, 15), "21:00", "2026-07-15T19:00:00Z"),
+        (New_York, ymd(2026, 1, 15), "15:00", "2026-01-15T20:00:00Z"),
+        (New_York, ymd(2026, 7, 15), "15:00", "2026-07-15T19:00:00Z"),
+        // Spring forward: the skipped hour moves past the jump
+        (Paris, ymd(2026, 3, 29), "02:30", "2026-03-29T01:30:00Z"),
+        (New_York, ymd(2026, 3, 8), "02:30", "2026-03-08T07:30:00Z"),
+        // Just either side of the jump
+        (Paris, ymd(2026, 3, 29), "01:59", "2026-03-29T00:59:00Z"),
+        (Paris, ymd(2026, 3, 29), "03:00", "2026-03-29T01:00:00Z"),
+        // Fall back: the repeated hour takes its first occurrence
+        (Paris, ymd(2026, 10, 25), "02:30", "2026-10-25T00:30:00Z"),
+        (New_York, ymd(2026, 11, 1), "01:30", "2026-11-01T05:30:00Z"),
+    ];
+
+    for (tz, date, time, expected) in cases {
+        let time = NaiveTime::parse_from_str(time, "%H:%M").unwrap();
+        let kickoff = dates::kickoff_in(tz, date, time).map(|dt| dt.with_timezone(&Utc));
+        assert_eq!(kickoff, Some(utc(expected)), "{} {} {}", tz, date, time);
+    }
+}
+
+// ── Clock ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_fixed_clock_today_depends_on_zone() {
+    let clock = FixedClock(utc("2026-12-31T23:30:00Z"));
+
+    assert_eq!(clock.now(), utc("2026-12-31T23:30:00Z"));
+    assert_eq!(clock.today_in(London), ymd(2026, 12, 31));
+    assert_eq!(clock.today_in(Paris), ymd(2027, 1, 1));
+    assert_eq!(clock.today_in(New_York), ymd(2026, 12, 31));
+}
+
+// ── Provider date strings ────────────────────────────────────────────────
+
+#[test]
+fn test_convert_et_to_display_table() {
+    // (date, time, display zone, today, expected)
+    let cases = [
+        (
+            "Monday, February 23",
+            "03:00 PM ET",
+            DisplayZone::Source,
+            ymd(2026, 2, 20),
+            ("Mon 23 Feb 2026", "15:00"),
+        ),
+        // Year-end rollover
+        (
+            "Sunday, January 3",
+            "12:30 PM ET",
+            DisplayZone::Source,
+            ymd(2026, 12, 28),
+            ("Sun 03 Jan 2027", "12:30"),
+        ),
+        // Evening kickoff lands on the next day in UTC
+        (
+            "Thursday, December 31",
+            "08:00 PM ET",
+            DisplayZone::Named(UTC),
+            ymd(2026, 12, 28),
+            ("Fri 01 Jan 2027", "01:00"),
+        ),
+        // Time skipped by the DST change
+        (
+            "Sunday, March 8",
+            "02:30 AM ET",
+            DisplayZone::Named(UTC),
+            ymd(2026, 3, 1),
+            ("Sun 08 Mar 2026", "07:30"),
+        ),
+    ];
+
+    for (date, kickoff, zone, today, (expected_date, expected_time)) in cases {
+        assert_eq!(
+            time::convert_et_to_display(date, kickoff, zone, today),
+            Some((expected_date.to_string(), expected_time.to_string())),
+            "{} {}",
+            date,
+            kickoff
+        );
+    }
+}
+
+#[test]
+fn test_convert_et_to_display_rejects_unknown_month() {
+    assert!(time::convert_et_to_display("Monday, Brumaire 23", "03:00 PM ET", DisplayZone::Source, ymd(2026, 2, 20)).is_none());
+}
+
+#[test]
+fn test_parse_french_date_table() {
+    // (header, today, expected)
+    let cases = [
+        ("samedi 15 mars", ymd(2026, 2, 10), ymd(2026, 3, 15)),
+        ("dimanche 28 décembre", ymd(2027, 1, 5), ymd(2026, 12, 28)),
+        ("dimanche 3 janvier", ymd(2026, 12, 28), ymd(2027, 1, 3)),
+    ];
+
+    for (header, today, expected) in cases {
+        let (_, date) = matchstv::parse_french_date(header, today).unwrap();
+        assert_eq!(date, expected, "{:?} seen on {}", header, today);
+    }
+}
+
+#[test]
+fn test_french_time_in_dst_gap() {
+    let result = matchstv::convert_french_time_to_display(ymd(2026, 3, 29), "02h30", DisplayZone::Named(UTC));
+    assert_eq!(result, Some(("Sun 29 Mar 2026".to_string(), "01:30".to_string())));
+}
@@ -0,0 +1,214 @@
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Europe::Paris;
+use chrono_tz::UTC;
+use foot_info_core::models::MatchStatus;
+use foot_info_core::utils::format::{Countdown, HourCycle, KickoffFormat, Locale};
+use foot_info_core::utils::time::DisplayZone;
+
+fn utc(iso: &str) -> DateTime<Utc> {
+    iso.parse().unwrap()
+}
+
+/// Formats in the source zone, here UTC, so expectations don't depend on
+/// the machine running the tests.
+fn format(locale: Locale, hour_cycle: HourCycle) -> KickoffFormat {
+    KickoffFormat::new(locale, hour_cycle, DisplayZone::Source)
+}
+
+// ── Parsing ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_locale_parse_table() {
+    let cases = [
+        ("en", Some(Locale::En)),
+        ("fr", Some(Locale::Fr)),
+        ("fr-FR", Some(Locale::Fr)),
+        ("de_AT", Some(Locale::De)),
+        (" ES ", Some(Locale::Es)),
+        ("it", None),
+        ("", None),
+    ];
+
+    for (tag, expected) in cases {
+        assert_eq!(Locale::parse(tag), expected, "{:?}", tag);
+    }
+}
+
+#[test]
+fn test_hour_cycle_parse_table() {
+    let cases = [
+        ("24h", Some(HourCycle::H24)),
+        ("12h", Some(HourCycle::H12)),
+        ("12", Some(HourCycle::H12)),
+        ("12H", Some(HourCycle::H12)),
+        ("ampm", None),
+    ];
+
+    for (value, expected) in cases {
+        assert_eq!(HourCycle::parse(value), expected, "{:?}", value);
+    }
+}
+
+// ── Absolute dates and times ─────────────────────────────────────────────
+
+#[test]
+fn test_date_per_locale() {
+    let kickoff = utc("2026-02-21T20:45:00Z");
+    let cases = [
+        (Locale::En, "Sat 21 Feb 2026"),
+        (Locale::Fr, "sam. 21 févr. 2026"),
+        (Locale::De, "Sa., 21. Feb. 2026"),
+        (Locale::Es, "sáb, 21 feb 2026"),
+    ];
+
+    for (locale, expected) in cases {
+        assert_eq!(format(locale, HourCycle::H24).date(kickoff, UTC), expected);
+    }
+}
+
+#[test]
+fn test_time_per_locale_and_hour_cycle() {
+    let cases = [
+        (Locale::En, HourCycle::H24, "2026-02-21T20:45:00Z", "20:45"),
+        (Locale::Fr, HourCycle::H24, "2026-02-21T20:45:00Z", "20h45"),
+        (Locale::De, HourCycle::H24, "2026-02-21T09:05:00Z", "09:05"),
+        (Locale::En, HourCycle::H12, "2026-02-21T20:45:00Z", "8:45 PM"),
+        (Locale::En, HourCycle::H12, "2026-02-21T00:05:00Z", "12:05 AM"),
+        (Locale::En, HourCycle::H12, "2026-02-21T12:00:00Z", "12:00 PM"),
+        (Locale::Es, HourCycle::H12, "2026-02-21T20:45:00Z", "8:45 p. m."),
+    ];
+
+    for (locale, hour_cycle, kickoff, expected) in cases {
+        assert_eq!(
+            format(locale, hour_cycle).time(utc(kickoff), UTC),
+            expected,
+            "{:?} {:?} {}",
+            locale,
+            hour_cycle,
+            kickoff
+        );
+    }
+}
+
+#[test]
+fn test_named_zone_converts_before_formatting() {
+    let format = KickoffFormat::new(Locale::Fr, HourCycle::H24, DisplayZone::Named(Paris));
+    let kickoff = utc("2026-02-21T23:30:00Z");
+
+    assert_eq!(format.date(kickoff, UTC), "dim. 22 févr. 2026");
+    assert_eq!(format.time(kickoff, UTC), "00h30");
+}
+
+// ── Relative forms ───────────────────────────────────────────────────────
+
+#[test]
+fn test_relative_table() {
+    let now = utc("2026-02-21T10:00:00Z");
+    let cases = [
+        (Locale::En, "2026-02-21T13:00:00Z", "Today 13:00"),
+        (Locale::En, "2026-02-21T20:45:00Z", "Tonight 20:45"),
+        (Locale::En, "2026-02-22T15:00:00Z", "Tomorrow 15:00"),
+        (Locale::En, "2026-02-20T19:00:00Z", "Yesterday 19:00"),
+        (Locale::En, "2026-02-25T15:00:00Z", "Wed 25 Feb 2026 15:00"),
+        (Locale::Fr, "2026-02-21T20:45:00Z", "Ce soir 20h45"),
+        (Locale::Fr, "2026-02-22T15:00:00Z", "Demain 15h00"),
+        (Locale::De, "2026-02-21T20:45:00Z", "Heute Abend 20:45"),
+        (Locale::De, "2026-02-21T13:00:00Z", "Heute 13:00"),
+        (Locale::Es, "2026-02-22T15:00:00Z", "Mañana 15:00"),
+        (Locale::Es, "2026-02-20T19:00:00Z", "Ayer 19:00"),
+    ];
+
+    for (locale, kickoff, expected) in cases {
+        assert_eq!(
+            format(locale, HourCycle::H24).relative(utc(kickoff), UTC, now),
+            expected,
+            "{:?} {}",
+            locale,
+            kickoff
+        );
+    }
+}
+
+#[test]
+fn test_relative_day_follows_display_zone() {
+    let now = utc("2026-02-21T23:30:00Z");
+    let kickoff = utc("2026-02-22T00:30:00Z");
+
+    // Still Saturday evening in New York, already Sunday in Paris
+    let new_york = KickoffFormat::new(Locale::En, HourCycle::H24, DisplayZone::Named(New_York));
+    assert_eq!(new_york.relative(kickoff, UTC, now), "Tonight 19:30");
+
+    let paris = KickoffFormat::new(Locale::En, HourCycle::H24, DisplayZone::Named(Paris));
+    assert_eq!(paris.relative(kickoff, UTC, now), "Today 01:30");
+}
+
+#[test]
+fn test_relative_with_twelve_hour_clock() {
+    let now = utc("2026-02-21T10:00:00Z");
+    let result = format(Locale::En, HourCycle::H12).relative(utc("2026-02-22T15:00:00Z"), UTC, now);
+    assert_eq!(result, "Tomorrow 3:00 PM");
+}
+
+// ── Countdowns ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_countdown_inferred_from_clock_table() {
+    let kickoff = utc("2026-02-21T20:00:00Z");
+    let cases = [
+        ("2026-02-19T17:30:00Z", Countdown::Upcoming(Duration::minutes(2 * 1440 + 150))),
+        ("2026-02-21T17:45:00Z", Countdown::Upcoming(Duration::minutes(135))),
+        ("2026-02-21T20:00:00Z", Countdown::Live),
+        ("2026-02-21T21:54:00Z", Countdown::Live),
+        ("2026-02-21T23:25:00Z", Countdown::Finished(Duration::minutes(90))),
+    ];
+
+    for (now, expected) in cases {
+        assert_eq!(Countdown::at(kickoff, utc(now), MatchStatus::Upcoming), expected, "{}", now);
+    }
+}
+
+#[test]
+fn test_countdown_reported_status_wins() {
+    let kickoff = utc("2026-02-21T20:00:00Z");
+    let before = utc("2026-02-21T19:00:00Z");
+
+    assert_eq!(Countdown::at(kickoff, before, MatchStatus::Live), Countdown::Live);
+    assert_eq!(Countdown::at(kickoff, before, MatchStatus::Postponed), Countdown::Postponed);
+    // Finished early by the site's account, never "in the future"
+    assert_eq!(
+        Countdown::at(kickoff, before, MatchStatus::Finished),
+        Countdown::Finished(Duration::zero())
+    );
+}
+
+#[test]
+fn test_countdown_label_table() {
+    let cases = [
+        (Countdown::Upcoming(Duration::seconds(20)), Locale::En, "in 1m"),
+        (Countdown::Upcoming(Duration::minutes(45)), Locale::En, "in 45m"),
+        (Countdown::Upcoming(Duration::minutes(120)), Locale::En, "in 2h"),
+        (Countdown::Upcoming(Duration::minutes(135)), Locale::En, "in 2h 15m"),
+        (Countdown::Upcoming(Duration::minutes(2 * 1440 + 150)), Locale::En, "in 2d 2h"),
+        (Countdown::Upcoming(Duration::minutes(1440)), Locale::Fr, "dans 1j"),
+        (Countdown::Upcoming(Duration::minutes(135)), Locale::Es, "en 2h 15m"),
+        (Countdown::Live, Locale::En, "LIVE"),
+        (Countdown::Live, Locale::Fr, "EN DIRECT"),
+        (Countdown::Finished(Duration::minutes(95)), Locale::En, "finished 1h ago"),
+        (Countdown::Finished(Duration::minutes(10)), Locale::De, "vor 10m beendet"),
+        (Countdown::Finished(Duration::days(3)), Locale::Fr, "terminé il y a 3j"),
+        (Countdown::Postponed, Locale::Es, "Aplazado"),
+    ];
+
+    for (countdown, locale, expected) in cases {
+        assert_eq!(countdown.label(locale), expected, "{:?} {:?}", countdown, locale);
+    }
+}
+
+#[test]
+fn test_countdown_is_imminent_within_the_hour() {
+    assert!(Countdown::Upcoming(Duration::minutes(60)).is_imminent());
+    assert!(Countdown::Upcoming(Duration::minutes(5)).is_imminent());
+    assert!(!Countdown::Upcoming(Duration::minutes(61)).is_imminent());
+    assert!(!Countdown::Live.is_imminent());
+}
@@ -0,0 +1,195 @@
+#![cfg(feature = "history")]
+
+use chrono::{DateTime, Utc};
+use foot_info_core::history::{self, History};
+use foot_info_core::models::{Country, LeagueFixture, LeagueStats, Match, StandingRow, TopScorer};
+
+fn at(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+fn listing(teams: &str, kickoff: &str, channels: &[&str]) -> Match {
+    Match {
+        teams: teams.into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: Some(at(kickoff)),
+        channels: channels.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+fn row(position: u8, team: &str, played: u8, points: u16) -> StandingRow {
+    StandingRow {
+        position,
+        team: team.into(),
+        played,
+        won: 0,
+        drawn: 0,
+        lost: 0,
+        goals_for: 0,
+        goals_against: 0,
+        goal_diff: 0,
+        points,
+        form: vec![],
+        zone: None,
+    }
+}
+
+fn league(table: Vec<StandingRow>, fixtures: Vec<LeagueFixture>) -> LeagueStats {
+    LeagueStats {
+        competition: "Premier League".into(),
+        fixtures,
+        table,
+        top_scorers: vec![TopScorer {
+            player: "Bukayo Saka".into(),
+            team: "Arsenal".into(),
+            goals: 12,
+            penalties: 3,
+            assists: Some(8),
+            appearances: Some(25),
+            minutes: None,
+        }],
+        previous_page: None,
+        next_page: None,
+    }
+}
+
+#[test]
+fn test_broadcasters_count_each_match_once() {
+    let history = History::open_in_memory().unwrap();
+    let matches = [
+        listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"]),
+        listing("Arsenal v Spurs", "2026-02-28T15:00:00Z", &["TNT Sports"]),
+    ];
+    // The same search fetched twice is still two matches
+    history.record_matches("Fake", Country::UK, &matches, at("2026-02-10T08:00:00Z")).unwrap();
+    history.record_matches("Fake", Country::UK, &matches, at("2026-02-11T08:00:00Z")).unwrap();
+
+    let fixture = LeagueFixture {
+        home_team: "Everton".into(),
+        away_team: "Arsenal".into(),
+        date: "Sat 7 Mar 2026".into(),
+        time: "15:00".into(),
+        kickoff: Some(at("2026-03-07T15:00:00Z")),
+        score: None,
+        channels: vec!["Sky Sports".into()],
+        match_url: "/match/1/everton-v-arsenal/".into(),
+        matchday: Some(29),
+    };
+    history
+        .record_league("LiveSoccerTV", &league(vec![], vec![fixture]), at("2026-02-12T08:00:00Z"))
+        .unwrap();
+
+    let broadcasters = history.broadcasters("arsenal").unwrap();
+    let counts: Vec<(&str, usize)> = broadcasters.iter().map(|b| (b.channel.as_str(), b.matches)).collect();
+    assert_eq!(counts, [("Sky Sports", 2), ("TNT Sports", 1)]);
+    assert_eq!(broadcasters[0].last_seen, at("2026-02-12T08:00:00Z"));
+}
+
+#[test]
+fn test_same_match_from_a_search_and_a_league_page_counts_once() {
+    let history = History::open_in_memory().unwrap();
+    let uk = [listing("Arsenal - Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"])];
+    let us = [listing("Arsenal vs Chelsea", "2026-02-21T15:00:00Z", &["Peacock"])];
+    history.record_matches("Fake", Country::UK, &uk, at("2026-02-10T08:00:00Z")).unwrap();
+    history.record_matches("Fake", Country::US, &us, at("2026-02-11T08:00:00Z")).unwrap();
+
+    let fixture = LeagueFixture {
+        home_team: "Arsenal".into(),
+        away_team: "Chelsea".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: Some(at("2026-02-21T15:00:00Z")),
+        score: None,
+        channels: vec!["Sky Sports".into(), "BBC One".into()],
+        match_url: "/match/1/arsenal-v-chelsea/".into(),
+        matchday: Some(27),
+    };
+    history
+        .record_league("LiveSoccerTV", &league(vec![], vec![fixture]), at("2026-02-12T08:00:00Z"))
+        .unwrap();
+
+    let share = history.free_tv_share("Arsenal").unwrap();
+    assert_eq!((share.matches, share.on_free_tv), (1, 1));
+}
+
+#[test]
+fn test_latest_channels_of_a_match_win() {
+    let history = History::open_in_memory().unwrap();
+    let before = [listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"])];
+    let after = [listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["BBC One"])];
+    history.record_matches("Fake", Country::UK, &before, at("2026-02-10T08:00:00Z")).unwrap();
+    history.record_matches("Fake", Country::UK, &after, at("2026-02-11T08:00:00Z")).unwrap();
+
+    let broadcasters = history.broadcasters("Arsenal").unwrap();
+    assert_eq!(broadcasters.len(), 1);
+    assert_eq!(broadcasters[0].channel, "BBC One");
+}
+
+#[test]
+fn test_team_names_match_whole_sides() {
+    let history = History::open_in_memory().unwrap();
+    let matches = [
+        listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"]),
+        listing("Arsenal Women v Chelsea Women", "2026-02-22T12:00:00Z", &["BBC Two"]),
+        listing("Arsenal Tula v Spartak", "2026-02-22T16:00:00Z", &["Match TV"]),
+    ];
+    history.record_matches("Fake", Country::UK, &matches, at("2026-02-10T08:00:00Z")).unwrap();
+
+    let broadcasters = history.broadcasters("arsenal").unwrap();
+    let channels: Vec<&str> = broadcasters.iter().map(|b| b.channel.as_str()).collect();
+    assert_eq!(channels, ["Sky Sports"]);
+    assert_eq!(history.free_tv_share("Arsenal Women").unwrap().on_free_tv, 1);
+}
+
+#[test]
+fn test_free_tv_share() {
+    let history = History::open_in_memory().unwrap();
+    let matches = [
+        listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports", "BBC One"]),
+        listing("Arsenal v Spurs", "2026-02-28T15:00:00Z", &["TNT Sports"]),
+        listing("Arsenal v Everton", "2026-03-07T15:00:00Z", &["NBC", "Peacock"]),
+        listing("Arsenal v Fulham", "2026-03-14T15:00:00Z", &[]),
+    ];
+    history.record_matches("Fake", Country::UK, &matches, at("2026-02-10T08:00:00Z")).unwrap();
+
+    let share = history.free_tv_share("Arsenal").unwrap();
+    assert_eq!((share.matches, share.on_free_tv), (4, 2));
+    assert_eq!(share.percent(), Some(50.0));
+
+    assert!(history::is_free_to_air("ITV1"));
+    assert!(!history::is_free_to_air("Sky Sports Main Event"));
+}
+
+#[test]
+fn test_table_positions_keep_only_changes() {
+    let history = History::open_in_memory().unwrap();
+    let looks = [
+        ("2026-02-01T08:00:00Z", row(3, "Arsenal", 23, 47)),
+        ("2026-02-02T08:00:00Z", row(3, "Arsenal", 23, 47)),
+        ("2026-02-09T08:00:00Z", row(1, "Arsenal", 24, 50)),
+    ];
+    for (seen_at, row) in looks {
+        let table = vec![row, self::row(2, "Liverpool", 24, 48)];
+        history.record_league("LiveSoccerTV", &league(table, vec![]), at(seen_at)).unwrap();
+    }
+
+    let positions = history.table_positions("premier league", "arsenal").unwrap();
+    let summary: Vec<(u8, u16)> = positions.iter().map(|p| (p.position, p.points)).collect();
+    assert_eq!(summary, [(3, 47), (1, 50)]);
+    assert_eq!(positions[1].seen_at, at("2026-02-09T08:00:00Z"));
+}
+
+#[test]
+fn test_history_persists_between_opens() {
+    let path = std::env::temp_dir().join(format!("foot-info-history-{}.sqlite3", std::process::id()));
+    {
+        let history = History::open(&path).unwrap();
+        let matches = [listing("Arsenal v Chelsea", "2026-02-21T15:00:00Z", &["Sky Sports"])];
+        history.record_matches("Fake", Country::UK, &matches, at("2026-02-10T08:00:00Z")).unwrap();
+    }
+    let history = History::open(&path).unwrap();
+    assert_eq!(history.broadcasters("Arsenal").unwrap().len(), 1);
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,159 @@
+use foot_info_core::export::ical;
+use foot_info_core::models::{LeagueFixture, Match};
+use foot_info_core::utils::clock::FixedClock;
+
+fn clock() -> FixedClock {
+    FixedClock("2026-02-10T08:00:00Z".parse().unwrap())
+}
+
+fn sample_match(kickoff: Option<&str>) -> Match {
+    Match {
+        teams: "Arsenal v Chelsea".into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: kickoff.map(|k| k.parse().unwrap()),
+        channels: vec!["Sky Sports".into(), "NBC".into()],
+    }
+}
+
+fn fixture(match_url: &str) -> LeagueFixture {
+    LeagueFixture {
+        home_team: "Burnley".into(),
+        away_team: "Chelsea".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "10:00".into(),
+        kickoff: Some("2026-02-21T15:00:00Z".parse().unwrap()),
+        score: None,
+        channels: vec![],
+        match_url: match_url.into(),
+        matchday: Some(27),
+    }
+}
+
+/// Content lines with folding undone.
+fn unfolded(calendar: &str) -> Vec<String> {
+    calendar.replace("\r\n ", "").split("\r\n").filter(|l| !l.is_empty()).map(String::from).collect()
+}
+
+fn value<'a>(lines: &'a [String], name: &str) -> Vec<&'a str> {
+    lines
+        .iter()
+        .filter_map(|l| l.strip_prefix(name).and_then(|rest| rest.strip_prefix(':')))
+        .collect()
+}
+
+#[test]
+fn test_matches_to_ical_writes_one_event_per_match() {
+    let calendar = ical::matches_to_ical("Arsenal", &[sample_match(Some("2026-02-21T15:00:00Z"))], &clock());
+    let lines = unfolded(&calendar);
+
+    assert_eq!(lines.first().map(String::as_str), Some("BEGIN:VCALENDAR"));
+    assert_eq!(lines.last().map(String::as_str), Some("END:VCALENDAR"));
+    assert_eq!(value(&lines, "X-WR-CALNAME"), ["Arsenal"]);
+    assert_eq!(value(&lines, "DTSTART"), ["20260221T150000Z"]);
+    assert_eq!(value(&lines, "DTEND"), ["20260221T170000Z"]);
+    assert_eq!(value(&lines, "DTSTAMP"), ["20260210T080000Z"]);
+    assert_eq!(value(&lines, "SUMMARY"), ["Arsenal v Chelsea"]);
+    assert_eq!(
+        value(&lines, "DESCRIPTION"),
+        ["Competition: Premier League\\nChannels: Sky Sports\\, NBC"]
+    );
+}
+
+#[test]
+fn test_matches_to_ical_uses_crlf_line_endings() {
+    let calendar = ical::matches_to_ical("Arsenal", &[sample_match(Some("2026-02-21T15:00:00Z"))], &clock());
+
+    assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+    assert!(!calendar.replace("\r\n", "").contains('\n'), "Every line break should be CRLF");
+}
+
+#[test]
+fn test_matches_without_kickoff_are_skipped() {
+    let matches = [sample_match(None), sample_match(Some("2026-02-21T15:00:00Z"))];
+    let calendar = ical::matches_to_ical("Arsenal", &matches, &clock());
+
+    assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 1);
+}
+
+#[test]
+fn test_uid_survives_kickoff_time_change() {
+    let before = ical::matches_to_ical("Arsenal", &[sample_match(Some("2026-02-21T15:00:00Z"))], &clock());
+    let after = ical::matches_to_ical("Arsenal", &[sample_match(Some("2026-02-22T19:45:00Z"))], &clock());
+
+    let uid = |calendar: &str| value(&unfolded(calendar), "UID").concat();
+    assert_eq!(uid(&before), uid(&after), "Moving to another day keeps the event");
+    assert_eq!(uid(&before), "premier-league-2025-26-arsenal-v-chelsea@foot-info");
+}
+
+#[test]
+fn test_next_seasons_meeting_is_another_event() {
+    let this_season = ical::matches_to_ical("Arsenal", &[sample_match(Some("2026-02-21T15:00:00Z"))], &clock());
+    let next_season = ical::matches_to_ical("Arsenal", &[sample_match(Some("2027-02-20T15:00:00Z"))], &clock());
+    let august = ical::matches_to_ical("Arsenal", &[sample_match(Some("2026-08-22T15:00:00Z"))], &clock());
+
+    let uid = |calendar: &str| value(&unfolded(calendar), "UID").concat();
+    assert_eq!(uid(&next_season), "premier-league-2026-27-arsenal-v-chelsea@foot-info");
+    assert_eq!(uid(&august), uid(&next_season));
+    assert_ne!(uid(&this_season), uid(&next_season));
+}
+
+#[test]
+fn test_repeated_pairings_get_distinct_uids() {
+    let matches = [sample_match(Some("2026-02-21T15:00:00Z")), sample_match(Some("2026-03-03T19:45:00Z"))];
+    let calendar = ical::matches_to_ical("Arsenal", &matches, &clock());
+
+    assert_eq!(
+        value(&unfolded(&calendar), "UID"),
+        [
+            "premier-league-2025-26-arsenal-v-chelsea@foot-info",
+            "premier-league-2025-26-arsenal-v-chelsea-2@foot-info"
+        ]
+    );
+}
+
+#[test]
+fn test_fixtures_to_ical_keys_on_match_url() {
+    let calendar = ical::fixtures_to_ical(
+        "Premier League",
+        &[fixture("/match/burnley-vs-chelsea/17gw2#200"), fixture("")],
+        &clock(),
+    );
+    let lines = unfolded(&calendar);
+
+    assert_eq!(
+        value(&lines, "UID"),
+        [
+            "match-burnley-vs-chelsea-17gw2-200@foot-info",
+            "premier-league-2025-26-burnley-v-chelsea@foot-info",
+        ]
+    );
+    assert_eq!(value(&lines, "SUMMARY"), ["Burnley v Chelsea", "Burnley v Chelsea"]);
+    assert!(value(&lines, "DESCRIPTION")[0].ends_with("Channels: No TV info"));
+}
+
+#[test]
+fn test_text_is_escaped_and_long_lines_folded() {
+    let mut m = sample_match(Some("2026-02-21T15:00:00Z"));
+    m.teams = "Paris Saint-Germain; Olympique de Marseille, Coupe de France".into();
+    m.channels = (1..=12).map(|i| format!("beIN Sports Ünïcödé {}", i)).collect();
+    let calendar = ical::matches_to_ical("PSG", &[m], &clock());
+
+    for line in calendar.split("\r\n") {
+        assert!(line.len() <= 75, "Line exceeds 75 octets: {:?}", line);
+    }
+    let lines = unfolded(&calendar);
+    assert_eq!(
+        value(&lines, "SUMMARY"),
+        ["Paris Saint-Germain\\; Olympique de Marseille\\, Coupe de France"]
+    );
+    assert!(value(&lines, "DESCRIPTION")[0].contains("beIN Sports Ünïcödé 12"));
+}
+
+#[test]
+fn test_file_name_is_slugged() {
+    assert_eq!(ical::file_name("Paris Saint-Germain"), "paris-saint-germain.ics");
+    assert_eq!(ical::file_name("England - Premier League"), "england-premier-league.ics");
+    assert_eq!(ical::file_name(""), "foot-info.ics");
+}
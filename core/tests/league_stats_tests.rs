@@ -1,4 +1,6 @@
+use foot_info_core::models::{FixturesPage, LeagueFixture, PageDirection, Zone, ZoneKind};
 use foot_info_core::providers::league_stats;
+use foot_info_core::utils::time::DisplayZone;
 
 fn load_resource(name: &str) -> String {
     let path = format!("{}/tests/resources/{}", env!("CARGO_MANIFEST_DIR"), name);
@@ -9,7 +11,7 @@ fn load_resource(name: &str) -> String {
 #[test]
 fn test_parse_real_html_returns_league_stats() {
     let html = load_resource("livesoccertv_league.html");
-    let result = league_stats::parse_html(&html);
+    let result = league_stats::parse_html(&html, DisplayZone::Local);
     assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
 
     let stats = result.unwrap();
@@ -35,6 +37,286 @@ fn test_parse_real_html_returns_league_stats() {
 
 #[test]
 fn test_parse_empty_html_returns_error() {
-    let result = league_stats::parse_html("<html><body></body></html>");
+    let result = league_stats::parse_html("<html><body></body></html>", DisplayZone::Local);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_parse_real_html_reads_table_zones() {
+    let html = load_resource("livesoccertv_league.html");
+    let stats = league_stats::parse_html(&html, DisplayZone::Local).unwrap();
+
+    let top = stats.table[0].zone.as_ref().expect("Leader should be in a zone");
+    assert_eq!(top.kind, ZoneKind::Qualification);
+    assert_eq!(top.label, "Champions League");
+
+    let bottom = stats.table[19].zone.as_ref().expect("Last place should be in a zone");
+    assert_eq!(bottom.kind, ZoneKind::Relegation);
+    assert_eq!(bottom.label, "Relegation");
+
+    // Mid-table rows carry no zone
+    assert!(stats.table[9].zone.is_none());
+}
+
+#[test]
+fn test_teams_in_zone() {
+    let html = load_resource("livesoccertv_league.html");
+    let stats = league_stats::parse_html(&html, DisplayZone::Local).unwrap();
+
+    let relegated: Vec<&str> = stats
+        .teams_in_zone(ZoneKind::Relegation)
+        .iter()
+        .map(|r| r.team.as_str())
+        .collect();
+    assert_eq!(relegated.len(), 3);
+    assert!(relegated.contains(&"Wolverhampton Wanderers"));
+
+    assert_eq!(stats.teams_in_zone_labelled("champions league").len(), 4);
+    assert_eq!(stats.teams_in_zone_labelled("Europa League")[0].team, "Liverpool");
+
+    let labels: Vec<&str> = stats.zones().iter().map(|z| z.label.as_str()).collect();
+    assert_eq!(labels, vec!["Champions League", "Europa League", "Relegation"]);
+}
+
+#[test]
+fn test_zone_falls_back_to_legend_class() {
+    let html = r##"
+    <html><body>
+      <h1>Championship</h1>
+      <table class="standings">
+        <tr>
+          <td class="aright tbl_green" title="">1</td><td></td><td>Leeds</td>
+          <td>10</td><td>8</td><td>1</td><td>1</td><td>20</td><td>5</td><td>15</td><td>25</td><td></td>
+        </tr>
+        <tr>
+          <td class="aright tbl_none" title="">2</td><td></td><td>Burnley</td>
+          <td>10</td><td>7</td><td>1</td><td>2</td><td>18</td><td>6</td><td>12</td><td>22</td><td></td>
+        </tr>
+      </table>
+      <div><div class='tbl_green m5'>Promotion</div></div>
+    </body></html>"##;
+
+    let stats = league_stats::parse_html(html, DisplayZone::Local).unwrap();
+    assert_eq!(
+        stats.table[0].zone,
+        Some(Zone {
+            kind: ZoneKind::Promotion,
+            label: "Promotion".to_string()
+        })
+    );
+    assert!(stats.table[1].zone.is_none());
+}
+
+#[test]
+fn test_zone_from_label_classifies_kinds() {
+    let kind = |l: &str| Zone::from_label(l).map(|z| z.kind);
+    assert_eq!(kind("Champions League"), Some(ZoneKind::Qualification));
+    assert_eq!(kind("Conference League Qualifiers"), Some(ZoneKind::Qualification));
+    assert_eq!(kind("Promotion"), Some(ZoneKind::Promotion));
+    assert_eq!(kind("Promotion Playoffs"), Some(ZoneKind::Playoff));
+    assert_eq!(kind("Relegation Play-off"), Some(ZoneKind::Relegation));
+    assert_eq!(kind("  "), None);
+}
+
+#[test]
+fn test_parse_real_html_infers_matchdays_from_table() {
+    let html = load_resource("livesoccertv_league.html");
+    let stats = league_stats::parse_html(&html, DisplayZone::Local).unwrap();
+
+    assert!(
+        stats.fixtures.iter().all(|f| f.matchday.is_some()),
+        "Every fixture should get a matchday: {:?}",
+        stats.fixtures.iter().map(|f| f.matchday).collect::<Vec<_>>()
+    );
+
+    // Results come before upcoming games, so matchdays never go backwards
+    let days: Vec<u16> = stats.fixtures.iter().filter_map(|f| f.matchday).collect();
+    assert!(days.windows(2).all(|w| w[0] <= w[1]), "Got {:?}", days);
+    assert!(stats.matchdays().len() >= 2, "Expected results and upcoming rounds");
+}
+
+#[test]
+fn test_parse_real_html_reads_pagination() {
+    let html = load_resource("livesoccertv_league.html");
+    let stats = league_stats::parse_html(&html, DisplayZone::Local).unwrap();
+
+    let prev = stats.previous_page.expect("Expected a previous page cursor");
+    assert_eq!(prev.direction, PageDirection::Previous);
+    assert_eq!(prev.competition_id, "6");
+    assert_eq!(prev.offset, -1);
+    assert_eq!(prev.anchor_time, "2026-03-21 13:30:00");
+
+    let next = stats.next_page.expect("Expected a next page cursor");
+    assert_eq!(next.direction, PageDirection::Next);
+    assert_eq!(next.anchor_match, "Liverpool vs Fulham");
+}
+
+#[test]
+fn test_page_url_encodes_the_anchor() {
+    let cursor = league_stats::parse_paginate_call(
+        "paginate('next','competition', '6','1','2026-04-11 12:30:00','Brighton+%26+Hove+vs+Wolves','1','_live')",
+    )
+    .unwrap();
+    assert_eq!(cursor.anchor_match, "Brighton & Hove vs Wolves");
+    assert_eq!(
+        league_stats::page_url(&cursor),
+        "https://www.livesoccertv.com/ajax/paginate/?dir=next&type=competition&id=6&offset=1\
+         &time=2026-04-11+12%3A30%3A00&match=Brighton+%26+Hove+vs+Wolves"
+    );
+}
+
+#[test]
+fn test_parse_round_number() {
+    assert_eq!(league_stats::parse_round_number("Matchday 30"), Some(30));
+    assert_eq!(league_stats::parse_round_number("Premier League - Round 12"), Some(12));
+    assert_eq!(league_stats::parse_round_number("Journée 25"), Some(25));
+    assert_eq!(league_stats::parse_round_number("Gameweek: 7"), Some(7));
+    assert_eq!(league_stats::parse_round_number("Saturday, 21 March"), None);
+}
+
+#[test]
+fn test_explicit_round_headers_take_precedence() {
+    let html = r##"
+    <html><body>
+      <h1>Ligue 1</h1>
+      <table class="schedules">
+        <tr class="drow"><td colspan="3">Matchday 26</td></tr>
+        <tr class="matchrow">
+          <td>20:45</td><td><a href="/match/1">Lyon vs Nice</a></td><td>beIN Sports</td>
+        </tr>
+        <tr class="drow"><td colspan="3">Matchday 27</td></tr>
+        <tr class="matchrow">
+          <td>21:00</td><td><a href="/match/2">Nice vs Lens</a></td><td>Ligue 1+</td>
+        </tr>
+      </table>
+    </body></html>"##;
+
+    let stats = league_stats::parse_html(html, DisplayZone::Local).unwrap();
+    assert_eq!(stats.fixtures[0].matchday, Some(26));
+    assert_eq!(stats.fixtures[1].matchday, Some(27));
+    assert_eq!(stats.fixtures_in_matchday(27)[0].home_team, "Nice");
+}
+
+#[test]
+fn test_merge_fixtures_page_prepends_and_infers() {
+    let html = load_resource("livesoccertv_league.html");
+    let mut stats = league_stats::parse_html(&html, DisplayZone::Local).unwrap();
+    let first_day = stats.matchdays()[0];
+    let before = stats.fixtures.len();
+
+    let page = FixturesPage {
+        fixtures: vec![LeagueFixture {
+            home_team: "Chelsea".into(),
+            away_team: "Everton".into(),
+            date: "Sat 14 Mar 2026".into(),
+            time: "15:00".into(),
+            kickoff: None,
+            score: Some("1 - 1".into()),
+            channels: vec![],
+            match_url: "/match/older".into(),
+            matchday: None,
+        }],
+        previous_page: None,
+        next_page: None,
+    };
+
+    league_stats::merge_fixtures_page(&mut stats, page, PageDirection::Previous);
+    assert_eq!(stats.fixtures.len(), before + 1);
+    assert_eq!(stats.fixtures[0].match_url, "/match/older");
+    assert!(stats.fixtures[0].matchday.unwrap() < first_day);
+    assert!(stats.previous_page.is_none());
+}
+
+#[test]
+fn test_parse_real_html_reads_scorer_columns() {
+    let html = load_resource("livesoccertv_league.html");
+    let stats = league_stats::parse_html(&html, DisplayZone::Local).unwrap();
+
+    let haaland = &stats.top_scorers[0];
+    assert_eq!(haaland.goals, 22);
+    assert_eq!(haaland.penalties, 3);
+    assert_eq!(haaland.non_penalty_goals(), 19);
+    // The landing page has no assists or appearances columns
+    assert!(haaland.assists.is_none());
+    assert!(haaland.goals_per_game().is_none());
+}
+
+#[test]
+fn test_parse_scorers_with_extended_columns() {
+    let html = r##"
+    <html><body>
+      <h1>Serie A</h1>
+      <table class="standings"><tr><td>1</td><td></td><td>Inter</td>
+        <td>30</td><td>22</td><td>5</td><td>3</td><td>70</td><td>25</td><td>45</td><td>71</td><td></td></tr></table>
+      <table class="standings" id="topscorers-table">
+        <tr>
+          <th>Player</th><th>Team</th><th title="Matches Played">MP</th>
+          <th title="Minutes Played">Min</th><th title="Total Goals Scored">Goals</th>
+          <th title="Assists">A</th><th title="Penalties Converted">Pen</th>
+        </tr>
+        <tr>
+          <td>L. Martinez</td><td>Inter</td><td>28</td><td>2,310</td><td>21</td><td>5</td><td>4</td>
+        </tr>
+      </table>
+    </body></html>"##;
+
+    let stats = league_stats::parse_html(html, DisplayZone::Local).unwrap();
+    let s = &stats.top_scorers[0];
+    assert_eq!(s.player, "L. Martinez");
+    assert_eq!(s.team, "Inter");
+    assert_eq!(s.goals, 21);
+    assert_eq!(s.penalties, 4);
+    assert_eq!(s.assists, Some(5));
+    assert_eq!(s.appearances, Some(28));
+    assert_eq!(s.minutes, Some(2310));
+    assert_eq!(s.non_penalty_goals(), 17);
+    assert_eq!(s.goal_contributions(), Some(26));
+    assert!((s.goals_per_game().unwrap() - 0.75).abs() < 1e-9);
+    assert!((s.minutes_per_goal().unwrap() - 110.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_parse_scorers_skips_derived_columns() {
+    let html = r##"
+    <html><body>
+      <h1>Serie A</h1>
+      <table class="standings"><tr><td>1</td><td></td><td>Inter</td>
+        <td>30</td><td>22</td><td>5</td><td>3</td><td>70</td><td>25</td><td>45</td><td>71</td><td></td></tr></table>
+      <table class="standings" id="topscorers-table">
+        <tr>
+          <th>Player</th><th>Team</th><th>Goals</th><th>Non-penalty goals</th>
+          <th>Goals per game</th><th>Penalties</th>
+        </tr>
+        <tr>
+          <td>L. Martinez</td><td>Inter</td><td>21</td><td>17</td><td>0.75</td><td>4</td>
+        </tr>
+      </table>
+    </body></html>"##;
+
+    let stats = league_stats::parse_html(html, DisplayZone::Local).unwrap();
+    let s = &stats.top_scorers[0];
+    assert_eq!(s.goals, 21);
+    assert_eq!(s.penalties, 4);
+    assert_eq!(s.non_penalty_goals(), 17);
+}
+
+#[test]
+fn test_competition_url_resolves_names_paths_and_urls() {
+    let cases = [
+        ("premier-league", Some("https://www.livesoccertv.com/competitions/england/premier-league/")),
+        ("EPL", Some("https://www.livesoccertv.com/competitions/england/premier-league/")),
+        ("Serie A", Some("https://www.livesoccertv.com/competitions/italy/serie-a/")),
+        ("ucl", Some("https://www.livesoccertv.com/competitions/international/uefa-champions-league/")),
+        ("/scotland/premiership/", Some("https://www.livesoccertv.com/competitions/scotland/premiership/")),
+        (
+            "https://www.livesoccertv.com/competitions/spain/primera-division/",
+            Some("https://www.livesoccertv.com/competitions/spain/primera-division/"),
+        ),
+        ("nowhere", None),
+        ("", None),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(league_stats::competition_url(input).as_deref(), expected, "{:?}", input);
+    }
+}
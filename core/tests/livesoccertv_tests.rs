@@ -1,4 +1,7 @@
+use chrono::NaiveDate;
+use foot_info_core::models::MatchStatus;
 use foot_info_core::providers::livesoccertv;
+use foot_info_core::utils::time::DisplayZone;
 
 fn load_resource(name: &str) -> String {
     let path = format!("{}/tests/resources/{}", env!("CARGO_MANIFEST_DIR"), name);
@@ -9,7 +12,7 @@ fn load_resource(name: &str) -> String {
 #[test]
 fn test_parse_real_html_returns_top_matches() {
     let html = load_resource("livesoccertv.html");
-    let result = livesoccertv::parse_html(&html);
+    let result = livesoccertv::parse_html(&html, DisplayZone::Local);
     assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
 
     let matches = result.unwrap();
@@ -29,7 +32,7 @@ fn test_parse_real_html_returns_top_matches() {
 #[test]
 fn test_parse_real_html_has_valid_structure() {
     let html = load_resource("livesoccertv.html");
-    let matches = livesoccertv::parse_html(&html).unwrap();
+    let matches = livesoccertv::parse_html(&html, DisplayZone::Local).unwrap();
 
     for m in &matches {
         assert!(!m.teams.is_empty(), "Teams should not be empty");
@@ -45,7 +48,7 @@ fn test_parse_real_html_has_valid_structure() {
 #[test]
 fn test_parse_real_html_has_known_teams() {
     let html = load_resource("livesoccertv.html");
-    let matches = livesoccertv::parse_html(&html).unwrap();
+    let matches = livesoccertv::parse_html(&html, DisplayZone::Local).unwrap();
 
     // Check that some well-known teams appear
     let all_teams: String = matches
@@ -74,7 +77,7 @@ fn test_parse_real_html_has_known_teams() {
 #[test]
 fn test_parse_real_html_has_match_urls() {
     let html = load_resource("livesoccertv.html");
-    let matches = livesoccertv::parse_html(&html).unwrap();
+    let matches = livesoccertv::parse_html(&html, DisplayZone::Local).unwrap();
 
     let has_urls = matches.iter().any(|m| m.match_url.starts_with("/match/"));
     assert!(
@@ -85,7 +88,7 @@ fn test_parse_real_html_has_match_urls() {
 
 #[test]
 fn test_parse_empty_html_returns_error() {
-    let result = livesoccertv::parse_html("<html><body></body></html>");
+    let result = livesoccertv::parse_html("<html><body></body></html>", DisplayZone::Local);
     assert!(result.is_err());
 }
 
@@ -97,7 +100,7 @@ fn test_parse_html_without_top_matches_section() {
       <div>Some content</div>
     </body></html>"##;
 
-    let result = livesoccertv::parse_html(html);
+    let result = livesoccertv::parse_html(html, DisplayZone::Local);
     assert!(result.is_err());
 }
 
@@ -109,6 +112,136 @@ fn test_parse_html_with_empty_top_matches_section() {
       <div class="fheader">Next Section</div>
     </body></html>"##;
 
-    let result = livesoccertv::parse_html(html);
+    let result = livesoccertv::parse_html(html, DisplayZone::Local);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_real_html_exposes_match_ids() {
+    let html = load_resource("livesoccertv.html");
+    let matches = livesoccertv::parse_html(&html, DisplayZone::Local).unwrap();
+
+    assert_eq!(matches[0].match_id(), Some("5303719"));
+    assert!(matches.iter().all(|m| m.match_id().is_some()));
+}
+
+const SCHEDULE_HTML: &str = r##"
+<html><body>
+  <table class="schedules">
+    <tr class="sortable_comp" id="50"><td colspan="3" class="r_comprow">
+      <span class="flag europe">Europe - UEFA Champions League</span>
+    </td></tr>
+    <tr id="100" class="matchrow">
+      <td class='timecol'><div class='meta'>
+        <span class='livecell ft' title='Match ended'>Live</span>
+        <span class="timecell"><span class='ts started' dv='1771350300000'>12:45</span></span>
+      </div></td>
+      <td id="match"><a href="/match/galatasaray-vs-juventus/21gaf#100">Galatasaray 5 - 2 Juventus</a></td>
+      <td id="channels"><div class="mchannels">
+        <a href="/channels/canalplus-foot/" title="Canal+ Foot">Canal+ Foot</a>,
+        <a href="/channels/tabii/" title="tabii">tabii</a>
+      </div></td>
+    </tr>
+    <tr class="sortable_comp" id="7"><td colspan="3" class="r_comprow">
+      <span class="flag eng">England - Premier League</span>
+    </td></tr>
+    <tr id="200" class="matchrow">
+      <td class='timecol'><div class='meta'>
+        <span class='livecell live' title='Live Broadcast'></span>
+        <span class="timecell"><span class='ts started' dv='1771686000000'>10:00</span></span>
+      </div></td>
+      <td id="match"><a href="/match/burnley-vs-chelsea/17gw2#200">Chelsea vs Burnley</a></td>
+      <td id="channels"><div class="mchannels">
+        <a href="/channels/nbc-sports/" title="NBC Sports">NBC Sports</a>
+      </div></td>
+    </tr>
+    <tr id="300" class="matchrow">
+      <td class='timecol'><div class='meta'>
+        <span class='livecell live' title='Live Broadcast'></span>
+        <span class='timecell' title='Match postponed'>Postp.</span>
+      </div></td>
+      <td id="match"><a href="/match/everton-vs-fulham/1abcd#300">Everton vs Fulham</a></td>
+      <td id="channels"></td>
+    </tr>
+  </table>
+  <div class="fblock">
+    <div class="fheader">Upcoming Top Matches</div>
+    <div><span class='ts' dv='1771350300000'>Feb 17</span> &#183; <a href='/match/galatasaray-vs-juventus/21gaf#100'>Galatasaray - Juventus</a></div>
+    <div><span class='ts' dv='1771686000000'>Feb 21</span> &#183; <a href='/match/burnley-vs-chelsea/17gw2#200'>Chelsea - Burnley</a></div>
+    <div><span class='ts' dv='1771686000000'>Feb 21</span> &#183; <a href='/match/everton-vs-fulham/1abcd#300'>Everton - Fulham</a></div>
+    <div><span class='ts' dv='1771768800000'>Feb 22</span> &#183; <a href='/match/arsenal-vs-spurs/17ooc#400'>Tottenham Hotspur - Arsenal</a></div>
+  </div>
+</body></html>"##;
+
+#[test]
+fn test_parse_html_enriches_top_matches_from_schedule() {
+    let matches = livesoccertv::parse_html(SCHEDULE_HTML, DisplayZone::Local).unwrap();
+    assert_eq!(matches.len(), 4);
+
+    let ucl = &matches[0];
+    assert_eq!(ucl.competition.as_deref(), Some("Europe - UEFA Champions League"));
+    assert_eq!(ucl.channels, vec!["Canal+ Foot", "tabii"]);
+    assert_eq!(ucl.status, MatchStatus::Finished);
+    assert_eq!(ucl.kickoff, Some("2026-02-17T17:45:00Z".parse().unwrap()));
+
+    let live = &matches[1];
+    assert_eq!(live.competition.as_deref(), Some("England - Premier League"));
+    assert_eq!(live.channels, vec!["NBC Sports"]);
+    assert_eq!(live.status, MatchStatus::Live);
+
+    let postponed = &matches[2];
+    assert_eq!(postponed.status, MatchStatus::Postponed);
+    assert!(postponed.channels.is_empty());
+}
+
+#[test]
+fn test_parse_html_top_match_without_schedule_row_keeps_defaults() {
+    let matches = livesoccertv::parse_html(SCHEDULE_HTML, DisplayZone::Local).unwrap();
+    let unlisted = &matches[3];
+
+    assert!(unlisted.competition.is_none());
+    assert!(unlisted.channels.is_empty());
+    assert_eq!(unlisted.status, MatchStatus::Upcoming);
+}
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_parse_schedule_returns_every_listed_match() {
+    let html = load_resource("livesoccertv.html");
+    let matches = livesoccertv::parse_schedule(&html, day(2026, 2, 17), DisplayZone::Local).unwrap();
+
+    // Every schedule row, not just the featured top matches
+    assert_eq!(matches.len(), 21);
+
+    let first = &matches[0];
+    assert_eq!(first.teams, "Galatasaray - Juventus");
+    assert_eq!(first.competition.as_deref(), Some("Europe - UEFA Champions League"));
+    assert_eq!(first.status, MatchStatus::Finished);
+    assert!(first.channels.contains(&"Canal+ Foot".to_string()));
+    assert_eq!(first.match_id(), Some("5542863"));
+
+    assert!(matches.iter().all(|m| m.teams.contains(" - ")));
+    assert!(matches.iter().all(|m| m.competition.is_some()));
+}
+
+#[test]
+fn test_parse_schedule_postponed_match_keeps_requested_day() {
+    let html = load_resource("livesoccertv.html");
+    let matches = livesoccertv::parse_schedule(&html, day(2026, 2, 17), DisplayZone::Local).unwrap();
+
+    let postponed = matches
+        .iter()
+        .find(|m| m.teams == "Wealdstone - Hartlepool United")
+        .expect("postponed match should still be listed");
+    assert_eq!(postponed.status, MatchStatus::Postponed);
+    assert_eq!(postponed.date, "Tue 17 Feb 2026");
+}
+
+#[test]
+fn test_parse_schedule_without_rows_returns_error() {
+    let result = livesoccertv::parse_schedule("<html><body></body></html>", day(2026, 2, 17), DisplayZone::Local);
     assert!(result.is_err());
 }
@@ -0,0 +1,66 @@
+use foot_info_core::models::MatchStatus;
+use foot_info_core::providers::match_details;
+use foot_info_core::utils::time::DisplayZone;
+
+const MATCH_URL: &str = "https://www.livesoccertv.com/match/arsenal-vs-chelsea/17gw2";
+
+const MATCH_HTML: &str = r#"
+<html><head><title>Arsenal vs Chelsea Live Stream &amp; TV Listings | LiveSoccerTV</title></head>
+<body>
+  <div class="breadcrumbs">
+    <a href="/competitions/england/">England</a>
+    <a href="/competitions/england/premier-league/">Premier League</a>
+  </div>
+  <h1>Arsenal vs Chelsea</h1>
+  <span class="timecell"><span class='ts' dv='1771686000000'>10:00</span></span>
+  <table id="wc_channels">
+    <tr><td>United Kingdom</td><td><a href="/channels/sky-sports-main-event/" title="Sky Sports Main Event">Sky Sports ME</a></td></tr>
+    <tr><td>United States</td><td><a href="/channels/peacock/">Peacock</a></td></tr>
+    <tr><td>Ireland</td><td><a href="/channels/sky-sports-main-event/" title="Sky Sports Main Event">Sky Sports ME</a></td></tr>
+  </table>
+  <div class="sidebar"><a href="/channels/espn/">ESPN</a></div>
+</body></html>
+"#;
+
+#[test]
+fn test_parse_html_reads_match_page() {
+    let details = match_details::parse_html(MATCH_HTML, MATCH_URL, DisplayZone::Local).unwrap();
+
+    assert_eq!(details.teams, "Arsenal - Chelsea");
+    assert_eq!(details.competition.as_deref(), Some("Premier League"));
+    assert_eq!(details.kickoff, Some("2026-02-21T15:00:00Z".parse().unwrap()));
+    assert_eq!(details.status, MatchStatus::Upcoming);
+    assert_eq!(details.url, MATCH_URL);
+}
+
+#[test]
+fn test_parse_html_lists_each_channel_once_from_the_listing_table() {
+    let details = match_details::parse_html(MATCH_HTML, MATCH_URL, DisplayZone::Local).unwrap();
+    assert_eq!(details.channels, vec!["Sky Sports Main Event", "Peacock"]);
+}
+
+#[test]
+fn test_parse_html_falls_back_to_title_for_teams() {
+    let html = r#"<html><head><title>Lyon vs Monaco Live Stream &amp; TV Listings</title></head>
+        <body><span class='ts started' dv='1771686000000'>10:00</span></body></html>"#;
+    let details = match_details::parse_html(html, MATCH_URL, DisplayZone::Local).unwrap();
+
+    assert_eq!(details.teams, "Lyon - Monaco");
+    assert_eq!(details.status, MatchStatus::Live);
+    assert!(details.channels.is_empty());
+}
+
+#[test]
+fn test_parse_html_without_match_returns_error() {
+    let html = "<html><head><title>Page not found</title></head><body></body></html>";
+    assert!(match_details::parse_html(html, MATCH_URL, DisplayZone::Local).is_err());
+}
+
+#[test]
+fn test_absolute_url_prefixes_relative_match_urls() {
+    assert_eq!(
+        match_details::absolute_url("/match/burnley-vs-chelsea/17gw2#200"),
+        "https://www.livesoccertv.com/match/burnley-vs-chelsea/17gw2#200"
+    );
+    assert_eq!(match_details::absolute_url(MATCH_URL), MATCH_URL);
+}
@@ -1,5 +1,7 @@
 use chrono::{NaiveDate, Datelike};
 use foot_info_core::providers::matchstv;
+use foot_info_core::utils::clock::SystemClock;
+use foot_info_core::utils::time::DisplayZone;
 
 fn load_resource(name: &str) -> String {
     let path = format!("{}/tests/resources/{}", env!("CARGO_MANIFEST_DIR"), name);
@@ -7,6 +9,10 @@ fn load_resource(name: &str) -> String {
         .unwrap_or_else(|e| panic!("Failed to load test resource '{}': {}", path, e))
 }
 
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 2, 10).unwrap()
+}
+
 // =============================================================================
 // HTML Parsing Tests (using real HTML)
 // =============================================================================
@@ -14,7 +20,7 @@ fn load_resource(name: &str) -> String {
 #[test]
 fn test_parse_real_html_returns_matches() {
     let html = load_resource("matchstv.html");
-    let result = matchstv::parse_html(&html, "Manchester United");
+    let result = matchstv::parse_html(&html, "Manchester United", DisplayZone::Local, &SystemClock);
     assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
 
     let matches = result.unwrap();
@@ -34,7 +40,7 @@ fn test_parse_real_html_returns_matches() {
 #[test]
 fn test_parse_real_html_has_channels() {
     let html = load_resource("matchstv.html");
-    let matches = matchstv::parse_html(&html, "Manchester United").unwrap();
+    let matches = matchstv::parse_html(&html, "Manchester United", DisplayZone::Local, &SystemClock).unwrap();
 
     let has_channels = matches.iter().any(|m| !m.channels.is_empty());
     assert!(
@@ -46,7 +52,7 @@ fn test_parse_real_html_has_channels() {
 #[test]
 fn test_parse_real_html_has_competitions() {
     let html = load_resource("matchstv.html");
-    let matches = matchstv::parse_html(&html, "Manchester United").unwrap();
+    let matches = matchstv::parse_html(&html, "Manchester United", DisplayZone::Local, &SystemClock).unwrap();
 
     let has_competition = matches.iter().any(|m| !m.competition.is_empty());
     assert!(
@@ -59,7 +65,7 @@ fn test_parse_real_html_has_competitions() {
 #[test]
 fn test_parse_empty_schedule_returns_error() {
     let html = r#"<html><body><table class="programme-tv fixtures"></table></body></html>"#;
-    let result = matchstv::parse_html(html, "FakeTeam");
+    let result = matchstv::parse_html(html, "FakeTeam", DisplayZone::Local, &SystemClock);
 
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
@@ -76,7 +82,7 @@ fn test_parse_empty_schedule_returns_error() {
 
 #[test]
 fn test_parse_french_date_mars() {
-    let result = matchstv::parse_french_date("samedi 15 mars");
+    let result = matchstv::parse_french_date("samedi 15 mars", today());
     assert!(result.is_some(), "Should parse 'samedi 15 mars'");
 
     let (formatted, naive) = result.unwrap();
@@ -87,7 +93,7 @@ fn test_parse_french_date_mars() {
 
 #[test]
 fn test_parse_french_date_fevrier_with_accent() {
-    let result = matchstv::parse_french_date("mardi 1 février");
+    let result = matchstv::parse_french_date("mardi 1 février", today());
     assert!(result.is_some(), "Should parse 'février' with accent");
 
     let (_, naive) = result.unwrap();
@@ -97,7 +103,7 @@ fn test_parse_french_date_fevrier_with_accent() {
 
 #[test]
 fn test_parse_french_date_decembre_without_accent() {
-    let result = matchstv::parse_french_date("lundi 25 decembre");
+    let result = matchstv::parse_french_date("lundi 25 decembre", today());
     assert!(result.is_some(), "Should parse 'decembre' without accent");
 
     let (_, naive) = result.unwrap();
@@ -107,18 +113,18 @@ fn test_parse_french_date_decembre_without_accent() {
 
 #[test]
 fn test_parse_french_date_invalid_input() {
-    assert!(matchstv::parse_french_date("invalid").is_none());
+    assert!(matchstv::parse_french_date("invalid", today()).is_none());
 }
 
 #[test]
 fn test_parse_french_date_unknown_month() {
-    assert!(matchstv::parse_french_date("lundi 1 randommonth").is_none());
+    assert!(matchstv::parse_french_date("lundi 1 randommonth", today()).is_none());
 }
 
 #[test]
 fn test_parse_french_date_too_few_parts() {
-    assert!(matchstv::parse_french_date("mars").is_none());
-    assert!(matchstv::parse_french_date("15 mars").is_none());
+    assert!(matchstv::parse_french_date("mars", today()).is_none());
+    assert!(matchstv::parse_french_date("15 mars", today()).is_none());
 }
 
 // =============================================================================
@@ -128,7 +134,7 @@ fn test_parse_french_date_too_few_parts() {
 #[test]
 fn test_convert_french_time_valid() {
     let date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
-    let result = matchstv::convert_french_time_to_local(date, "21h00");
+    let result = matchstv::convert_french_time_to_display(date, "21h00", DisplayZone::Local);
     assert!(result.is_some(), "Should convert '21h00' to local time");
 
     let (date_str, time_str) = result.unwrap();
@@ -139,20 +145,20 @@ fn test_convert_french_time_valid() {
 #[test]
 fn test_convert_french_time_midday() {
     let date = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
-    let result = matchstv::convert_french_time_to_local(date, "12h30");
+    let result = matchstv::convert_french_time_to_display(date, "12h30", DisplayZone::Local);
     assert!(result.is_some());
 }
 
 #[test]
 fn test_convert_french_time_invalid() {
     let date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
-    assert!(matchstv::convert_french_time_to_local(date, "not-a-time").is_none());
+    assert!(matchstv::convert_french_time_to_display(date, "not-a-time", DisplayZone::Local).is_none());
 }
 
 #[test]
 fn test_convert_french_time_midnight() {
     let date = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
-    let result = matchstv::convert_french_time_to_local(date, "00h00");
+    let result = matchstv::convert_french_time_to_display(date, "00h00", DisplayZone::Local);
     assert!(result.is_some());
 }
 
@@ -179,7 +185,7 @@ fn test_competition_takes_first_comma_segment() {
       </table>
     </body></html>"##;
 
-    let result = matchstv::parse_html(html, "Test");
+    let result = matchstv::parse_html(html, "Test", DisplayZone::Local, &SystemClock);
     let matches = result.unwrap();
     assert_eq!(matches.len(), 1);
     assert_eq!(matches[0].competition, "Ligue 1");
@@ -0,0 +1,60 @@
+use chrono::NaiveDate;
+use chrono_tz::America::New_York;
+use chrono_tz::Asia::Tokyo;
+use chrono_tz::Europe::{London, Paris};
+use chrono_tz::US::Eastern;
+use chrono_tz::UTC;
+use foot_info_core::providers::matchstv;
+use foot_info_core::utils::time::{self, DisplayZone};
+
+// ── DisplayZone tests ────────────────────────────────────────────────────
+
+#[test]
+fn test_display_zone_parses_iana_names() {
+    assert_eq!(DisplayZone::parse("Europe/Paris"), Some(DisplayZone::Named(Paris)));
+    assert_eq!(DisplayZone::parse(" America/New_York "), Some(DisplayZone::Named(New_York)));
+}
+
+#[test]
+fn test_display_zone_parses_keywords() {
+    assert_eq!(DisplayZone::parse("local"), Some(DisplayZone::Local));
+    assert_eq!(DisplayZone::parse(""), Some(DisplayZone::Local));
+    assert_eq!(DisplayZone::parse("Source"), Some(DisplayZone::Source));
+}
+
+#[test]
+fn test_display_zone_rejects_unknown_names() {
+    assert_eq!(DisplayZone::parse("Mars/Olympus_Mons"), None);
+}
+
+// ── Conversion tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_utc_to_named_zone_crosses_midnight() {
+    let result = time::convert_utc_to_display("2026-02-21T15:00:00Z", DisplayZone::Named(Tokyo), London);
+    assert_eq!(result, Some(("Sun 22 Feb 2026".to_string(), "00:00".to_string())));
+}
+
+#[test]
+fn test_utc_to_source_zone_uses_provider_zone() {
+    // British Summer Time is UTC+1
+    let result = time::convert_utc_to_display("2026-07-04T14:00:00Z", DisplayZone::Source, London);
+    assert_eq!(result, Some(("Sat 04 Jul 2026".to_string(), "15:00".to_string())));
+}
+
+#[test]
+fn test_millis_to_source_zone() {
+    // 1771684200000 = 2026-02-21 14:30 UTC, 09:30 in New York
+    let result = time::convert_millis_to_display(1771684200000, DisplayZone::Source, Eastern);
+    assert_eq!(result, Some(("Sat 21 Feb 2026".to_string(), "09:30".to_string())));
+}
+
+#[test]
+fn test_french_time_to_named_zone() {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+    let result = matchstv::convert_french_time_to_display(date, "21h00", DisplayZone::Named(UTC));
+    assert_eq!(result, Some(("Sun 15 Mar 2026".to_string(), "20:00".to_string())));
+
+    let source = matchstv::convert_french_time_to_display(date, "21h00", DisplayZone::Source);
+    assert_eq!(source, Some(("Sun 15 Mar 2026".to_string(), "21:00".to_string())));
+}
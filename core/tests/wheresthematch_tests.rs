@@ -1,4 +1,5 @@
 use foot_info_core::providers::wheresthematch;
+use foot_info_core::utils::time::DisplayZone;
 
 fn load_resource(name: &str) -> String {
     let path = format!("{}/tests/resources/{}", env!("CARGO_MANIFEST_DIR"), name);
@@ -9,7 +10,7 @@ fn load_resource(name: &str) -> String {
 #[test]
 fn test_parse_real_html_returns_matches() {
     let html = load_resource("wheresthematch.html");
-    let result = wheresthematch::parse_html(&html, "Manchester United");
+    let result = wheresthematch::parse_html(&html, "Manchester United", DisplayZone::Local);
     assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
 
     let matches = result.unwrap();
@@ -36,7 +37,7 @@ fn test_parse_real_html_returns_matches() {
 #[test]
 fn test_parse_real_html_contains_man_utd() {
     let html = load_resource("wheresthematch.html");
-    let matches = wheresthematch::parse_html(&html, "Manchester United").unwrap();
+    let matches = wheresthematch::parse_html(&html, "Manchester United", DisplayZone::Local).unwrap();
 
     let has_man_utd = matches.iter().any(|m| {
         m.teams.contains("Man") || m.teams.contains("Manchester")
@@ -51,7 +52,7 @@ fn test_parse_real_html_contains_man_utd() {
 #[test]
 fn test_parse_real_html_has_channels() {
     let html = load_resource("wheresthematch.html");
-    let matches = wheresthematch::parse_html(&html, "Manchester United").unwrap();
+    let matches = wheresthematch::parse_html(&html, "Manchester United", DisplayZone::Local).unwrap();
 
     let has_channels = matches.iter().any(|m| !m.channels.is_empty());
     assert!(
@@ -63,7 +64,7 @@ fn test_parse_real_html_has_channels() {
 #[test]
 fn test_parse_invalid_html_returns_team_not_found() {
     let html = "<html><body><p>Invalid URL Format</p></body></html>";
-    let result = wheresthematch::parse_html(html, "FakeTeam");
+    let result = wheresthematch::parse_html(html, "FakeTeam", DisplayZone::Local);
 
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
@@ -77,7 +78,7 @@ fn test_parse_invalid_html_returns_team_not_found() {
 #[test]
 fn test_parse_empty_page_returns_team_not_found() {
     let html = "<html><body><p>Nothing here</p></body></html>";
-    let result = wheresthematch::parse_html(html, "NoTeam");
+    let result = wheresthematch::parse_html(html, "NoTeam", DisplayZone::Local);
 
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
@@ -95,7 +96,7 @@ fn test_parse_page_with_header_but_no_matches() {
       <h1 class="intro">Some Team on TV</h1>
       <div id="teamswrapper"><table><tbody></tbody></table></div>
     </body></html>"#;
-    let result = wheresthematch::parse_html(html, "Some Team");
+    let result = wheresthematch::parse_html(html, "Some Team", DisplayZone::Local);
 
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
@@ -1,4 +1,6 @@
 use foot_info_core::providers::worldsoccertalk;
+use foot_info_core::utils::clock::{FixedClock, SystemClock};
+use foot_info_core::utils::time::DisplayZone;
 
 fn load_resource(name: &str) -> String {
     let path = format!("{}/tests/resources/{}", env!("CARGO_MANIFEST_DIR"), name);
@@ -9,7 +11,7 @@ fn load_resource(name: &str) -> String {
 #[test]
 fn test_parse_real_html_returns_matches() {
     let html = load_resource("worldsoccertalk.html");
-    let result = worldsoccertalk::parse_html(&html, "Manchester United");
+    let result = worldsoccertalk::parse_html(&html, "Manchester United", DisplayZone::Local, &SystemClock);
     assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
 
     let matches = result.unwrap();
@@ -29,7 +31,7 @@ fn test_parse_real_html_returns_matches() {
 #[test]
 fn test_parse_real_html_has_channels() {
     let html = load_resource("worldsoccertalk.html");
-    let matches = worldsoccertalk::parse_html(&html, "Manchester United").unwrap();
+    let matches = worldsoccertalk::parse_html(&html, "Manchester United", DisplayZone::Local, &SystemClock).unwrap();
 
     let has_channels = matches.iter().any(|m| !m.channels.is_empty());
     assert!(
@@ -41,7 +43,7 @@ fn test_parse_real_html_has_channels() {
 #[test]
 fn test_parse_real_html_parses_competition_from_title() {
     let html = load_resource("worldsoccertalk.html");
-    let matches = worldsoccertalk::parse_html(&html, "Manchester United").unwrap();
+    let matches = worldsoccertalk::parse_html(&html, "Manchester United", DisplayZone::Local, &SystemClock).unwrap();
 
     // Matches with parentheses in the title should have competition extracted
     let has_known_competition = matches.iter().any(|m| m.competition != "Unknown Competition");
@@ -55,7 +57,7 @@ fn test_parse_real_html_parses_competition_from_title() {
 #[test]
 fn test_parse_empty_schedule_returns_error() {
     let html = "<html><body><div>No schedule here</div></body></html>";
-    let result = worldsoccertalk::parse_html(html, "FakeTeam");
+    let result = worldsoccertalk::parse_html(html, "FakeTeam", DisplayZone::Local, &SystemClock);
 
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
@@ -87,9 +89,25 @@ fn test_parse_title_without_parentheses_gives_unknown_competition() {
       </div>
     </body></html>"##;
 
-    let result = worldsoccertalk::parse_html(html, "Test");
+    let result = worldsoccertalk::parse_html(html, "Test", DisplayZone::Local, &SystemClock);
     let matches = result.unwrap();
     assert_eq!(matches.len(), 1);
     assert_eq!(matches[0].teams, "Some Match No Parens");
     assert_eq!(matches[0].competition, "Unknown Competition");
 }
+
+#[test]
+fn test_parse_real_html_infers_year_from_clock() {
+    let html = load_resource("worldsoccertalk.html");
+
+    let clock = FixedClock("2026-02-20T12:00:00Z".parse().unwrap());
+    let matches = worldsoccertalk::parse_html(&html, "Manchester United", DisplayZone::Source, &clock).unwrap();
+    assert_eq!(matches[0].date, "Mon 23 Feb 2026");
+    assert_eq!(matches[0].time, "15:00");
+    assert_eq!(matches[0].kickoff, Some("2026-02-23T20:00:00Z".parse().unwrap()));
+
+    // Read the following January, the listing must be next February
+    let clock = FixedClock("2027-01-05T12:00:00Z".parse().unwrap());
+    let matches = worldsoccertalk::parse_html(&html, "Manchester United", DisplayZone::Source, &clock).unwrap();
+    assert_eq!(matches[0].date, "Tue 23 Feb 2027");
+}
@@ -1,45 +1,132 @@
 use foot_info_core::error::AppError;
-use foot_info_core::models::{LeagueStats, Match, TopMatch};
+use foot_info_core::changes::{self, Change, Listing, SnapshotStore};
+use foot_info_core::models::{FixturesPage, LeagueStats, Match, PageCursor, TopMatch};
+use crate::config::{Config, Favorite};
+#[cfg(feature = "history")]
+use foot_info_core::history::{self, History};
+#[cfg(feature = "history")]
+use foot_info_core::providers::league_stats;
 use crate::handlers;
+use crate::models::ResultsSource;
 use crate::state::AppState;
 use crate::ui;
+use chrono::{DateTime, NaiveDate, Utc};
+use crossterm::clipboard::CopyToClipboard;
 use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::execute;
 use ratatui::DefaultTerminal;
 use std::io;
+use std::sync::Arc;
+#[cfg(feature = "history")]
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 pub enum Action {
     Search(String),
-    MatchesFound(Vec<Match>),
+    MatchesFound(ResultsSource, Vec<Match>),
     Error(AppError),
     FetchTopMatches,
     TopMatchesFound(Vec<TopMatch>),
+    FetchSchedule(NaiveDate),
+    ScheduleFound(NaiveDate, Vec<TopMatch>),
     FetchLeagueStats(String),
     LeagueStatsFound(LeagueStats),
+    FetchLeagueFixturesPage(PageCursor),
+    LeagueFixturesPageFound(PageCursor, FixturesPage),
+    /// Advances the clock countdowns are measured from.
+    Tick(DateTime<Utc>),
+    /// What changed since the last look under this snapshot key.
+    ChangesFound(String, Vec<Change>),
+    /// Fetches these favourites for the dashboard, all at once.
+    FetchDashboard(Vec<Favorite>),
+    FavoriteMatchesFound(Favorite, Vec<Match>),
+    FavoriteError(Favorite, String),
+    /// Puts this text on the terminal's clipboard.
+    Copy(String),
 }
 
+/// How often countdowns are refreshed.
+const TICK_RATE: Duration = Duration::from_secs(1);
+
 pub struct App {
     pub state: AppState,
     action_tx: mpsc::UnboundedSender<Action>,
     action_rx: mpsc::UnboundedReceiver<Action>,
+    snapshots: Option<Arc<SnapshotStore>>,
+    #[cfg(feature = "history")]
+    history: Option<SharedHistory>,
+}
+
+/// Saves `listings` as the last look under `key`, off the async workers,
+/// returning what changed. Failing to save only costs the markers.
+async fn record_look(snapshots: Option<Arc<SnapshotStore>>, key: String, listings: Vec<Listing>) -> Vec<Change> {
+    let Some(store) = snapshots else {
+        return Vec::new();
+    };
+    tokio::task::spawn_blocking(move || store.record(&key, listings, Utc::now()))
+        .await
+        .ok()
+        .and_then(Result::ok)
+        .unwrap_or_default()
+}
+
+/// The local history database, opened once for the session.
+#[cfg(feature = "history")]
+type SharedHistory = Arc<Mutex<History>>;
+
+#[cfg(feature = "history")]
+fn open_history() -> Option<SharedHistory> {
+    let path = Config::history_path()?;
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    History::open(path).ok().map(|history| Arc::new(Mutex::new(history)))
+}
+
+/// Adds a fetch to the local history database, on a blocking thread.
+/// Failing to record must not get in the way of showing what was fetched,
+/// so errors are dropped.
+#[cfg(feature = "history")]
+fn record_history(
+    history: Option<&SharedHistory>,
+    record: impl FnOnce(&History) -> history::Result<()> + Send + 'static,
+) {
+    let Some(history) = history.cloned() else {
+        return;
+    };
+    tokio::task::spawn_blocking(move || {
+        let history = history.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = record(&history);
+    });
 }
 
 impl App {
     pub fn new() -> Self {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let mut state = AppState::new();
+        if let Some(action) = handlers::start(&mut state) {
+            let _ = action_tx.send(action);
+        }
         Self {
-            state: AppState::new(),
+            state,
             action_tx,
             action_rx,
+            snapshots: Config::snapshot_dir().map(|dir| Arc::new(SnapshotStore::new(dir))),
+            #[cfg(feature = "history")]
+            history: open_history(),
         }
     }
 
     pub async fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        let mut last_tick = Instant::now();
+        let size = terminal.size()?;
+        handlers::handle_resize(&mut self.state, size.width, size.height);
         while !self.state.exit {
             terminal.draw(|frame| ui::draw(frame, &self.state))?;
 
             // Handle terminal events
-            if event::poll(std::time::Duration::from_millis(100))? {
+            if event::poll(Duration::from_millis(100))? {
                 match event::read()? {
                     Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
                         if let Some(action) = handlers::handle_key_event(&mut self.state, key_event)
@@ -47,10 +134,21 @@ impl App {
                             let _ = self.action_tx.send(action);
                         }
                     }
+                    Event::Mouse(mouse_event) => {
+                        if let Some(action) = handlers::handle_mouse_event(&mut self.state, mouse_event, Instant::now()) {
+                            let _ = self.action_tx.send(action);
+                        }
+                    }
+                    Event::Resize(width, height) => handlers::handle_resize(&mut self.state, width, height),
                     _ => {}
                 }
             }
 
+            if last_tick.elapsed() >= TICK_RATE {
+                let _ = self.action_tx.send(Action::Tick(Utc::now()));
+                last_tick = Instant::now();
+            }
+
             // Handle async actions
             while let Ok(action) = self.action_rx.try_recv() {
                 let should_spawn = handlers::handle_action(&mut self.state, &action);
@@ -61,11 +159,31 @@ impl App {
                             let tx = self.action_tx.clone();
                             let client = self.state.client.clone();
                             let provider = self.state.client.providers()[self.state.current_provider_index].country();
+                            #[cfg(feature = "history")]
+                            let history = self.history.clone();
+                            #[cfg(feature = "history")]
+                            let provider_name = self.state.get_current_provider().name().to_string();
+                            let source = ResultsSource {
+                                country: provider,
+                                url: Some(self.state.get_current_provider().team_url(team)).filter(|url| !url.is_empty()),
+                            };
                             let team = team.clone();
+                            let snapshots = self.snapshots.clone();
                             tokio::spawn(async move {
                                 match client.search_team(&team, provider).await {
                                     Ok(matches) => {
-                                        let _ = tx.send(Action::MatchesFound(matches));
+                                        #[cfg(feature = "history")]
+                                        {
+                                            let matches = matches.clone();
+                                            record_history(history.as_ref(), move |h| {
+                                                h.record_matches(&provider_name, provider, &matches, Utc::now())
+                                            });
+                                        }
+                                        let key = changes::team_key(&team, provider);
+                                        let listings = matches.iter().map(Listing::from_match).collect();
+                                        let _ = tx.send(Action::MatchesFound(source, matches));
+                                        let changes = record_look(snapshots, key.clone(), listings).await;
+                                        let _ = tx.send(Action::ChangesFound(key, changes));
                                     }
                                     Err(e) => {
                                         let _ = tx.send(Action::Error(e));
@@ -73,6 +191,38 @@ impl App {
                                 }
                             });
                         }
+                        Action::FetchDashboard(ref favorites) => {
+                            for favorite in favorites.clone() {
+                                let tx = self.action_tx.clone();
+                                let client = self.state.client.clone();
+                                #[cfg(feature = "history")]
+                                let history = self.history.clone();
+                                #[cfg(feature = "history")]
+                                let provider_name = client
+                                    .providers()
+                                    .iter()
+                                    .find(|p| p.country() == favorite.country)
+                                    .map(|p| p.name().to_string())
+                                    .unwrap_or_default();
+                                tokio::spawn(async move {
+                                    match client.search_team(&favorite.team, favorite.country).await {
+                                        Ok(matches) => {
+                                            #[cfg(feature = "history")]
+                                            {
+                                                let (country, matches) = (favorite.country, matches.clone());
+                                                record_history(history.as_ref(), move |h| {
+                                                    h.record_matches(&provider_name, country, &matches, Utc::now())
+                                                });
+                                            }
+                                            let _ = tx.send(Action::FavoriteMatchesFound(favorite, matches));
+                                        }
+                                        Err(e) => {
+                                            let _ = tx.send(Action::FavoriteError(favorite, e.to_string()));
+                                        }
+                                    }
+                                });
+                            }
+                        }
                         Action::FetchTopMatches => {
                             let tx = self.action_tx.clone();
                             let client = self.state.client.clone();
@@ -87,14 +237,46 @@ impl App {
                                 }
                             });
                         }
+                        Action::FetchSchedule(date) => {
+                            let tx = self.action_tx.clone();
+                            let client = self.state.client.clone();
+                            tokio::spawn(async move {
+                                match client.fetch_schedule(date).await {
+                                    Ok(matches) => {
+                                        let _ = tx.send(Action::ScheduleFound(date, matches));
+                                    }
+                                    Err(e) => {
+                                        let _ = tx.send(Action::Error(e));
+                                    }
+                                }
+                            });
+                        }
                         Action::FetchLeagueStats(ref url) => {
                             let tx = self.action_tx.clone();
                             let client = self.state.client.clone();
                             let url = url.clone();
+                            let snapshots = self.snapshots.clone();
+                            #[cfg(feature = "history")]
+                            let history = self.history.clone();
                             tokio::spawn(async move {
                                 match client.fetch_league_stats(&url).await {
                                     Ok(stats) => {
+                                        #[cfg(feature = "history")]
+                                        {
+                                            let stats = stats.clone();
+                                            record_history(history.as_ref(), move |h| {
+                                                h.record_league(league_stats::PROVIDER_NAME, &stats, Utc::now())
+                                            });
+                                        }
+                                        let key = changes::league_key(&url);
+                                        let listings = stats
+                                            .fixtures
+                                            .iter()
+                                            .map(|f| Listing::from_fixture(f, &stats.competition))
+                                            .collect();
                                         let _ = tx.send(Action::LeagueStatsFound(stats));
+                                        let changes = record_look(snapshots, key.clone(), listings).await;
+                                        let _ = tx.send(Action::ChangesFound(key, changes));
                                     }
                                     Err(e) => {
                                         let _ = tx.send(Action::Error(e));
@@ -102,6 +284,44 @@ impl App {
                                 }
                             });
                         }
+                        Action::FetchLeagueFixturesPage(ref cursor) => {
+                            let tx = self.action_tx.clone();
+                            let client = self.state.client.clone();
+                            let cursor = cursor.clone();
+                            #[cfg(feature = "history")]
+                            let history = self.history.clone();
+                            #[cfg(feature = "history")]
+                            let competition = self
+                                .state
+                                .league_stats
+                                .as_ref()
+                                .map(|stats| stats.competition.clone())
+                                .unwrap_or_default();
+                            tokio::spawn(async move {
+                                match client.fetch_league_fixtures_page(&cursor).await {
+                                    Ok(page) => {
+                                        #[cfg(feature = "history")]
+                                        {
+                                            let fixtures = page.fixtures.clone();
+                                            record_history(history.as_ref(), move |h| {
+                                                h.record_fixtures(league_stats::PROVIDER_NAME, &competition, &fixtures, Utc::now())
+                                            });
+                                        }
+                                        let _ = tx.send(Action::LeagueFixturesPageFound(cursor, page));
+                                    }
+                                    Err(e) => {
+                                        let _ = tx.send(Action::Error(e));
+                                    }
+                                }
+                            });
+                        }
+                        Action::Copy(ref text) => {
+                            // OSC 52: the terminal itself sets the clipboard, over SSH too
+                            match execute!(io::stdout(), CopyToClipboard::to_clipboard_from(text)) {
+                                Ok(()) => self.state.status_message = Some("Copied to the clipboard".to_string()),
+                                Err(e) => self.state.error_message = Some(format!("Failed to copy: {}", e)),
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -0,0 +1,613 @@
+use crate::config::{Config, Favorite};
+use crate::models::LeagueTab;
+use crate::server::{self, FeedTeam, ServerState};
+use crate::ui::kickoff::KickoffLabels;
+use chrono::Utc;
+use clap::{Parser, Subcommand, ValueEnum};
+use foot_info_core::changes::{self, Change, ChangeKind, Listing, SnapshotStore};
+use foot_info_core::client::FootballClient;
+#[cfg(feature = "history")]
+use foot_info_core::history::History;
+use foot_info_core::error::AppError;
+use foot_info_core::export::ical;
+use foot_info_core::models::{Country, LeagueStats, Match, MatchDetails, TopMatch};
+use foot_info_core::providers::{league_stats, livesoccertv};
+use foot_info_core::utils::clock::SystemClock;
+use foot_info_core::utils::format::KickoffFormat;
+use serde::Serialize;
+use std::net::SocketAddr;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Command line of the `foot-info-tui` binary. Without a subcommand the
+/// full-screen UI starts.
+#[derive(Debug, Parser)]
+#[command(version, about = "Where to watch upcoming football matches")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Output format of subcommands
+    #[arg(long, short, value_enum, global = true, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Where a team's upcoming matches are broadcast
+    Search {
+        team: String,
+        /// Whose TV listings to search: uk, us or fr
+        #[arg(long, short, default_value = "uk", value_parser = parse_country)]
+        country: Country,
+    },
+    /// Featured upcoming matches
+    Top,
+    /// A competition's fixtures, table or top scorers
+    League {
+        /// Competition page URL, or a short name such as "premier-league"
+        #[arg(value_parser = parse_competition)]
+        competition: String,
+        /// Which part of the competition page to print
+        #[arg(long, short, value_enum, default_value_t = LeagueTab::Fixtures)]
+        tab: LeagueTab,
+    },
+    /// Everything listed for one match, from its LiveSoccerTV URL
+    Details { url: String },
+    /// What changed since the last look at a team or competition, for cron
+    /// jobs; prints nothing as a table when nothing did
+    Changes {
+        #[arg(required_unless_present = "league", conflicts_with = "league")]
+        team: Option<String>,
+        /// Whose TV listings to search: uk, us or fr
+        #[arg(long, short, default_value = "uk", value_parser = parse_country)]
+        country: Country,
+        /// Watch a competition's fixtures instead of a team
+        #[arg(long, short, value_parser = parse_competition)]
+        league: Option<String>,
+    },
+    /// Ask the local history of everything fetched so far
+    #[cfg(feature = "history")]
+    History {
+        #[command(subcommand)]
+        query: HistoryQuery,
+    },
+    /// Serve a local JSON API until stopped
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        bind: SocketAddr,
+        /// Seconds a fetched response is reused for; feeds are refreshed
+        /// at the same interval
+        #[arg(long, default_value_t = 300, value_parser = clap::value_parser!(u64).range(1..))]
+        cache_ttl: u64,
+        /// Team whose feeds are kept refreshed, besides the favourite;
+        /// repeatable
+        #[arg(long = "team")]
+        teams: Vec<String>,
+        /// Country the refreshed feeds are searched in
+        #[arg(long, value_parser = parse_country, default_value = "uk")]
+        country: Country,
+    },
+}
+
+#[cfg(feature = "history")]
+#[derive(Debug, Clone, Subcommand)]
+pub enum HistoryQuery {
+    /// Channels that showed a team, most frequent first
+    Broadcasters { team: String },
+    /// How many of a team's matches were on free-to-air TV
+    FreeTv { team: String },
+    /// A team's table position over time
+    Positions {
+        team: String,
+        /// Competition, by the same names `league` takes
+        #[arg(long, short, default_value = "premier-league", value_parser = parse_competition)]
+        league: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+    Markdown,
+    /// iCalendar, for `search` and `league --tab fixtures`
+    Ical,
+}
+
+fn parse_country(value: &str) -> Result<Country, String> {
+    Country::parse(value).ok_or_else(|| format!("unknown country '{}' (expected uk, us or fr)", value))
+}
+
+/// The competition's page URL, so a misspelt name is a usage error.
+fn parse_competition(value: &str) -> Result<String, String> {
+    league_stats::competition_url(value).ok_or_else(|| format!("unknown competition '{}'", value))
+}
+
+/// Process exit code for each error kind, so scripts can tell a misspelt
+/// team from a network failure. 1 and 2 stay with the usual meanings of
+/// I/O and usage errors.
+pub fn exit_code(err: &AppError) -> u8 {
+    match err {
+        AppError::Network(_) => 3,
+        AppError::ProviderError(_) => 4,
+        AppError::TeamNotFound(_) => 5,
+        AppError::NoMatchesScheduled(_) => 6,
+    }
+}
+
+/// The favourites, each in its own country, followed by the `--team`
+/// arguments in `country`. A team already listed isn't repeated, whatever
+/// its country.
+pub fn feed_teams(favorites: &[Favorite], teams: Vec<String>, country: Country) -> Vec<FeedTeam> {
+    let favorites = favorites.iter().map(|f| (f.team.clone(), f.country));
+    let mut feed_teams: Vec<FeedTeam> = Vec::new();
+    for (team, country) in favorites.chain(teams.into_iter().map(|team| (team, country))) {
+        let team = team.trim().to_string();
+        if !team.is_empty() && !feed_teams.iter().any(|f| f.team.eq_ignore_ascii_case(&team)) {
+            feed_teams.push(FeedTeam { team, country });
+        }
+    }
+    feed_teams
+}
+
+/// Runs one subcommand, printing its result to stdout or the error to stderr.
+/// `serve` runs until the process is stopped.
+pub async fn run(command: Command, format: OutputFormat) -> ExitCode {
+    let config = Config::load();
+    let client = FootballClient::with_zone(config.display_zone());
+    let kickoff = KickoffFormat::new(config.locale(), config.hour_cycle(), config.display_zone());
+    let labels = |source| KickoffLabels::new(kickoff, source, Utc::now());
+    let quiet_when_empty = matches!(command, Command::Changes { .. });
+
+    let report = match command {
+        Command::Search { team, country } => client
+            .search_team(&team, country)
+            .await
+            .map(|matches| {
+                let mut report = matches_report(&matches, &labels(country.source_zone()));
+                report.calendar = Some(ical::matches_to_ical(&team, &matches, &SystemClock));
+                report
+            }),
+        Command::Top => client
+            .fetch_top_matches()
+            .await
+            .map(|matches| top_matches_report(&matches, &labels(livesoccertv::SOURCE_ZONE))),
+        Command::League { competition, tab } => client
+            .fetch_league_stats(&competition)
+            .await
+            .map(|stats| {
+                let mut report = league_report(&stats, tab, &labels(livesoccertv::SOURCE_ZONE));
+                if tab == LeagueTab::Fixtures {
+                    report.calendar = Some(ical::fixtures_to_ical(&stats.competition, &stats.fixtures, &SystemClock));
+                }
+                report
+            }),
+        Command::Details { url } => client
+            .fetch_match_details(&url)
+            .await
+            .map(|details| details_report(&details, &labels(livesoccertv::SOURCE_ZONE))),
+        Command::Changes { team, country, league } => {
+            let Some(store) = Config::snapshot_dir().map(SnapshotStore::new) else {
+                eprintln!("Error: no data directory to keep snapshots in");
+                return ExitCode::FAILURE;
+            };
+            let look = match league {
+                Some(url) => client.fetch_league_stats(&url).await.map(|stats| {
+                    let listings = stats
+                        .fixtures
+                        .iter()
+                        .map(|f| Listing::from_fixture(f, &stats.competition))
+                        .collect();
+                    (changes::league_key(&url), listings, livesoccertv::SOURCE_ZONE)
+                }),
+                None => {
+                    let team = team.unwrap_or_default();
+                    // An emptied schedule is a change too: its matches were removed.
+                    let matches = match client.search_team(&team, country).await {
+                        Err(AppError::NoMatchesScheduled(_)) => Ok(Vec::new()),
+                        other => other,
+                    };
+                    matches.map(|matches| {
+                        let listings = matches.iter().map(Listing::from_match).collect();
+                        (changes::team_key(&team, country), listings, country.source_zone())
+                    })
+                }
+            };
+            match look {
+                Ok((key, listings, source)) => match store.record(&key, listings, Utc::now()) {
+                    Ok(changes) => Ok(changes_report(&changes, &labels(source))),
+                    Err(e) => {
+                        eprintln!("Error: failed to save snapshot: {}", e);
+                        return ExitCode::FAILURE;
+                    }
+                },
+                Err(e) => Err(e),
+            }
+        }
+        #[cfg(feature = "history")]
+        Command::History { query } => {
+            let history = Config::history_path()
+                .ok_or_else(|| "no data directory to keep the history in".to_string())
+                .and_then(|path| {
+                    if let Some(parent) = path.parent() {
+                        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                    }
+                    History::open(path).map_err(|e| e.to_string())
+                });
+            match history.and_then(|h| history_report(&h, &query, &labels(chrono_tz::UTC))) {
+                Ok(report) => Ok(report),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Serve { bind, cache_ttl, teams, country } => {
+            let ttl = Duration::from_secs(cache_ttl);
+            let state = ServerState::new(client, ttl);
+            let feed_teams = feed_teams(&config.favorites, teams, country);
+            eprintln!("Serving on http://{}", bind);
+            return match server::serve(bind, state, feed_teams, ttl).await {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+    };
+
+    match report {
+        Ok(report) if format == OutputFormat::Ical && report.calendar.is_none() => {
+            eprintln!("Error: iCalendar output is only available for search and league fixtures");
+            ExitCode::from(2)
+        }
+        Ok(report) if quiet_when_empty && report.rows.is_empty() && format == OutputFormat::Table => {
+            ExitCode::SUCCESS
+        }
+        Ok(report) => {
+            print!("{}", report.render(format));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(exit_code(&e))
+        }
+    }
+}
+
+/// Rows ready for the text formats, plus the underlying data for JSON.
+#[derive(Debug)]
+pub struct Report {
+    pub headers: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+    pub json: serde_json::Value,
+    /// iCalendar rendering, for reports made of matches with kickoffs.
+    pub calendar: Option<String>,
+}
+
+impl Report {
+    fn new<T: Serialize + ?Sized>(headers: Vec<&'static str>, rows: Vec<Vec<String>>, data: &T) -> Self {
+        Self {
+            headers,
+            rows,
+            json: serde_json::to_value(data).unwrap_or_default(),
+            calendar: None,
+        }
+    }
+
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Table => self.render_table(),
+            OutputFormat::Json => {
+                let mut json = serde_json::to_string_pretty(&self.json).unwrap_or_default();
+                json.push('\n');
+                json
+            }
+            OutputFormat::Csv => self.render_csv(),
+            OutputFormat::Markdown => self.render_markdown(),
+            OutputFormat::Ical => self.calendar.clone().unwrap_or_default(),
+        }
+    }
+
+    fn render_table(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |cells: Vec<&str>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        };
+
+        let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        let mut out = line(self.headers.clone());
+        out.push_str(&line(separator.iter().map(String::as_str).collect()));
+        for row in &self.rows {
+            out.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        out
+    }
+
+    fn render_csv(&self) -> String {
+        let line = |cells: Vec<&str>| {
+            let fields: Vec<String> = cells.into_iter().map(csv_field).collect();
+            format!("{}\n", fields.join(","))
+        };
+
+        let mut out = line(self.headers.clone());
+        for row in &self.rows {
+            out.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        out
+    }
+
+    fn render_markdown(&self) -> String {
+        let line = |cells: Vec<&str>| {
+            let escaped: Vec<String> = cells.iter().map(|c| c.replace('|', "\\|")).collect();
+            format!("| {} |\n", escaped.join(" | "))
+        };
+
+        let mut out = line(self.headers.clone());
+        out.push_str(&line(vec!["---"; self.headers.len()]));
+        for row in &self.rows {
+            out.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        out
+    }
+}
+
+/// Quotes a field when it holds a separator, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn matches_report(matches: &[Match], labels: &KickoffLabels) -> Report {
+    let rows = matches
+        .iter()
+        .map(|m| {
+            vec![
+                labels.date(m.kickoff, &m.date),
+                labels.time(m.kickoff, &m.time),
+                m.teams.clone(),
+                m.competition.clone(),
+                m.channels.join(", "),
+            ]
+        })
+        .collect();
+    Report::new(vec!["Date", "Time", "Teams", "Competition", "Channels"], rows, matches)
+}
+
+pub fn top_matches_report(matches: &[TopMatch], labels: &KickoffLabels) -> Report {
+    let rows = matches
+        .iter()
+        .map(|m| {
+            vec![
+                labels.date(m.kickoff, &m.date),
+                labels.time(m.kickoff, &m.time),
+                m.teams.clone(),
+                m.competition.clone().unwrap_or_default(),
+                m.status.label().to_string(),
+                m.channels.join(", "),
+                m.match_url.clone(),
+            ]
+        })
+        .collect();
+    Report::new(
+        vec!["Date", "Time", "Teams", "Competition", "Status", "Channels", "URL"],
+        rows,
+        matches,
+    )
+}
+
+pub fn league_report(stats: &LeagueStats, tab: LeagueTab, labels: &KickoffLabels) -> Report {
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+    match tab {
+        LeagueTab::Fixtures => {
+            let rows = stats
+                .fixtures
+                .iter()
+                .map(|f| {
+                    vec![
+                        or_dash(f.matchday.map(|d| d.to_string())),
+                        labels.date(f.kickoff, &f.date),
+                        labels.time(f.kickoff, &f.time),
+                        f.home_team.clone(),
+                        f.away_team.clone(),
+                        or_dash(f.score.clone()),
+                        f.channels.join(", "),
+                    ]
+                })
+                .collect();
+            Report::new(
+                vec!["Round", "Date", "Time", "Home", "Away", "Score", "Channels"],
+                rows,
+                &stats.fixtures,
+            )
+        }
+        LeagueTab::Table => {
+            let rows = stats
+                .table
+                .iter()
+                .map(|r| {
+                    vec![
+                        r.position.to_string(),
+                        r.team.clone(),
+                        r.played.to_string(),
+                        r.won.to_string(),
+                        r.drawn.to_string(),
+                        r.lost.to_string(),
+                        r.goals_for.to_string(),
+                        r.goals_against.to_string(),
+                        r.goal_diff.to_string(),
+                        r.points.to_string(),
+                        r.zone.as_ref().map(|z| z.label.clone()).unwrap_or_default(),
+                    ]
+                })
+                .collect();
+            Report::new(
+                vec!["Pos", "Team", "P", "W", "D", "L", "GF", "GA", "GD", "Pts", "Zone"],
+                rows,
+                &stats.table,
+            )
+        }
+        LeagueTab::TopScorers => {
+            let rows = stats
+                .top_scorers
+                .iter()
+                .map(|s| {
+                    vec![
+                        s.player.clone(),
+                        s.team.clone(),
+                        s.goals.to_string(),
+                        s.penalties.to_string(),
+                        or_dash(s.assists.map(|a| a.to_string())),
+                        or_dash(s.appearances.map(|a| a.to_string())),
+                    ]
+                })
+                .collect();
+            Report::new(
+                vec!["Player", "Team", "Goals", "Pen", "Ast", "Apps"],
+                rows,
+                &stats.top_scorers,
+            )
+        }
+    }
+}
+
+pub fn details_report(details: &MatchDetails, labels: &KickoffLabels) -> Report {
+    let row = vec![
+        details.teams.clone(),
+        details.competition.clone().unwrap_or_default(),
+        labels.date(details.kickoff, &details.date),
+        labels.time(details.kickoff, &details.time),
+        details.status.label().to_string(),
+        details.channels.join(", "),
+        details.url.clone(),
+    ];
+    Report::new(
+        vec!["Teams", "Competition", "Date", "Time", "Status", "Channels", "URL"],
+        vec![row],
+        details,
+    )
+}
+
+pub fn changes_report(changes: &[Change], labels: &KickoffLabels) -> Report {
+    let rows = changes
+        .iter()
+        .map(|c| {
+            let listing = &c.listing;
+            let details = match &c.kind {
+                ChangeKind::Added => listing.channels.join(", "),
+                ChangeKind::Removed => String::new(),
+                ChangeKind::Rescheduled {
+                    from_date,
+                    from_time,
+                    from_kickoff,
+                } => format!(
+                    "was {} {}",
+                    labels.date(*from_kickoff, from_date),
+                    labels.time(*from_kickoff, from_time)
+                ),
+                ChangeKind::ChannelsChanged { added, removed } => added
+                    .iter()
+                    .map(|ch| format!("+{}", ch))
+                    .chain(removed.iter().map(|ch| format!("-{}", ch)))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            };
+            vec![
+                c.kind.label().to_string(),
+                labels.date(listing.kickoff, &listing.date),
+                labels.time(listing.kickoff, &listing.time),
+                listing.teams.clone(),
+                listing.competition.clone(),
+                details,
+            ]
+        })
+        .collect();
+    Report::new(
+        vec!["Change", "Date", "Time", "Teams", "Competition", "Details"],
+        rows,
+        changes,
+    )
+}
+
+#[cfg(feature = "history")]
+pub fn history_report(history: &History, query: &HistoryQuery, labels: &KickoffLabels) -> Result<Report, String> {
+    let seen = |at| format!("{} {}", labels.date(Some(at), ""), labels.time(Some(at), ""));
+
+    Ok(match query {
+        HistoryQuery::Broadcasters { team } => {
+            let broadcasters = history.broadcasters(team).map_err(|e| e.to_string())?;
+            let rows = broadcasters
+                .iter()
+                .map(|b| vec![b.channel.clone(), b.matches.to_string(), seen(b.last_seen)])
+                .collect();
+            Report::new(vec!["Channel", "Matches", "Last seen"], rows, &broadcasters)
+        }
+        HistoryQuery::FreeTv { team } => {
+            let share = history.free_tv_share(team).map_err(|e| e.to_string())?;
+            let percent = share
+                .percent()
+                .map(|p| format!("{:.0}%", p))
+                .unwrap_or_else(|| "-".to_string());
+            let rows = vec![vec![share.matches.to_string(), share.on_free_tv.to_string(), percent]];
+            Report::new(vec!["Matches", "On free TV", "Share"], rows, &share)
+        }
+        HistoryQuery::Positions { team, league } => {
+            let competition = recorded_competition(history, league)?;
+            let positions = history.table_positions(&competition, team).map_err(|e| e.to_string())?;
+            let rows = positions
+                .iter()
+                .map(|p| {
+                    vec![
+                        seen(p.seen_at),
+                        p.position.to_string(),
+                        p.played.to_string(),
+                        p.points.to_string(),
+                    ]
+                })
+                .collect();
+            Report::new(vec!["Seen", "Position", "Played", "Points"], rows, &positions)
+        }
+    })
+}
+
+/// The recorded table behind a competition page URL. Tables are recorded
+/// under their page titles, so a title matches when it reads as the page's
+/// name ("Premier League" for `.../premier-league/`) or as one of the short
+/// names standing for that page ("La Liga").
+#[cfg(feature = "history")]
+fn recorded_competition(history: &History, url: &str) -> Result<String, String> {
+    let path = url.trim_end_matches('/').rsplit("/competitions/").next().unwrap_or_default();
+    let mut names: Vec<&str> = league_stats::KNOWN_COMPETITIONS
+        .iter()
+        .filter(|(_, known)| *known == path)
+        .map(|(name, _)| *name)
+        .collect();
+    names.extend(path.rsplit('/').next());
+    let as_name = |title: &str| title.to_lowercase().split_whitespace().collect::<Vec<_>>().join("-");
+
+    history
+        .competitions()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|title| names.contains(&as_name(title).as_str()))
+        .ok_or_else(|| format!("no table recorded for {} yet; fetch it with `league` first", url))
+}
@@ -1,29 +1,194 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
-use directories::ProjectDirs;
+use directories::{ProjectDirs, UserDirs};
+use crate::keymap::Keymap;
+use crate::suggest;
+use crate::ui::theme::{ColorDepth, Theme};
+use foot_info_core::models::Country;
+use foot_info_core::utils::format::{HourCycle, Locale};
+use foot_info_core::utils::time::DisplayZone;
+
+/// A favourite team and the country whose broadcasters list it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Favorite {
+    pub team: String,
+    pub country: Country,
+}
+
+impl Favorite {
+    pub fn new(team: &str, country: Country) -> Self {
+        Self {
+            team: team.trim().to_string(),
+            country,
+        }
+    }
+
+    /// Same team, ignoring case, in the same country.
+    pub fn is(&self, team: &str, country: Country) -> bool {
+        self.country == country && self.team.eq_ignore_ascii_case(team.trim())
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Config {
+    /// The single favourite of older configs; moved into `favorites` on load.
+    #[serde(default, skip_serializing)]
     pub favorite_team: Option<String>,
+    /// Favourite teams, in the order the dashboard shows them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub favorites: Vec<Favorite>,
+    /// IANA zone kickoff times are shown in, e.g. "Europe/Bucharest".
+    /// Unset or unknown names fall back to the machine's zone.
+    pub timezone: Option<String>,
+    /// Language dates are written in: "en", "fr", "de" or "es".
+    pub locale: Option<String>,
+    /// "24h" (default) or "12h".
+    pub clock: Option<String>,
+    /// Where exported calendars are written; defaults to the downloads
+    /// folder, or the working directory when there is none.
+    pub export_dir: Option<String>,
+    /// "vim" for modal keys: Esc stops typing, then j/k/h/l move and `/`
+    /// types again. Anything else keeps the default keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keys: Option<String>,
+    /// A bundled theme ("dark", "light", "solarized", "high-contrast") or
+    /// one in the themes folder; defaults to "dark".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Key overrides by action name, e.g. `"switch_country": ["ctrl+o"]`,
+    /// or `"league.refresh": ["f5"]` for a single view.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub keybindings: HashMap<String, Vec<String>>,
+    /// Keywords of the commands last run from the palette, newest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_commands: Vec<String>,
+    /// Teams searched in each country, newest first.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub search_history: HashMap<Country, Vec<String>>,
+    /// The file this config was loaded from and is saved back to; none for
+    /// a config that is never saved.
+    #[serde(skip)]
+    path: Option<PathBuf>,
 }
 
 impl Config {
+    pub fn display_zone(&self) -> DisplayZone {
+        self.timezone
+            .as_deref()
+            .and_then(DisplayZone::parse)
+            .unwrap_or_default()
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+            .as_deref()
+            .and_then(Locale::parse)
+            .unwrap_or_default()
+    }
+
+    pub fn hour_cycle(&self) -> HourCycle {
+        self.clock
+            .as_deref()
+            .and_then(HourCycle::parse)
+            .unwrap_or_default()
+    }
+
+    pub fn vim_keys(&self) -> bool {
+        self.keys.as_deref().is_some_and(|keys| keys.eq_ignore_ascii_case("vim"))
+    }
+
+    /// The keymap this config asks for, with whatever was wrong with its
+    /// overrides.
+    pub fn keymap(&self) -> (Keymap, Vec<String>) {
+        let base = if self.vim_keys() { Keymap::vim() } else { Keymap::default() };
+        base.overridden(&self.keybindings)
+    }
+
+    /// The theme this config asks for, brought within what the terminal
+    /// can show.
+    pub fn theme(&self) -> Result<Theme, String> {
+        let name = self.theme.as_deref().unwrap_or("dark");
+        let theme = Theme::named(name, Self::themes_dir().as_deref())?;
+        Ok(theme.for_depth(ColorDepth::detect()))
+    }
+
+    /// Turns an older config's single favourite into the first of the
+    /// list. It was searched with the first provider, the UK one.
+    pub fn migrate_favorite(&mut self) {
+        if let Some(team) = self.favorite_team.take()
+            && self.favorites.is_empty()
+        {
+            self.favorites.push(Favorite::new(&team, Country::UK));
+        }
+    }
+
+    /// Adds a favourite at the end; false if it's already one.
+    pub fn add_favorite(&mut self, team: &str, country: Country) -> bool {
+        if team.trim().is_empty() || self.favorites.iter().any(|f| f.is(team, country)) {
+            return false;
+        }
+        self.favorites.push(Favorite::new(team, country));
+        true
+    }
+
+    /// Index of the favourite named `team`, in any country.
+    pub fn find_favorite(&self, team: &str) -> Option<usize> {
+        self.favorites.iter().position(|f| f.team.eq_ignore_ascii_case(team.trim()))
+    }
+
+    /// Moves the favourite at `from` to `to` (clamped to the list),
+    /// returning where it ended up.
+    pub fn move_favorite(&mut self, from: usize, to: usize) -> Option<usize> {
+        if from >= self.favorites.len() {
+            return None;
+        }
+        let to = to.min(self.favorites.len() - 1);
+        let favorite = self.favorites.remove(from);
+        self.favorites.insert(to, favorite);
+        Some(to)
+    }
+
+    /// Teams searched in `country`, newest first.
+    pub fn searches(&self, country: Country) -> &[String] {
+        self.search_history.get(&country).map_or(&[], Vec::as_slice)
+    }
+
+    /// Records a search of `team` in `country` as the newest.
+    pub fn remember_search(&mut self, team: &str, country: Country) {
+        suggest::remember(self.search_history.entry(country).or_default(), team);
+    }
+
+    pub fn export_dir(&self) -> PathBuf {
+        self.export_dir
+            .as_ref()
+            .map(PathBuf::from)
+            .or_else(|| UserDirs::new().and_then(|dirs| dirs.download_dir().map(PathBuf::from)))
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
+
     pub fn load() -> Self {
-        if let Some(path) = Self::get_config_path() {
-            if path.exists() {
-                if let Ok(content) = fs::read_to_string(path) {
-                    if let Ok(config) = serde_json::from_str(&content) {
-                        return config;
-                    }
-                }
-            }
+        match Self::get_config_path() {
+            Some(path) => Self::at(path),
+            None => Self::default(),
         }
-        Self::default()
+    }
+
+    /// The config kept in `path` instead of the user's config folder; the
+    /// defaults when the file is missing or unreadable.
+    pub fn at(path: PathBuf) -> Self {
+        let mut config = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Config>(&content).ok())
+            .unwrap_or_default();
+        config.migrate_favorite();
+        config.path = Some(path);
+        config
     }
 
     pub fn save(&self) -> std::io::Result<()> {
-        if let Some(path) = Self::get_config_path() {
+        if let Some(path) = &self.path {
             if let Some(parent) = path.parent() {
                 fs::create_dir_all(parent)?;
             }
@@ -33,6 +198,31 @@ impl Config {
         Ok(())
     }
 
+    /// Where the last look at each team and competition is kept, to tell
+    /// what changed by the next one.
+    pub fn snapshot_dir() -> Option<PathBuf> {
+        ProjectDirs::from("com", "foot-info", "foot-info")
+            .map(|proj_dirs| proj_dirs.data_dir().join("snapshots"))
+    }
+
+    /// The SQLite database everything fetched is recorded in.
+    pub fn history_path() -> Option<PathBuf> {
+        ProjectDirs::from("com", "foot-info", "foot-info")
+            .map(|proj_dirs| proj_dirs.data_dir().join("history.sqlite3"))
+    }
+
+    /// Teams seen in fetched league tables, suggested while typing.
+    pub fn team_index_path() -> Option<PathBuf> {
+        ProjectDirs::from("com", "foot-info", "foot-info")
+            .map(|proj_dirs| proj_dirs.data_dir().join("teams.json"))
+    }
+
+    /// Where user themes are looked for, one `<name>.json` each.
+    pub fn themes_dir() -> Option<PathBuf> {
+        ProjectDirs::from("com", "foot-info", "foot-info")
+            .map(|proj_dirs| proj_dirs.config_dir().join("themes"))
+    }
+
     fn get_config_path() -> Option<PathBuf> {
         ProjectDirs::from("com", "foot-info", "foot-info")
             .map(|proj_dirs| proj_dirs.config_dir().join("config.json"))
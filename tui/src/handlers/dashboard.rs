@@ -0,0 +1,162 @@
+use crate::app::Action;
+use crate::config::Favorite;
+use crate::keymap::KeyAction;
+use crate::models::{FavoriteStatus, ViewMode};
+use crate::state::AppState;
+use foot_info_core::models::Country;
+
+/// Handles key events when the app is on the dashboard.
+pub fn handle(state: &mut AppState, bound: KeyAction) -> Option<Action> {
+    let count = state.config.favorites.len();
+    match bound {
+        KeyAction::Back => {
+            state.view_mode = ViewMode::Search;
+            state.status_message = None;
+            state.error_message = None;
+            None
+        }
+        KeyAction::Up => {
+            state.selected_favorite_index = state.selected_favorite_index.saturating_sub(1);
+            None
+        }
+        KeyAction::Down => {
+            if state.selected_favorite_index + 1 < count {
+                state.selected_favorite_index += 1;
+            }
+            None
+        }
+        KeyAction::First => {
+            state.selected_favorite_index = 0;
+            None
+        }
+        KeyAction::Last => {
+            state.selected_favorite_index = count.saturating_sub(1);
+            None
+        }
+        KeyAction::MoveUp if state.selected_favorite_index > 0 => {
+            let from = state.selected_favorite_index;
+            move_favorite(state, from, from - 1);
+            None
+        }
+        KeyAction::MoveDown => {
+            let from = state.selected_favorite_index;
+            move_favorite(state, from, from + 1);
+            None
+        }
+        KeyAction::RemoveFavorite => {
+            remove_favorite(state, state.selected_favorite_index);
+            None
+        }
+        KeyAction::Submit => {
+            let favorite = state.config.favorites.get(state.selected_favorite_index)?.clone();
+            search_favorite(state, &favorite)
+        }
+        KeyAction::Refresh => refresh(state, state.config.favorites.clone()),
+        KeyAction::TopMatches | KeyAction::League => super::search::handle(state, bound),
+        _ => None,
+    }
+}
+
+/// Switches to the dashboard, fetching the favourites it hasn't heard of
+/// yet. Earlier results stay until refreshed.
+pub fn open(state: &mut AppState) -> Option<Action> {
+    state.view_mode = ViewMode::Dashboard;
+    state.error_message = None;
+    if state.config.favorites.is_empty() {
+        state.status_message = Some(match state.save_favorite_key() {
+            Some(key) => format!("No favorites yet: search a team and add it with {}.", key),
+            None => "No favorites yet: run \"add <team>\" from the command palette.".to_string(),
+        });
+        return None;
+    }
+    state.selected_favorite_index = state.selected_favorite_index.min(state.config.favorites.len() - 1);
+    state.status_message = None;
+
+    let unfetched: Vec<Favorite> = state
+        .config
+        .favorites
+        .iter()
+        .filter(|f| state.favorite_status(f).is_none())
+        .cloned()
+        .collect();
+    (!unfetched.is_empty()).then_some(Action::FetchDashboard(unfetched))
+}
+
+fn refresh(state: &mut AppState, favorites: Vec<Favorite>) -> Option<Action> {
+    if favorites.is_empty() {
+        return None;
+    }
+    state.status_message = Some("Refreshing favorites...".to_string());
+    Some(Action::FetchDashboard(favorites))
+}
+
+/// Searches `favorite` in its own country.
+fn search_favorite(state: &mut AppState, favorite: &Favorite) -> Option<Action> {
+    if !super::select_country(state, favorite.country) {
+        return None;
+    }
+    state.view_mode = ViewMode::Search;
+    state.search_input = favorite.team.clone();
+    state.status_message = Some(format!("Favorite: {} ({})", favorite.team, favorite.country));
+    Some(Action::Search(favorite.team.clone()))
+}
+
+/// Adds `team` to the favourites, listed in `country`, and saves them.
+pub fn add_favorite(state: &mut AppState, team: &str, country: Country) -> Option<Action> {
+    let team = team.trim();
+    if team.is_empty() {
+        state.status_message = Some("Type a team to add to the favorites.".to_string());
+        return None;
+    }
+    if !state.config.add_favorite(team, country) {
+        state.status_message = Some(format!("Already a favorite: {} ({})", team, country));
+        return None;
+    }
+    if save(state) {
+        state.status_message = Some(format!("Added favorite: {} ({})", team, country));
+    }
+    // On the dashboard it shows up straight away; elsewhere it's fetched
+    // when the dashboard is next opened
+    (state.view_mode == ViewMode::Dashboard).then(|| Action::FetchDashboard(vec![Favorite::new(team, country)]))
+}
+
+/// Removes the favourite at `index` and saves the rest.
+pub fn remove_favorite(state: &mut AppState, index: usize) {
+    if index >= state.config.favorites.len() {
+        return;
+    }
+    let removed = state.config.favorites.remove(index);
+    state.dashboard.retain(|(f, _)| *f != removed);
+    state.selected_favorite_index = state
+        .selected_favorite_index
+        .min(state.config.favorites.len().saturating_sub(1));
+    if save(state) {
+        state.status_message = Some(format!("Removed favorite: {}", removed.team));
+    }
+}
+
+/// Moves the favourite at `from` to `to`, keeping it selected.
+pub fn move_favorite(state: &mut AppState, from: usize, to: usize) {
+    if let Some(to) = state.config.move_favorite(from, to) {
+        state.selected_favorite_index = to;
+        if save(state) {
+            state.status_message = Some(format!("Moved {} to #{}", state.config.favorites[to].team, to + 1));
+        }
+    }
+}
+
+/// Saves the favourites; false, with the error shown, when they can't be.
+fn save(state: &mut AppState) -> bool {
+    if let Err(e) = state.config.save() {
+        state.error_message = Some(format!("Failed to save config: {}", e));
+        return false;
+    }
+    true
+}
+
+/// Applies a favourite's fetch, unless it was removed meanwhile.
+pub fn apply_status(state: &mut AppState, favorite: &Favorite, status: FavoriteStatus) {
+    if state.config.favorites.contains(favorite) {
+        state.set_favorite_status(favorite, status);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::models::ListKind;
+use crate::state::AppState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// `/`: starts typing into the filter of the list on screen.
+pub fn open(state: &mut AppState) {
+    state.filtering = state.current_list().is_some();
+}
+
+/// Types a key into the open filter: Enter keeps it, Esc clears it.
+/// Returns false for keys left to the bindings, so the arrows still move
+/// through what's left of the list.
+pub fn type_key(state: &mut AppState, key_event: KeyEvent) -> bool {
+    let Some(list) = state.current_list() else {
+        state.filtering = false;
+        return false;
+    };
+    match key_event.code {
+        KeyCode::Char(c) if !key_event.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            state.filter_mut(list).push(c);
+        }
+        KeyCode::Backspace => {
+            state.filter_mut(list).pop();
+        }
+        KeyCode::Enter => {
+            state.filtering = false;
+            return true;
+        }
+        KeyCode::Esc => {
+            state.filter_mut(list).clear();
+            state.filtering = false;
+        }
+        _ => return false,
+    }
+    apply(state, list);
+    true
+}
+
+/// Moves the list on screen to its next order and says which.
+pub fn cycle_sort(state: &mut AppState) {
+    let Some(list) = state.current_list() else {
+        return;
+    };
+    let label = match list {
+        ListKind::Results => {
+            state.match_sort = state.match_sort.next();
+            state.match_sort.label()
+        }
+        ListKind::TopMatches => {
+            state.top_match_sort = state.top_match_sort.next();
+            state.top_match_sort.label()
+        }
+        ListKind::Fixtures => {
+            state.fixture_sort = state.fixture_sort.next();
+            state.fixture_sort.label()
+        }
+        ListKind::Table => {
+            state.table_sort = state.table_sort.next();
+            state.table_sort.label()
+        }
+        ListKind::Scorers => {
+            state.scorer_sort = state.scorer_sort.next();
+            state.scorer_sort.label()
+        }
+    };
+    apply(state, list);
+    state.status_message = Some(format!("Sorted by: {}", label));
+}
+
+/// Shows `list` again after its filter or order changed, from the top.
+fn apply(state: &mut AppState, list: ListKind) {
+    match list {
+        ListKind::Results => super::search::apply_filter(state),
+        ListKind::TopMatches => super::top_matches::apply_filters(state),
+        ListKind::Fixtures => state.selected_fixture_index = 0,
+        ListKind::Table => state.selected_table_index = 0,
+        ListKind::Scorers => state.selected_scorer_index = 0,
+    }
+}
@@ -1,31 +1,31 @@
 use crate::app::Action;
+use crate::keymap::KeyAction;
 use crate::models::{LeagueTab, ViewMode};
 use crate::state::AppState;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use foot_info_core::changes::fixture_key;
+use foot_info_core::export::ical;
+use foot_info_core::models::{FixturesPage, LeagueFixture, PageCursor, PageDirection};
+use foot_info_core::providers::league_stats;
+use foot_info_core::utils::clock::SystemClock;
 
 /// Handles key events when the app is in League mode.
-pub fn handle(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
-    match key_event.code {
-        KeyCode::Esc => {
+pub fn handle(state: &mut AppState, bound: KeyAction) -> Option<Action> {
+    match bound {
+        KeyAction::Back => {
             state.view_mode = ViewMode::Search;
             state.status_message = None;
             state.error_message = None;
             None
         }
-        KeyCode::Char('l') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            state.view_mode = ViewMode::Search;
-            state.status_message = None;
-            None
-        }
-        KeyCode::Tab => {
+        KeyAction::NextTab => {
             state.league_tab = state.league_tab.next();
             None
         }
-        KeyCode::BackTab => {
+        KeyAction::PrevTab => {
             state.league_tab = state.league_tab.prev();
             None
         }
-        KeyCode::Up => {
+        KeyAction::Up => {
             match state.league_tab {
                 LeagueTab::Fixtures => {
                     if state.selected_fixture_index > 0 {
@@ -45,46 +45,179 @@ pub fn handle(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
             }
             None
         }
-        KeyCode::Down => {
-            if let Some(stats) = &state.league_stats {
-                match state.league_tab {
-                    LeagueTab::Fixtures => {
-                        if state.selected_fixture_index + 1 < stats.fixtures.len() {
-                            state.selected_fixture_index += 1;
-                        }
-                    }
-                    LeagueTab::Table => {
-                        if state.selected_table_index + 1 < stats.table.len() {
-                            state.selected_table_index += 1;
-                        }
-                    }
-                    LeagueTab::TopScorers => {
-                        if state.selected_scorer_index + 1 < stats.top_scorers.len() {
-                            state.selected_scorer_index += 1;
-                        }
-                    }
-                }
+        KeyAction::Down => {
+            let len = shown_len(state);
+            let selected = selected_index(state);
+            if *selected + 1 < len {
+                *selected += 1;
             }
             None
         }
-        KeyCode::Enter => {
-            if state.league_tab == LeagueTab::Fixtures {
-                if let Some(stats) = &state.league_stats {
-                    if let Some(fixture) = stats.fixtures.get(state.selected_fixture_index) {
-                        let team = fixture.home_team.clone();
-                        state.search_input = team.clone();
-                        state.view_mode = ViewMode::Search;
-                        state.status_message = None;
-                        return Some(Action::Search(team));
-                    }
-                }
+        KeyAction::Submit => {
+            if state.league_tab == LeagueTab::Fixtures
+                && let Some(fixture) = state.shown_fixtures().get(state.selected_fixture_index)
+            {
+                let team = fixture.home_team.clone();
+                state.search_input = team.clone();
+                state.view_mode = ViewMode::Search;
+                state.status_message = None;
+                return Some(Action::Search(team));
+            }
+            None
+        }
+        KeyAction::First => {
+            *selected_index(state) = 0;
+            None
+        }
+        KeyAction::Last => {
+            let last = shown_len(state).saturating_sub(1);
+            *selected_index(state) = last;
+            None
+        }
+        KeyAction::Previous if state.league_tab == LeagueTab::Fixtures => {
+            change_round(state, PageDirection::Previous)
+        }
+        KeyAction::Next if state.league_tab == LeagueTab::Fixtures => {
+            change_round(state, PageDirection::Next)
+        }
+        KeyAction::Export => {
+            if let Some(stats) = &state.league_stats {
+                // What's shown, so a filter exports just those fixtures
+                let fixtures: Vec<LeagueFixture> = state.shown_fixtures().into_iter().cloned().collect();
+                let calendar = ical::fixtures_to_ical(&stats.competition, &fixtures, &SystemClock);
+                let count = fixtures.iter().filter(|f| f.kickoff.is_some()).count();
+                let file_name = ical::file_name(&stats.competition);
+                super::save_calendar(state, &file_name, &calendar, count);
             }
             None
         }
-        KeyCode::Char('r') => {
+        KeyAction::Refresh => {
             let url = state.league_url.clone();
             Some(Action::FetchLeagueStats(url))
         }
         _ => None,
     }
 }
+
+/// Rows shown on the current tab.
+fn shown_len(state: &AppState) -> usize {
+    match state.league_tab {
+        LeagueTab::Fixtures => state.shown_fixtures().len(),
+        LeagueTab::Table => state.shown_table().len(),
+        LeagueTab::TopScorers => state.shown_scorers().len(),
+    }
+}
+
+/// The selection of the current tab.
+fn selected_index(state: &mut AppState) -> &mut usize {
+    match state.league_tab {
+        LeagueTab::Fixtures => &mut state.selected_fixture_index,
+        LeagueTab::Table => &mut state.selected_table_index,
+        LeagueTab::TopScorers => &mut state.selected_scorer_index,
+    }
+}
+
+// ── Matchday navigation helpers ──────────────────────────────────────────
+
+/// Moves the fixture selection to the first game of the previous / next round.
+/// When the loaded fixtures hold no such round, asks for the adjacent page.
+fn change_round(state: &mut AppState, direction: PageDirection) -> Option<Action> {
+    let stats = state.league_stats.as_ref()?;
+
+    if let Some(index) = round_start(state, direction) {
+        state.selected_fixture_index = index;
+        state.status_message = matchday_label(state);
+        return None;
+    }
+
+    let cursor = match direction {
+        PageDirection::Previous => stats.previous_page.clone(),
+        PageDirection::Next => stats.next_page.clone(),
+    };
+
+    match cursor {
+        Some(cursor) => {
+            state.status_message = Some("Loading more fixtures...".to_string());
+            Some(Action::FetchLeagueFixturesPage(cursor))
+        }
+        None => {
+            state.status_message = Some(match direction {
+                PageDirection::Previous => "No earlier matchday".to_string(),
+                PageDirection::Next => "No later matchday".to_string(),
+            });
+            None
+        }
+    }
+}
+
+/// "Matchday 31" for the selected fixture.
+fn matchday_label(state: &AppState) -> Option<String> {
+    let fixtures = state.shown_fixtures();
+    let matchday = fixtures.get(state.selected_fixture_index)?.matchday?;
+    Some(format!("Matchday {}", matchday))
+}
+
+/// Index of the first shown fixture of the round before / after the selected one.
+fn round_start(state: &AppState, direction: PageDirection) -> Option<usize> {
+    let fixtures = state.shown_fixtures();
+    let current = fixtures.get(state.selected_fixture_index)?.matchday?;
+
+    let target = match direction {
+        PageDirection::Previous => fixtures
+            .iter()
+            .filter_map(|f| f.matchday)
+            .filter(|&m| m < current)
+            .max()?,
+        PageDirection::Next => fixtures
+            .iter()
+            .filter_map(|f| f.matchday)
+            .filter(|&m| m > current)
+            .min()?,
+    };
+
+    fixtures.iter().position(|f| f.matchday == Some(target))
+}
+
+/// Merges a loaded page into the league stats and moves on to the round it brought in.
+pub fn apply_fixtures_page(state: &mut AppState, cursor: &PageCursor, page: FixturesPage) {
+    let Some(stats) = state.league_stats.as_mut() else {
+        return;
+    };
+
+    if page.fixtures.is_empty() {
+        match cursor.direction {
+            PageDirection::Previous => stats.previous_page = None,
+            PageDirection::Next => stats.next_page = None,
+        }
+        state.status_message = Some("No more fixtures".to_string());
+        return;
+    }
+
+    let added = page.fixtures.len();
+    let selected = state
+        .shown_fixtures()
+        .get(state.selected_fixture_index)
+        .map(|f| fixture_key(f));
+    let Some(stats) = state.league_stats.as_mut() else {
+        return;
+    };
+    league_stats::merge_fixtures_page(stats, page, cursor.direction);
+
+    // Keep the same fixture selected, then step into the new round
+    if let Some(selected) = selected {
+        state.selected_fixture_index = state
+            .shown_fixtures()
+            .iter()
+            .position(|f| fixture_key(f) == selected)
+            .unwrap_or(0);
+    }
+    match round_start(state, cursor.direction) {
+        Some(index) => {
+            state.selected_fixture_index = index;
+            state.status_message = matchday_label(state);
+        }
+        None => {
+            state.status_message = Some(format!("Loaded {} more fixtures", added));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::app::Action;
+use crate::keymap::KeyAction;
+use crate::palette::fuzzy_score;
+use crate::state::AppState;
+use foot_info_core::export::ical;
+use foot_info_core::models::Match;
+use foot_info_core::utils::clock::SystemClock;
+
+/// Handles key events while a search result's details are open.
+pub fn handle(state: &mut AppState, bound: KeyAction) -> Option<Action> {
+    let listing = state.match_detail.clone()?;
+    match bound {
+        KeyAction::Back => {
+            state.match_detail = None;
+            None
+        }
+        KeyAction::Export => {
+            let calendar = ical::matches_to_ical(&listing.teams, std::slice::from_ref(&listing), &SystemClock);
+            let count = usize::from(listing.kickoff.is_some());
+            super::save_calendar(state, &ical::file_name(&listing.teams), &calendar, count);
+            None
+        }
+        KeyAction::Copy => Some(Action::Copy(summary(state, &listing))),
+        KeyAction::SearchOpponent => {
+            let searched = state.searched_team.clone().unwrap_or_default();
+            let Some(opponent) = opponent(&listing, &searched) else {
+                state.status_message = Some("This listing doesn't name an opponent.".to_string());
+                return None;
+            };
+            let opponent = opponent.to_string();
+            state.match_detail = None;
+            state.search_input = opponent.clone();
+            state.status_message = Some(format!("Opponent: {}", opponent));
+            Some(Action::Search(opponent))
+        }
+        _ => None,
+    }
+}
+
+/// The side of `listing` that `team` matches least; `None` when it doesn't
+/// name two sides.
+fn opponent<'a>(listing: &'a Match, team: &str) -> Option<&'a str> {
+    let (home, away) = listing.sides()?;
+    let score = |side: &str| fuzzy_score(team, side).unwrap_or(i32::MIN);
+    Some(if score(away) > score(home) { home } else { away })
+}
+
+/// One line about `listing` to paste elsewhere, with the kickoff as shown.
+fn summary(state: &AppState, listing: &Match) -> String {
+    let source = state.results_country().source_zone();
+    let format = state.kickoff_format();
+    let kickoff = match listing.kickoff {
+        Some(kickoff) => format!("{} {}", format.date(kickoff, source), format.time(kickoff, source)),
+        None => format!("{} {}", listing.date, listing.time),
+    };
+    let mut parts = vec![listing.teams.clone(), listing.competition.clone(), kickoff];
+    if !listing.channels.is_empty() {
+        parts.push(listing.channels.join(", "));
+    }
+    parts.join(" · ")
+}
@@ -1,60 +1,324 @@
+mod dashboard;
+mod filter;
 mod league;
+mod match_detail;
+mod mouse;
+mod palette;
+mod scroll;
 mod search;
 mod top_matches;
 
 use crate::app::Action;
-use crate::models::ViewMode;
+use crate::config::Config;
+use crate::keymap::{KeyAction, KeyBinding};
+use crate::models::{FavoriteStatus, InputMode, ListKind, ViewMode};
+use crate::palette::Palette;
 use crate::state::AppState;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::suggest;
+use chrono_tz::Tz;
+use crossterm::event::{KeyEvent, MouseEvent};
+use foot_info_core::changes;
+use foot_info_core::models::Country;
+use foot_info_core::utils::time::DisplayZone;
+use ratatui::layout::Rect;
+use std::fs;
+use std::time::Instant;
+
+/// What to show on startup: the dashboard once there are favourites,
+/// otherwise the Search view.
+pub fn start(state: &mut AppState) -> Option<Action> {
+    if state.config.favorites.is_empty() {
+        return None;
+    }
+    dashboard::open(state)
+}
 
 /// Handles a key press event, mutating state and optionally returning an Action to dispatch.
 pub fn handle_key_event(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
-    // Shared shortcuts available on all screens
-    if let Some(action) = handle_global(state, key_event) {
+    let action = key_event_action(state, key_event);
+    scroll::follow(state);
+    action
+}
+
+/// Handles a mouse event that came at `at` (clicks are timed to tell
+/// double-clicks), optionally returning an Action to dispatch.
+pub fn handle_mouse_event(state: &mut AppState, mouse_event: MouseEvent, at: Instant) -> Option<Action> {
+    let list = state.current_list();
+    let action = mouse::handle(state, mouse_event, at);
+    close_stale_filter(state, list);
+    scroll::follow(state);
+    action
+}
+
+/// The terminal was resized: lists scroll to keep their selection shown.
+pub fn handle_resize(state: &mut AppState, width: u16, height: u16) {
+    state.screen = Rect::new(0, 0, width, height);
+    scroll::follow(state);
+}
+
+fn key_event_action(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
+    // The help overlay closes on the next key, whichever it is
+    if state.show_help {
+        state.show_help = false;
+        return None;
+    }
+
+    // The open palette takes every key
+    if state.palette.is_some() {
+        return palette::handle(state, key_event);
+    }
+
+    // The open filter takes what's typed; other keys work as usual
+    if state.filtering && filter::type_key(state, key_event) {
+        return None;
+    }
+
+    let bound = state.keymap.lookup(state.key_scope(), &key_event);
+
+    // A letter bound to First (vim's gg) only jumps on its second press
+    let pending_first = std::mem::take(&mut state.pending_first);
+    if bound == Some(KeyAction::First)
+        && KeyBinding::new(key_event.code, key_event.modifiers).is_plain_char()
+        && !pending_first
+    {
+        state.pending_first = true;
+        return None;
+    }
+
+    match bound {
+        Some(bound) => {
+            let list = state.current_list();
+            let action = perform(state, bound);
+            close_stale_filter(state, list);
+            action
+        }
+        // Keys bound to nothing are typed into the search box
+        None if state.view_mode == ViewMode::Search
+            && state.input_mode == InputMode::Insert
+            && state.match_detail.is_none() =>
+        {
+            search::type_key(state, key_event)
+        }
+        None => None,
+    }
+}
+
+/// A filter being typed belongs to `list`, the one it was opened on.
+fn close_stale_filter(state: &mut AppState, list: Option<ListKind>) {
+    if state.current_list() != list || state.match_detail.is_some() {
+        state.filtering = false;
+    }
+}
+
+/// Runs a bound action: shared shortcuts first, then the current view's.
+fn perform(state: &mut AppState, bound: KeyAction) -> Option<Action> {
+    if let Some(action) = handle_global(state, bound) {
         return action;
     }
+    if state.match_detail.is_some() {
+        return match_detail::handle(state, bound);
+    }
 
-    // Mode-specific shortcuts
     match state.view_mode {
-        ViewMode::Search => search::handle(state, key_event),
-        ViewMode::TopMatches => top_matches::handle(state, key_event),
-        ViewMode::League => league::handle(state, key_event),
+        ViewMode::Dashboard => dashboard::handle(state, bound),
+        ViewMode::Search => search::handle(state, bound),
+        ViewMode::TopMatches => top_matches::handle(state, bound),
+        ViewMode::League => league::handle(state, bound),
     }
 }
 
-/// Shortcuts available on every screen (e.g. switch country).
+/// Actions that work the same on every screen (e.g. switch country).
 /// Returns `Some(Some(action))` if an async action is needed,
 /// `Some(None)` if consumed but no action needed,
 /// `None` if the key wasn't handled here (fall through to mode handler).
-fn handle_global(state: &mut AppState, key_event: KeyEvent) -> Option<Option<Action>> {
-    match key_event.code {
-        KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            state.current_provider_index =
-                (state.current_provider_index + 1) % state.client.providers().len();
-            let provider = state.get_current_provider();
-            state.status_message = Some(format!(
-                "Switched to: {} ({})",
-                provider.country(),
-                provider.name()
-            ));
+fn handle_global(state: &mut AppState, bound: KeyAction) -> Option<Option<Action>> {
+    match bound {
+        KeyAction::Quit => {
+            state.exit = true;
+            Some(None)
+        }
+        KeyAction::Help => {
+            state.show_help = true;
+            Some(None)
+        }
+        KeyAction::SwitchCountry => {
+            let next = (state.current_provider_index + 1) % state.client.providers().len();
+            select_provider(state, next);
             Some(None) // Consumed, no async action needed
         }
+        KeyAction::CommandPalette => {
+            state.match_detail = None;
+            state.palette = Some(Palette::default());
+            Some(None)
+        }
+        KeyAction::ToggleZone => Some(change_zone(state)),
+        KeyAction::Filter if state.current_list().is_some() => {
+            filter::open(state);
+            Some(None)
+        }
+        KeyAction::CycleSort if state.current_list().is_some() => {
+            filter::cycle_sort(state);
+            Some(None)
+        }
+        KeyAction::PageUp | KeyAction::PageDown => {
+            scroll::page(state, bound == KeyAction::PageDown);
+            Some(None)
+        }
+        KeyAction::FocusSearch => {
+            if state.view_mode != ViewMode::Search {
+                state.view_mode = ViewMode::Search;
+                state.status_message = None;
+                state.error_message = None;
+            }
+            state.input_mode = InputMode::Insert;
+            Some(None)
+        }
+        KeyAction::NormalMode => {
+            state.input_mode = InputMode::Normal;
+            Some(None)
+        }
         _ => None, // Not handled, fall through to mode handler
     }
 }
 
-/// Applies an incoming Action to state. Returns true if an async task should be spawned.
+/// Ctrl+z: with a zone name typed into the search box (e.g. "Europe/Paris"),
+/// saves it as the configured zone; otherwise toggles between that zone and
+/// the broadcaster's. Times are formatted while fetching, so whatever is on
+/// screen gets fetched again.
+fn change_zone(state: &mut AppState) -> Option<Action> {
+    let typed_zone = match DisplayZone::parse(&state.search_input) {
+        Some(DisplayZone::Named(tz)) if state.view_mode == ViewMode::Search => Some(tz),
+        _ => None,
+    };
+
+    if let Some(tz) = typed_zone {
+        if !save_zone(state, Some(tz)) {
+            return None;
+        }
+        state.search_input.clear();
+        search::close_suggestions(state);
+    } else {
+        state.show_source_zone = !state.show_source_zone;
+    }
+    show_zone(state)
+}
+
+/// Saves `tz` as the configured zone (`None` for the machine's) and stops
+/// showing the broadcaster's. Returns false, with the error shown, when the
+/// config can't be saved.
+fn save_zone(state: &mut AppState, tz: Option<Tz>) -> bool {
+    state.config.timezone = tz.map(|tz| tz.name().to_string());
+    if let Err(e) = state.config.save() {
+        state.error_message = Some(format!("Failed to save config: {}", e));
+        return false;
+    }
+    state.show_source_zone = false;
+    true
+}
+
+/// Applies a changed display zone, refetching whatever is on screen.
+fn show_zone(state: &mut AppState) -> Option<Action> {
+    state.refresh_client_zone();
+    state.status_message = Some(format!("Times shown in: {}", state.display_zone().label()));
+    refetch_current_view(state)
+}
+
+/// Switches to the provider at `index` and says so.
+fn select_provider(state: &mut AppState, index: usize) {
+    state.current_provider_index = index;
+    let provider = state.get_current_provider();
+    state.status_message = Some(format!(
+        "Switched to: {} ({})",
+        provider.country(),
+        provider.name()
+    ));
+}
+
+/// Switches to the provider of `country`; false, said in the status, when
+/// there is none.
+fn select_country(state: &mut AppState, country: Country) -> bool {
+    match state.client.providers().iter().position(|p| p.country() == country) {
+        Some(index) => {
+            select_provider(state, index);
+            true
+        }
+        None => {
+            state.status_message = Some(format!("No provider for {}", country));
+            false
+        }
+    }
+}
+
+fn refetch_current_view(state: &AppState) -> Option<Action> {
+    match state.view_mode {
+        ViewMode::Dashboard if !state.config.favorites.is_empty() => {
+            Some(Action::FetchDashboard(state.config.favorites.clone()))
+        }
+        ViewMode::Dashboard => None,
+        ViewMode::Search if !state.all_matches.is_empty() && !state.search_input.is_empty() => {
+            Some(Action::Search(state.search_input.clone()))
+        }
+        ViewMode::Search => None,
+        ViewMode::TopMatches => Some(match state.schedule_date {
+            Some(date) => Action::FetchSchedule(date),
+            None => Action::FetchTopMatches,
+        }),
+        ViewMode::League => Some(Action::FetchLeagueStats(state.league_url.clone())),
+    }
+}
+
+/// Ctrl+e: writes a calendar of `count` matches into the configured export
+/// directory and reports where it went.
+fn save_calendar(state: &mut AppState, file_name: &str, calendar: &str, count: usize) {
+    if count == 0 {
+        state.status_message = Some("Nothing to export: no kickoff times known.".to_string());
+        return;
+    }
+
+    let dir = state.config.export_dir();
+    let path = dir.join(file_name);
+    match fs::create_dir_all(&dir).and_then(|_| fs::write(&path, calendar)) {
+        Ok(()) => {
+            state.error_message = None;
+            state.status_message = Some(format!("Exported {} matches to {}", count, path.display()));
+        }
+        Err(e) => state.error_message = Some(format!("Failed to export calendar: {}", e)),
+    }
+}
+
+/// Applies an incoming Action to state. Returns true if the app has more to
+/// do: spawn an async task, or write the clipboard.
 pub fn handle_action(state: &mut AppState, action: &Action) -> bool {
+    let more = apply_action(state, action);
+    scroll::follow(state);
+    more
+}
+
+fn apply_action(state: &mut AppState, action: &Action) -> bool {
     match action {
-        Action::Search(_) => {
+        Action::Search(team) => {
+            search::close_suggestions(state);
+            state.match_detail = None;
+            state.searched_team = Some(team.clone());
+            let country = state.get_current_provider().country();
+            state.config.remember_search(team, country);
+            // Not worth failing the search over
+            let _ = state.config.save();
             state.is_loading = true;
             state.error_message = None;
             state.matches.clear();
+            state.all_matches.clear();
+            state.match_filter.clear();
+            state.changes.clear();
+            state.changes_key = Some(changes::team_key(team, country));
+            state.results_source = None;
             true
         }
-        Action::MatchesFound(matches) => {
+        Action::MatchesFound(source, matches) => {
             state.is_loading = false;
-            state.matches = matches.clone();
+            state.results_source = Some(source.clone());
+            state.all_matches = matches.clone();
+            search::apply_filter(state);
             false
         }
         Action::Error(e) => {
@@ -65,22 +329,53 @@ pub fn handle_action(state: &mut AppState, action: &Action) -> bool {
         Action::FetchTopMatches => {
             state.is_loading = true;
             state.error_message = None;
+            state.schedule_date = None;
             state.top_matches.clear();
+            state.all_top_matches.clear();
             state.selected_top_match_index = 0;
             true
         }
+        Action::FetchSchedule(date) => {
+            state.is_loading = true;
+            state.error_message = None;
+            state.schedule_date = Some(*date);
+            state.top_matches.clear();
+            state.all_top_matches.clear();
+            state.selected_top_match_index = 0;
+            true
+        }
+        // The user has moved to another day since this one was fetched
+        Action::ScheduleFound(date, _) if state.schedule_date != Some(*date) => false,
+        Action::ScheduleFound(date, matches) => {
+            state.is_loading = false;
+            state.all_top_matches = matches.clone();
+            top_matches::apply_filters(state);
+            state.status_message = Some(format!(
+                "Schedule for {}: {} matches",
+                date.format("%a %d %b %Y"),
+                matches.len()
+            ));
+            false
+        }
         Action::TopMatchesFound(top_matches) => {
             state.is_loading = false;
-            state.top_matches = top_matches.clone();
-            state.selected_top_match_index = 0;
+            state.all_top_matches = top_matches.clone();
+            top_matches::apply_filters(state);
             state.status_message = Some(format!("Found {} upcoming matches", top_matches.len()));
             false
         }
         Action::FetchLeagueStats(url) => {
             state.is_loading = true;
             state.error_message = None;
+            if *url != state.league_url {
+                state.fixture_filter.clear();
+                state.table_filter.clear();
+                state.scorer_filter.clear();
+            }
             state.league_url = url.clone();
             state.league_stats = None;
+            state.changes.clear();
+            state.changes_key = Some(changes::league_key(url));
             state.selected_fixture_index = 0;
             state.selected_table_index = 0;
             state.selected_scorer_index = 0;
@@ -89,6 +384,11 @@ pub fn handle_action(state: &mut AppState, action: &Action) -> bool {
         Action::LeagueStatsFound(stats) => {
             state.is_loading = false;
             let title = stats.competition.clone();
+            if suggest::index_teams(&mut state.team_index, stats)
+                && let Some(path) = Config::team_index_path()
+            {
+                let _ = suggest::save_index(&path, &state.team_index);
+            }
             state.league_stats = Some(stats.clone());
             state.status_message = Some(format!(
                 "Loaded league: {}",
@@ -96,5 +396,46 @@ pub fn handle_action(state: &mut AppState, action: &Action) -> bool {
             ));
             false
         }
+        // A late answer for an earlier search or league isn't about what's shown
+        Action::ChangesFound(key, _) if state.changes_key.as_ref() != Some(key) => false,
+        Action::ChangesFound(_, changes) => {
+            state.changes = changes.clone();
+            if !changes.is_empty() {
+                state.status_message = Some(format!(
+                    "Changed since last look: {}",
+                    changes::summarize(changes)
+                ));
+            }
+            false
+        }
+        Action::FetchLeagueFixturesPage(_) => {
+            state.is_loading = true;
+            state.error_message = None;
+            true
+        }
+        Action::LeagueFixturesPageFound(cursor, page) => {
+            state.is_loading = false;
+            league::apply_fixtures_page(state, cursor, page.clone());
+            false
+        }
+        Action::Copy(_) => true,
+        Action::Tick(now) => {
+            state.now = *now;
+            false
+        }
+        Action::FetchDashboard(favorites) => {
+            for favorite in favorites {
+                state.set_favorite_status(favorite, FavoriteStatus::Loading);
+            }
+            !favorites.is_empty()
+        }
+        Action::FavoriteMatchesFound(favorite, matches) => {
+            dashboard::apply_status(state, favorite, FavoriteStatus::Matches(matches.clone()));
+            false
+        }
+        Action::FavoriteError(favorite, err) => {
+            dashboard::apply_status(state, favorite, FavoriteStatus::Error(err.clone()));
+            false
+        }
     }
 }
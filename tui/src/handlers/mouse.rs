@@ -0,0 +1,77 @@
+use crate::app::Action;
+use crate::keymap::KeyAction;
+use crate::models::{InputMode, ViewMode};
+use crate::state::AppState;
+use crate::ui::{self, Hit};
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use std::time::{Duration, Instant};
+
+/// Two clicks on the same thing within this make a double-click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Handles a mouse event that came at `at`: a click selects what it lands
+/// on, a second one on the same row opens it, and the wheel moves through
+/// the list.
+pub fn handle(state: &mut AppState, event: MouseEvent, at: Instant) -> Option<Action> {
+    // Overlays are for the keyboard; the mouse waits for them to close
+    if state.show_help || state.palette.is_some() || state.match_detail.is_some() {
+        return None;
+    }
+    match event.kind {
+        MouseEventKind::ScrollDown => {
+            super::scroll::step(state, true);
+            None
+        }
+        MouseEventKind::ScrollUp => {
+            super::scroll::step(state, false);
+            None
+        }
+        MouseEventKind::Down(MouseButton::Left) => {
+            let hit = ui::hit(state, event.column, event.row)?;
+            click(state, hit, at)
+        }
+        _ => None,
+    }
+}
+
+/// Whether two clicks landed on the same thing; a row is the same one even
+/// if the list scrolled between the clicks.
+fn same_target(a: Hit, b: Hit) -> bool {
+    match (a, b) {
+        (Hit::Row { index: a, .. }, Hit::Row { index: b, .. }) => a == b,
+        _ => a == b,
+    }
+}
+
+fn click(state: &mut AppState, hit: Hit, at: Instant) -> Option<Action> {
+    let double = state
+        .last_click
+        .is_some_and(|(last, previous)| same_target(previous, hit) && at.duration_since(last) <= DOUBLE_CLICK);
+    // A third click starts over rather than opening it again
+    state.last_click = (!double).then_some((at, hit));
+
+    match hit {
+        Hit::Row { index, offset } => {
+            let position = state.list_position_mut();
+            *position.0 = index;
+            *position.1 = offset;
+            if !double {
+                return None;
+            }
+            // Results open their details; elsewhere a row opens like Enter
+            let open = match state.view_mode {
+                ViewMode::Search => KeyAction::Details,
+                _ => KeyAction::Submit,
+            };
+            super::perform(state, open)
+        }
+        Hit::Tab(tab) => {
+            state.league_tab = tab;
+            None
+        }
+        Hit::SearchBar => {
+            state.input_mode = InputMode::Insert;
+            None
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::app::Action;
+use crate::config::Config;
+use crate::keymap::{KeyAction, Scope};
+use crate::models::ViewMode;
+use crate::palette::{self, Candidate, Command};
+use crate::state::AppState;
+use crate::ui::theme::Theme;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use foot_info_core::models::Country;
+use foot_info_core::providers::league_stats;
+use foot_info_core::utils::time::DisplayZone;
+
+/// Handles key events while the command palette is open: typing filters
+/// the commands, Enter runs the highlighted one, Esc closes it.
+pub fn handle(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
+    match state.keymap.action(Scope::Global, &key_event) {
+        Some(KeyAction::Quit) => {
+            state.exit = true;
+            return None;
+        }
+        Some(KeyAction::CommandPalette) => {
+            state.palette = None;
+            return None;
+        }
+        _ => {}
+    }
+
+    let recent = &state.config.recent_commands;
+    let palette = state.palette.as_mut()?;
+    match key_event.code {
+        KeyCode::Esc => state.palette = None,
+        KeyCode::Up => palette.selected = palette.selected.saturating_sub(1),
+        KeyCode::Down if palette.selected + 1 < palette.candidates(recent).len() => palette.selected += 1,
+        // Completes the highlighted command, ready for its arguments
+        KeyCode::Tab => {
+            if let Some(candidate) = palette.candidates(recent).get(palette.selected) {
+                palette.input = format!("{} {}", candidate.command.keyword(), candidate.args);
+                palette.selected = 0;
+            }
+        }
+        KeyCode::Enter => {
+            let candidate = palette.candidates(recent).get(palette.selected).cloned();
+            state.palette = None;
+            return run(state, candidate?);
+        }
+        KeyCode::Backspace => {
+            palette.input.pop();
+            palette.selected = 0;
+        }
+        KeyCode::Char(c) if !key_event.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            palette.input.push(c);
+            palette.selected = 0;
+        }
+        _ => {}
+    }
+    None
+}
+
+/// Runs a palette command and remembers it as recently used.
+fn run(state: &mut AppState, candidate: Candidate) -> Option<Action> {
+    palette::remember(&mut state.config.recent_commands, candidate.command);
+    // Recents are a convenience: failing to save them must not get in the
+    // way of the command itself
+    let _ = state.config.save();
+
+    let args = candidate.args.trim();
+    match candidate.command {
+        Command::Search => search(state, args),
+        Command::League => league(state, args),
+        Command::TopMatches => from_search(state, KeyAction::TopMatches),
+        Command::Country => country(state, args),
+        Command::Favorites => super::dashboard::open(state),
+        Command::AddFavorite => add_favorite(state, args),
+        Command::RemoveFavorite => remove_favorite(state, args),
+        Command::MoveFavorite => move_favorite(state, args),
+        Command::Export if state.view_mode == ViewMode::TopMatches => {
+            state.status_message = Some("Nothing to export here: open a search or a league.".to_string());
+            None
+        }
+        Command::Export => super::perform(state, KeyAction::Export),
+        Command::Refresh => super::refetch_current_view(state),
+        Command::Timezone => timezone(state, args),
+        Command::Theme => theme(state, args),
+        Command::Help => super::perform(state, KeyAction::Help),
+        Command::Quit => super::perform(state, KeyAction::Quit),
+    }
+}
+
+/// Runs a Search view action from whichever view is open.
+fn from_search(state: &mut AppState, action: KeyAction) -> Option<Action> {
+    state.view_mode = ViewMode::Search;
+    super::perform(state, action)
+}
+
+/// "search lyon fr": a team, then optionally a country to search in.
+/// With no team, searches what's typed in the search box.
+fn search(state: &mut AppState, args: &str) -> Option<Action> {
+    let (team, country) = split_country(args);
+    if let Some(country) = country
+        && !super::select_country(state, country)
+    {
+        return None;
+    }
+    if !team.is_empty() {
+        state.search_input = team.to_string();
+    }
+    if state.search_input.is_empty() {
+        state.status_message = Some(format!("Usage: search {}", Command::Search.usage()));
+        return None;
+    }
+    from_search(state, KeyAction::Submit)
+}
+
+/// "lyon fr" as the team and its country; without a country code after
+/// the team, all of it is the team.
+fn split_country(args: &str) -> (&str, Option<Country>) {
+    match args.rsplit_once(' ') {
+        Some((team, code)) => match Country::parse(code) {
+            Some(country) => (team.trim(), Some(country)),
+            None => (args, None),
+        },
+        None => (args, None),
+    }
+}
+
+/// "add lyon fr": a favourite, listed in the given country or the current
+/// one. With no team, adds what's typed in the search box.
+fn add_favorite(state: &mut AppState, args: &str) -> Option<Action> {
+    let (team, country) = split_country(args);
+    let team = if team.is_empty() { state.search_input.clone() } else { team.to_string() };
+    let country = country.unwrap_or_else(|| state.get_current_provider().country());
+    super::dashboard::add_favorite(state, &team, country)
+}
+
+/// The favourite named `team`, or with none the one selected on the
+/// dashboard; `None`, said in the status, when there isn't one.
+fn favorite_index(state: &mut AppState, team: &str, command: Command) -> Option<usize> {
+    if team.is_empty() {
+        if state.view_mode == ViewMode::Dashboard && !state.config.favorites.is_empty() {
+            return Some(state.selected_favorite_index);
+        }
+        state.status_message = Some(format!("Usage: {} {}", command.keyword(), command.usage()));
+        return None;
+    }
+    let index = state.config.find_favorite(team);
+    if index.is_none() {
+        state.status_message = Some(format!("Not a favorite: {}", team));
+    }
+    index
+}
+
+/// "remove lyon", or "remove" for the favourite selected on the dashboard.
+fn remove_favorite(state: &mut AppState, args: &str) -> Option<Action> {
+    let index = favorite_index(state, args, Command::RemoveFavorite)?;
+    super::dashboard::remove_favorite(state, index);
+    None
+}
+
+/// "move lyon 1", "move lyon up", or "move down" for the favourite
+/// selected on the dashboard.
+fn move_favorite(state: &mut AppState, args: &str) -> Option<Action> {
+    let (team, place) = args.rsplit_once(' ').unwrap_or(("", args));
+    let index = favorite_index(state, team.trim(), Command::MoveFavorite)?;
+    let to = match place.to_lowercase().as_str() {
+        "up" => index.saturating_sub(1),
+        "down" => index + 1,
+        place => match place.parse::<usize>() {
+            Ok(position) if position > 0 => position - 1,
+            _ => {
+                state.status_message = Some(format!("Usage: move {}", Command::MoveFavorite.usage()));
+                return None;
+            }
+        },
+    };
+    super::dashboard::move_favorite(state, index, to);
+    None
+}
+
+/// "league serie a": a competition by short name, path or URL. With none,
+/// opens the last one.
+fn league(state: &mut AppState, args: &str) -> Option<Action> {
+    if !args.is_empty() {
+        match league_stats::competition_url(args) {
+            Some(url) => state.league_url = url,
+            None => {
+                state.status_message = Some(format!("Unknown competition: {}", args));
+                return None;
+            }
+        }
+    }
+    from_search(state, KeyAction::League)
+}
+
+/// "country fr" switches to that country; with none, to the next one.
+fn country(state: &mut AppState, args: &str) -> Option<Action> {
+    if args.is_empty() {
+        return super::perform(state, KeyAction::SwitchCountry);
+    }
+    match Country::parse(args) {
+        Some(country) => {
+            super::select_country(state, country);
+        }
+        None => state.status_message = Some(format!("Unknown country: {} (uk, us or fr)", args)),
+    }
+    None
+}
+
+/// "timezone Europe/Paris" saves that zone, "timezone local" the
+/// machine's, "timezone source" shows the broadcaster's; with none, toggles
+/// like Ctrl+z.
+fn timezone(state: &mut AppState, args: &str) -> Option<Action> {
+    if args.is_empty() {
+        state.show_source_zone = !state.show_source_zone;
+        return super::show_zone(state);
+    }
+    match DisplayZone::parse(args) {
+        Some(DisplayZone::Source) => state.show_source_zone = true,
+        Some(DisplayZone::Local) => {
+            if !super::save_zone(state, None) {
+                return None;
+            }
+        }
+        Some(DisplayZone::Named(tz)) => {
+            if !super::save_zone(state, Some(tz)) {
+                return None;
+            }
+        }
+        None => {
+            state.status_message = Some(format!("Unknown time zone: {}", args));
+            return None;
+        }
+    }
+    super::show_zone(state)
+}
+
+/// "theme solarized" switches to that theme and saves it; with none, to
+/// the next one, bundled themes first.
+fn theme(state: &mut AppState, args: &str) -> Option<Action> {
+    let themes_dir = Config::themes_dir();
+    let name = if args.is_empty() {
+        let available = Theme::available(themes_dir.as_deref());
+        let next = available
+            .iter()
+            .position(|name| *name == state.theme.name)
+            .map_or(0, |i| (i + 1) % available.len());
+        available[next].clone()
+    } else {
+        args.to_string()
+    };
+
+    match Theme::named(&name, themes_dir.as_deref()) {
+        Ok(theme) => {
+            state.config.theme = Some(theme.name.clone());
+            state.status_message = Some(format!("Theme: {}", theme.name));
+            state.theme = theme.for_depth(state.theme.depth);
+            if let Err(e) = state.config.save() {
+                state.error_message = Some(format!("Failed to save config: {}", e));
+            }
+        }
+        Err(err) => state.status_message = Some(err),
+    }
+    None
+}
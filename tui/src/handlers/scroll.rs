@@ -0,0 +1,34 @@
+use crate::state::AppState;
+use crate::ui;
+
+/// PgDn / PgUp: moves the selection a screenful through the list on
+/// screen; a day column in Top Matches.
+pub fn page(state: &mut AppState, down: bool) {
+    let Some(viewport) = ui::viewport(state) else {
+        return;
+    };
+    let (selected, offset) = state.list_position_mut();
+    if let Some(index) = viewport.page(*offset, *selected, down) {
+        *selected = index;
+    }
+}
+
+/// Mouse wheel: moves the selection a row down or up the list on screen.
+pub fn step(state: &mut AppState, down: bool) {
+    let Some(viewport) = ui::viewport(state) else {
+        return;
+    };
+    let (selected, _) = state.list_position_mut();
+    if let Some(index) = viewport.step(*selected, down) {
+        *selected = index;
+    }
+}
+
+/// Scrolls the list on screen as little as it takes to show its selection.
+pub fn follow(state: &mut AppState) {
+    let Some(viewport) = ui::viewport(state) else {
+        return;
+    };
+    let (selected, offset) = state.list_position_mut();
+    *offset = viewport.follow(*offset, *selected);
+}
@@ -1,64 +1,192 @@
 use crate::app::Action;
-use crate::models::ViewMode;
+use crate::keymap::KeyAction;
+use crate::models::{Filterable, InputMode, ViewMode};
 use crate::state::AppState;
+use crate::suggest;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use foot_info_core::export::ical;
+use foot_info_core::models::Match;
+use foot_info_core::utils::clock::SystemClock;
 
 /// Handles key events when the app is in Search mode.
-pub fn handle(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
-    match key_event.code {
-        KeyCode::Esc => {
-            state.exit = true;
-            None
-        }
-        KeyCode::Enter => {
+pub fn handle(state: &mut AppState, bound: KeyAction) -> Option<Action> {
+    match bound {
+        KeyAction::Submit => {
+            if let Some(suggestion) = state.selected_suggestion.and_then(|i| state.suggestions.get(i)) {
+                state.search_input = suggestion.team.clone();
+            }
+            close_suggestions(state);
             if !state.search_input.is_empty() {
                 state.status_message = None;
+                // With vim keys, the results are there to be moved through
+                if state.config.vim_keys() {
+                    state.input_mode = InputMode::Normal;
+                }
                 Some(Action::Search(state.search_input.clone()))
             } else {
                 None
             }
         }
-        KeyCode::Char('t') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+        KeyAction::Up if state.input_mode == InputMode::Insert => {
+            recall_previous(state);
+            None
+        }
+        KeyAction::Down if state.input_mode == InputMode::Insert => {
+            recall_next(state);
+            None
+        }
+        KeyAction::Complete if state.input_mode == InputMode::Insert => {
+            let index = state.selected_suggestion.unwrap_or(0);
+            if let Some(suggestion) = state.suggestions.get(index) {
+                state.search_input = suggestion.team.clone();
+                refresh_suggestions(state);
+            }
+            None
+        }
+        KeyAction::Details => {
+            state.match_detail = state.matches.get(state.selected_match_index).cloned();
+            None
+        }
+        KeyAction::Up => {
+            state.selected_match_index = state.selected_match_index.saturating_sub(1);
+            None
+        }
+        KeyAction::Down => {
+            if state.selected_match_index + 1 < state.matches.len() {
+                state.selected_match_index += 1;
+            }
+            None
+        }
+        KeyAction::First => {
+            state.selected_match_index = 0;
+            None
+        }
+        KeyAction::Last => {
+            state.selected_match_index = state.matches.len().saturating_sub(1);
+            None
+        }
+        KeyAction::TopMatches => {
             state.view_mode = ViewMode::TopMatches;
             state.error_message = None;
             state.status_message = Some("Fetching upcoming top matches...".to_string());
             Some(Action::FetchTopMatches)
         }
-        KeyCode::Char('l') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+        KeyAction::League => {
             state.view_mode = ViewMode::League;
             state.error_message = None;
             state.status_message = Some("Loading league stats...".to_string());
             Some(Action::FetchLeagueStats(state.league_url.clone()))
         }
-        KeyCode::Char('s') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            if !state.search_input.is_empty() {
-                state.config.favorite_team = Some(state.search_input.clone());
-                if let Err(e) = state.config.save() {
-                    state.error_message = Some(format!("Failed to save config: {}", e));
-                } else {
-                    state.status_message = Some(format!("Saved favorite: {}", state.search_input));
-                }
+        KeyAction::SaveFavorite => {
+            if state.search_input.trim().is_empty() {
+                return None;
             }
-            None
+            let team = state.search_input.clone();
+            let country = state.get_current_provider().country();
+            super::dashboard::add_favorite(state, &team, country)
         }
-        KeyCode::Char('f') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            if let Some(team) = &state.config.favorite_team {
-                state.search_input = team.clone();
-                state.status_message = Some(format!("Loaded favorite: {}", team));
-                Some(Action::Search(team.clone()))
-            } else {
-                state.status_message = Some("No favorite team saved.".to_string());
-                None
-            }
+        KeyAction::Dashboard => super::dashboard::open(state),
+        KeyAction::Export => {
+            // The box may have been edited since; the results are the search's
+            let name = state.searched_team.clone().unwrap_or_default();
+            let calendar = ical::matches_to_ical(&name, &state.matches, &SystemClock);
+            let count = state.matches.iter().filter(|m| m.kickoff.is_some()).count();
+            super::save_calendar(state, &ical::file_name(&name), &calendar, count);
+            None
         }
-        KeyCode::Char(c) => {
+        _ => None,
+    }
+}
+
+/// Rebuilds the results shown from all found, the filter and the order.
+pub fn apply_filter(state: &mut AppState) {
+    let mut matches: Vec<Match> = state
+        .all_matches
+        .iter()
+        .filter(|m| m.matches_filter(&state.match_filter))
+        .cloned()
+        .collect();
+    matches.sort_by(|a, b| state.match_sort.compare(a, b));
+    state.matches = matches;
+    state.selected_match_index = 0;
+}
+
+/// Types a key bound to nothing into the search box.
+pub fn type_key(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
+    match key_event.code {
+        // Unbound Ctrl/Alt chords are shortcuts that don't exist, not text
+        KeyCode::Char(c) if !key_event.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
             state.search_input.push(c);
-            None
         }
         KeyCode::Backspace => {
             state.search_input.pop();
-            None
         }
-        _ => None,
+        _ => return None,
+    }
+    refresh_suggestions(state);
+    None
+}
+
+/// Up while typing: back up through the highlighted suggestions, then the
+/// results, then the teams searched in this country.
+fn recall_previous(state: &mut AppState) {
+    if let Some(index) = state.selected_suggestion {
+        state.selected_suggestion = index.checked_sub(1);
+        return;
+    }
+    if state.history_cursor.is_none() && state.selected_match_index > 0 {
+        state.selected_match_index -= 1;
+        return;
+    }
+    let older = state.history_cursor.map_or(0, |i| i + 1);
+    let Some(team) = state.searches().get(older).cloned() else {
+        return;
+    };
+    if state.history_cursor.is_none() {
+        state.search_draft = std::mem::take(&mut state.search_input);
+    }
+    state.history_cursor = Some(older);
+    state.search_input = team;
+    state.suggestions.clear();
+}
+
+/// Down while typing: forward through the searches recalled, back to what
+/// was typed, or else down the suggestions or the results.
+fn recall_next(state: &mut AppState) {
+    match state.history_cursor {
+        Some(0) => {
+            state.history_cursor = None;
+            state.search_input = std::mem::take(&mut state.search_draft);
+            refresh_suggestions(state);
+        }
+        Some(i) => {
+            state.history_cursor = Some(i - 1);
+            state.search_input = state.searches().get(i - 1).cloned().unwrap_or_default();
+        }
+        None if !state.suggestions.is_empty() => {
+            let last = state.suggestions.len() - 1;
+            state.selected_suggestion = Some(state.selected_suggestion.map_or(0, |i| (i + 1).min(last)));
+        }
+        None if state.selected_match_index + 1 < state.matches.len() => {
+            state.selected_match_index += 1;
+        }
+        None => {}
     }
 }
+
+/// Matches what's typed against the country's searches, the favourites
+/// and the team index, starting a fresh pick.
+fn refresh_suggestions(state: &mut AppState) {
+    state.history_cursor = None;
+    state.selected_suggestion = None;
+    let favorites: Vec<&str> = state.config.favorites.iter().map(|f| f.team.as_str()).collect();
+    state.suggestions = suggest::suggest(&state.search_input, state.searches(), &favorites, &state.team_index);
+}
+
+/// Forgets the suggestions and any search recalled.
+pub fn close_suggestions(state: &mut AppState) {
+    state.suggestions.clear();
+    state.selected_suggestion = None;
+    state.history_cursor = None;
+    state.search_draft.clear();
+}
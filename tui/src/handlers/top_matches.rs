@@ -1,37 +1,33 @@
 use crate::app::Action;
-use crate::models::ViewMode;
+use crate::keymap::KeyAction;
+use crate::models::{Filterable, ViewMode};
 use crate::state::AppState;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use chrono::{Duration, Local};
 use foot_info_core::models::TopMatch;
 use std::collections::HashMap;
 
 /// Handles key events when the app is in TopMatches mode.
-pub fn handle(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
-    match key_event.code {
-        KeyCode::Esc => {
+pub fn handle(state: &mut AppState, bound: KeyAction) -> Option<Action> {
+    match bound {
+        KeyAction::Back => {
             state.view_mode = ViewMode::Search;
             state.status_message = None;
             state.error_message = None;
             None
         }
-        KeyCode::Char('t') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            state.view_mode = ViewMode::Search;
-            state.status_message = None;
-            None
-        }
-        KeyCode::Up => {
+        KeyAction::Up => {
             if state.selected_top_match_index > 0 {
                 state.selected_top_match_index -= 1;
             }
             None
         }
-        KeyCode::Down => {
+        KeyAction::Down => {
             if state.selected_top_match_index + 1 < state.top_matches.len() {
                 state.selected_top_match_index += 1;
             }
             None
         }
-        KeyCode::Left => {
+        KeyAction::Left => {
             let groups = date_groups(&state.top_matches);
             if let Some((col, row)) = flat_to_col_row(&groups, state.selected_top_match_index) {
                 if col > 0 {
@@ -41,7 +37,7 @@ pub fn handle(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
             }
             None
         }
-        KeyCode::Right => {
+        KeyAction::Right => {
             let groups = date_groups(&state.top_matches);
             if let Some((col, row)) = flat_to_col_row(&groups, state.selected_top_match_index) {
                 if col + 1 < groups.len() {
@@ -51,7 +47,32 @@ pub fn handle(state: &mut AppState, key_event: KeyEvent) -> Option<Action> {
             }
             None
         }
-        KeyCode::Enter => {
+        KeyAction::First => {
+            state.selected_top_match_index = 0;
+            None
+        }
+        KeyAction::Last => {
+            state.selected_top_match_index = state.top_matches.len().saturating_sub(1);
+            None
+        }
+        KeyAction::Previous => Some(change_day(state, -1)),
+        KeyAction::Next => Some(change_day(state, 1)),
+        KeyAction::FeaturedMatches if state.schedule_date.is_some() => Some(Action::FetchTopMatches),
+        KeyAction::CycleCompetition => {
+            state.top_match_competition =
+                next_competition(&state.all_top_matches, state.top_match_competition.as_deref());
+            apply_filters(state);
+            state.status_message = Some(match &state.top_match_competition {
+                Some(competition) => format!(
+                    "Competition: {} ({} matches)",
+                    competition,
+                    state.top_matches.len()
+                ),
+                None => "Competition: All".to_string(),
+            });
+            None
+        }
+        KeyAction::Submit => {
             if let Some(top_match) = state.top_matches.get(state.selected_top_match_index) {
                 let team = top_match
                     .teams
@@ -100,3 +121,68 @@ fn flat_to_col_row(groups: &[(String, Vec<usize>)], flat_idx: usize) -> Option<(
     }
     None
 }
+
+// ── Day navigation helpers ───────────────────────────────────────────────
+
+/// Steps the schedule a day back or forward. Coming from the featured top
+/// matches, the first step in either direction opens today's schedule.
+fn change_day(state: &AppState, days: i64) -> Action {
+    let date = match state.schedule_date {
+        Some(date) => date + Duration::days(days),
+        None => Local::now().date_naive(),
+    };
+    Action::FetchSchedule(date)
+}
+
+// ── Filter helpers ───────────────────────────────────────────────────────
+
+/// Rebuilds the visible top matches from the full list, the selected
+/// competition, the `/` filter and the order. A competition that's no
+/// longer listed falls back to all. Sorting is within each day, so the
+/// day columns stay put.
+pub fn apply_filters(state: &mut AppState) {
+    if let Some(competition) = &state.top_match_competition
+        && !state
+            .all_top_matches
+            .iter()
+            .any(|m| m.competition.as_ref() == Some(competition))
+    {
+        state.top_match_competition = None;
+    }
+
+    let mut matches: Vec<TopMatch> = state
+        .all_top_matches
+        .iter()
+        .filter(|m| match &state.top_match_competition {
+            Some(competition) => m.competition.as_ref() == Some(competition),
+            None => true,
+        })
+        .filter(|m| m.matches_filter(&state.top_match_filter))
+        .cloned()
+        .collect();
+    let day = |m: &TopMatch| state.all_top_matches.iter().position(|d| d.date == m.date);
+    matches.sort_by(|a, b| day(a).cmp(&day(b)).then(state.top_match_sort.compare(a, b)));
+    state.top_matches = matches;
+    state.selected_top_match_index = 0;
+}
+
+/// Competitions in order of first appearance.
+fn competitions(matches: &[TopMatch]) -> Vec<&str> {
+    let mut names: Vec<&str> = Vec::new();
+    for name in matches.iter().filter_map(|m| m.competition.as_deref()) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// All → first competition → … → last competition → All.
+fn next_competition(matches: &[TopMatch], current: Option<&str>) -> Option<String> {
+    let names = competitions(matches);
+    let next = match current.and_then(|c| names.iter().position(|n| *n == c)) {
+        Some(i) => names.get(i + 1),
+        None => names.first(),
+    };
+    next.map(|n| n.to_string())
+}
@@ -0,0 +1,825 @@
+use crate::models::ViewMode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Where a binding applies. Global bindings are looked up first, on every
+/// screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    Global,
+    Dashboard,
+    Search,
+    /// The Search view in vim normal mode; keys it leaves unbound fall
+    /// back to Search.
+    Normal,
+    TopMatches,
+    League,
+    /// The detail popup of a search result, over the Search view.
+    Detail,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 7] = [
+        Scope::Global,
+        Scope::Dashboard,
+        Scope::Search,
+        Scope::Normal,
+        Scope::TopMatches,
+        Scope::League,
+        Scope::Detail,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Scope::Global => "global",
+            Scope::Dashboard => "dashboard",
+            Scope::Search => "search",
+            Scope::Normal => "normal",
+            Scope::TopMatches => "top_matches",
+            Scope::League => "league",
+            Scope::Detail => "detail",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Scope::ALL.into_iter().find(|s| s.name() == name)
+    }
+
+    /// Scopes searched for a key, in order, global bindings excluded.
+    fn chain(self) -> &'static [Scope] {
+        match self {
+            Scope::Global => &[],
+            Scope::Dashboard => &[Scope::Dashboard],
+            Scope::Search => &[Scope::Search],
+            Scope::Normal => &[Scope::Normal, Scope::Search],
+            Scope::TopMatches => &[Scope::TopMatches],
+            Scope::League => &[Scope::League],
+            Scope::Detail => &[Scope::Detail],
+        }
+    }
+}
+
+impl From<ViewMode> for Scope {
+    fn from(mode: ViewMode) -> Self {
+        match mode {
+            ViewMode::Dashboard => Scope::Dashboard,
+            ViewMode::Search => Scope::Search,
+            ViewMode::TopMatches => Scope::TopMatches,
+            ViewMode::League => Scope::League,
+        }
+    }
+}
+
+/// Everything a key can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    Quit,
+    Back,
+    Help,
+    SwitchCountry,
+    ToggleZone,
+    /// Search the typed team, or the selected match's.
+    Submit,
+    TopMatches,
+    League,
+    /// Add the typed team to the favourites.
+    SaveFavorite,
+    Dashboard,
+    RemoveFavorite,
+    /// Move the selected favourite up the dashboard.
+    MoveUp,
+    MoveDown,
+    Export,
+    /// Open the selected search result's details.
+    Details,
+    /// Copy the match to the clipboard.
+    Copy,
+    /// Search the other team of the match.
+    SearchOpponent,
+    /// Take the highlighted suggestion into the search box.
+    Complete,
+    Up,
+    Down,
+    Left,
+    Right,
+    NextTab,
+    PrevTab,
+    /// Previous day of the schedule, or previous matchday.
+    Previous,
+    /// Next day of the schedule, or next matchday.
+    Next,
+    FeaturedMatches,
+    CycleCompetition,
+    /// Next order of the list on screen.
+    CycleSort,
+    /// Type a filter for the list on screen.
+    Filter,
+    Refresh,
+    /// Jump to the first row. Bound to a letter, it takes two presses (gg).
+    First,
+    Last,
+    /// Move a screenful down or up the list.
+    PageUp,
+    PageDown,
+    /// Go to the Search view and start typing.
+    FocusSearch,
+    /// Stop typing, so letters work as keys (vim normal mode).
+    NormalMode,
+    CommandPalette,
+}
+
+impl KeyAction {
+    const ALL: [KeyAction; 38] = [
+        KeyAction::Quit,
+        KeyAction::Back,
+        KeyAction::Help,
+        KeyAction::SwitchCountry,
+        KeyAction::ToggleZone,
+        KeyAction::Submit,
+        KeyAction::TopMatches,
+        KeyAction::League,
+        KeyAction::SaveFavorite,
+        KeyAction::Dashboard,
+        KeyAction::RemoveFavorite,
+        KeyAction::MoveUp,
+        KeyAction::MoveDown,
+        KeyAction::Export,
+        KeyAction::Details,
+        KeyAction::Copy,
+        KeyAction::SearchOpponent,
+        KeyAction::Complete,
+        KeyAction::Up,
+        KeyAction::Down,
+        KeyAction::Left,
+        KeyAction::Right,
+        KeyAction::NextTab,
+        KeyAction::PrevTab,
+        KeyAction::Previous,
+        KeyAction::Next,
+        KeyAction::FeaturedMatches,
+        KeyAction::CycleCompetition,
+        KeyAction::CycleSort,
+        KeyAction::Filter,
+        KeyAction::Refresh,
+        KeyAction::First,
+        KeyAction::Last,
+        KeyAction::PageUp,
+        KeyAction::PageDown,
+        KeyAction::FocusSearch,
+        KeyAction::NormalMode,
+        KeyAction::CommandPalette,
+    ];
+
+    /// Name used in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::Back => "back",
+            KeyAction::Help => "help",
+            KeyAction::SwitchCountry => "switch_country",
+            KeyAction::ToggleZone => "toggle_zone",
+            KeyAction::Submit => "submit",
+            KeyAction::TopMatches => "top_matches",
+            KeyAction::League => "league",
+            KeyAction::SaveFavorite => "save_favorite",
+            KeyAction::Dashboard => "dashboard",
+            KeyAction::RemoveFavorite => "remove_favorite",
+            KeyAction::MoveUp => "move_up",
+            KeyAction::MoveDown => "move_down",
+            KeyAction::Export => "export",
+            KeyAction::Details => "details",
+            KeyAction::Copy => "copy",
+            KeyAction::SearchOpponent => "search_opponent",
+            KeyAction::Complete => "complete",
+            KeyAction::Up => "up",
+            KeyAction::Down => "down",
+            KeyAction::Left => "left",
+            KeyAction::Right => "right",
+            KeyAction::NextTab => "next_tab",
+            KeyAction::PrevTab => "prev_tab",
+            KeyAction::Previous => "previous",
+            KeyAction::Next => "next",
+            KeyAction::FeaturedMatches => "featured_matches",
+            KeyAction::CycleCompetition => "cycle_competition",
+            KeyAction::CycleSort => "cycle_sort",
+            KeyAction::Filter => "filter",
+            KeyAction::Refresh => "refresh",
+            KeyAction::First => "first",
+            KeyAction::Last => "last",
+            KeyAction::PageUp => "page_up",
+            KeyAction::PageDown => "page_down",
+            KeyAction::FocusSearch => "focus_search",
+            KeyAction::NormalMode => "normal_mode",
+            KeyAction::CommandPalette => "command_palette",
+        }
+    }
+
+    /// Description shown in the help overlay.
+    pub fn description(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "Quit",
+            KeyAction::Back => "Back to search",
+            KeyAction::Help => "Show or hide this help",
+            KeyAction::SwitchCountry => "Switch country",
+            KeyAction::ToggleZone => "Toggle broadcaster's time zone",
+            KeyAction::Submit => "Search",
+            KeyAction::TopMatches => "Top matches",
+            KeyAction::League => "League stats",
+            KeyAction::SaveFavorite => "Add team to favorites",
+            KeyAction::Dashboard => "Favorites dashboard",
+            KeyAction::RemoveFavorite => "Remove favorite",
+            KeyAction::MoveUp => "Move favorite up",
+            KeyAction::MoveDown => "Move favorite down",
+            KeyAction::Export => "Export calendar",
+            KeyAction::Details => "Match details",
+            KeyAction::Copy => "Copy match",
+            KeyAction::SearchOpponent => "Search the opponent",
+            KeyAction::Complete => "Complete the suggested team",
+            KeyAction::Up => "Move up",
+            KeyAction::Down => "Move down",
+            KeyAction::Left => "Move left",
+            KeyAction::Right => "Move right",
+            KeyAction::NextTab => "Next tab",
+            KeyAction::PrevTab => "Previous tab",
+            KeyAction::Previous => "Previous day / matchday",
+            KeyAction::Next => "Next day / matchday",
+            KeyAction::FeaturedMatches => "Featured top matches",
+            KeyAction::CycleCompetition => "Filter by competition",
+            KeyAction::CycleSort => "Change the sort",
+            KeyAction::Filter => "Filter the list",
+            KeyAction::Refresh => "Refresh",
+            KeyAction::First => "Jump to the first row",
+            KeyAction::Last => "Jump to the last row",
+            KeyAction::PageUp => "Page up",
+            KeyAction::PageDown => "Page down",
+            KeyAction::FocusSearch => "Type a search",
+            KeyAction::NormalMode => "Stop typing (normal mode)",
+            KeyAction::CommandPalette => "Command palette",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        KeyAction::ALL.into_iter().find(|a| a.name() == name)
+    }
+}
+
+/// Built-in bindings, per scope. The Search scope avoids plain characters,
+/// which are typed into the search box.
+const DEFAULT_BINDINGS: &[(Scope, KeyAction, &[&str])] = &[
+    (Scope::Global, KeyAction::Quit, &["ctrl+c"]),
+    (Scope::Global, KeyAction::SwitchCountry, &["ctrl+n"]),
+    (Scope::Global, KeyAction::ToggleZone, &["ctrl+z"]),
+    (Scope::Global, KeyAction::Help, &["f1"]),
+    (Scope::Global, KeyAction::CommandPalette, &["ctrl+p"]),
+    (Scope::Dashboard, KeyAction::Back, &["esc", "ctrl+f"]),
+    (Scope::Dashboard, KeyAction::Submit, &["enter"]),
+    (Scope::Dashboard, KeyAction::Up, &["up"]),
+    (Scope::Dashboard, KeyAction::Down, &["down"]),
+    (Scope::Dashboard, KeyAction::PageUp, &["pageup"]),
+    (Scope::Dashboard, KeyAction::PageDown, &["pagedown"]),
+    (Scope::Dashboard, KeyAction::First, &["home"]),
+    (Scope::Dashboard, KeyAction::Last, &["end"]),
+    (Scope::Dashboard, KeyAction::MoveUp, &["shift+up"]),
+    (Scope::Dashboard, KeyAction::MoveDown, &["shift+down"]),
+    (Scope::Dashboard, KeyAction::RemoveFavorite, &["delete", "x"]),
+    (Scope::Dashboard, KeyAction::Refresh, &["r"]),
+    (Scope::Dashboard, KeyAction::TopMatches, &["ctrl+t"]),
+    (Scope::Dashboard, KeyAction::League, &["ctrl+l"]),
+    (Scope::Dashboard, KeyAction::FocusSearch, &["/"]),
+    (Scope::Dashboard, KeyAction::Help, &["?"]),
+    (Scope::Search, KeyAction::Quit, &["esc"]),
+    (Scope::Search, KeyAction::Submit, &["enter"]),
+    (Scope::Search, KeyAction::Up, &["up"]),
+    (Scope::Search, KeyAction::Down, &["down"]),
+    (Scope::Search, KeyAction::PageUp, &["pageup"]),
+    (Scope::Search, KeyAction::PageDown, &["pagedown"]),
+    (Scope::Search, KeyAction::First, &["home"]),
+    (Scope::Search, KeyAction::Last, &["end"]),
+    (Scope::Search, KeyAction::Complete, &["tab"]),
+    (Scope::Search, KeyAction::Details, &["ctrl+d"]),
+    (Scope::Search, KeyAction::SaveFavorite, &["ctrl+s"]),
+    (Scope::Search, KeyAction::Dashboard, &["ctrl+f"]),
+    (Scope::Search, KeyAction::TopMatches, &["ctrl+t"]),
+    (Scope::Search, KeyAction::League, &["ctrl+l"]),
+    (Scope::Search, KeyAction::Export, &["ctrl+e"]),
+    (Scope::Search, KeyAction::Filter, &["ctrl+r"]),
+    (Scope::Search, KeyAction::CycleSort, &["ctrl+g"]),
+    (Scope::Detail, KeyAction::Back, &["esc", "ctrl+d"]),
+    (Scope::Detail, KeyAction::Export, &["e", "ctrl+e"]),
+    (Scope::Detail, KeyAction::Copy, &["c"]),
+    (Scope::Detail, KeyAction::SearchOpponent, &["o"]),
+    (Scope::Detail, KeyAction::Help, &["?"]),
+    (Scope::TopMatches, KeyAction::Back, &["esc", "ctrl+t"]),
+    (Scope::TopMatches, KeyAction::Submit, &["enter"]),
+    (Scope::TopMatches, KeyAction::Up, &["up"]),
+    (Scope::TopMatches, KeyAction::Down, &["down"]),
+    (Scope::TopMatches, KeyAction::PageUp, &["pageup"]),
+    (Scope::TopMatches, KeyAction::PageDown, &["pagedown"]),
+    (Scope::TopMatches, KeyAction::First, &["home"]),
+    (Scope::TopMatches, KeyAction::Last, &["end"]),
+    (Scope::TopMatches, KeyAction::Left, &["left"]),
+    (Scope::TopMatches, KeyAction::Right, &["right"]),
+    (Scope::TopMatches, KeyAction::CycleCompetition, &["c"]),
+    (Scope::TopMatches, KeyAction::Previous, &["["]),
+    (Scope::TopMatches, KeyAction::Next, &["]"]),
+    (Scope::TopMatches, KeyAction::FeaturedMatches, &["f"]),
+    (Scope::TopMatches, KeyAction::Filter, &["/"]),
+    (Scope::TopMatches, KeyAction::CycleSort, &["s"]),
+    (Scope::TopMatches, KeyAction::Help, &["?"]),
+    (Scope::League, KeyAction::Back, &["esc", "ctrl+l"]),
+    (Scope::League, KeyAction::Up, &["up"]),
+    (Scope::League, KeyAction::Down, &["down"]),
+    (Scope::League, KeyAction::PageUp, &["pageup"]),
+    (Scope::League, KeyAction::PageDown, &["pagedown"]),
+    (Scope::League, KeyAction::First, &["home"]),
+    (Scope::League, KeyAction::Last, &["end"]),
+    (Scope::League, KeyAction::NextTab, &["tab"]),
+    (Scope::League, KeyAction::PrevTab, &["shift+tab"]),
+    (Scope::League, KeyAction::Previous, &["["]),
+    (Scope::League, KeyAction::Next, &["]"]),
+    (Scope::League, KeyAction::CycleSort, &["s"]),
+    (Scope::League, KeyAction::Filter, &["/"]),
+    (Scope::League, KeyAction::Refresh, &["r"]),
+    (Scope::League, KeyAction::Submit, &["enter"]),
+    (Scope::League, KeyAction::Export, &["ctrl+e"]),
+    (Scope::League, KeyAction::Help, &["?"]),
+];
+
+/// Layered over the defaults when vim keys are on. Each entry replaces the
+/// action's keys in its scope; Esc stops typing instead of quitting.
+const VIM_BINDINGS: &[(Scope, KeyAction, &[&str])] = &[
+    (Scope::Dashboard, KeyAction::Up, &["k", "up"]),
+    (Scope::Dashboard, KeyAction::Down, &["j", "down"]),
+    (Scope::Dashboard, KeyAction::MoveUp, &["K", "shift+up"]),
+    (Scope::Dashboard, KeyAction::MoveDown, &["J", "shift+down"]),
+    (Scope::Dashboard, KeyAction::First, &["g", "home"]),
+    (Scope::Dashboard, KeyAction::Last, &["G", "end"]),
+    (Scope::Dashboard, KeyAction::Quit, &["q"]),
+    (Scope::Search, KeyAction::Quit, &[]),
+    (Scope::Search, KeyAction::NormalMode, &["esc"]),
+    (Scope::Normal, KeyAction::Quit, &["q"]),
+    (Scope::Normal, KeyAction::NormalMode, &[]),
+    (Scope::Normal, KeyAction::FocusSearch, &["/", "i"]),
+    (Scope::Normal, KeyAction::Filter, &["F", "ctrl+r"]),
+    (Scope::Normal, KeyAction::CycleSort, &["s"]),
+    (Scope::Normal, KeyAction::Up, &["k", "up"]),
+    (Scope::Normal, KeyAction::Down, &["j", "down"]),
+    (Scope::Normal, KeyAction::First, &["g"]),
+    (Scope::Normal, KeyAction::Last, &["G"]),
+    (Scope::Normal, KeyAction::TopMatches, &["t", "ctrl+t"]),
+    (Scope::Normal, KeyAction::League, &["L", "ctrl+l"]),
+    (Scope::Normal, KeyAction::Help, &["?"]),
+    (Scope::Normal, KeyAction::Details, &["enter", "o"]),
+    (Scope::Detail, KeyAction::Back, &["esc", "q", "ctrl+d"]),
+    (Scope::Detail, KeyAction::Copy, &["c", "y"]),
+    (Scope::TopMatches, KeyAction::Up, &["k", "up"]),
+    (Scope::TopMatches, KeyAction::Down, &["j", "down"]),
+    (Scope::TopMatches, KeyAction::Left, &["h", "left"]),
+    (Scope::TopMatches, KeyAction::Right, &["l", "right"]),
+    (Scope::TopMatches, KeyAction::First, &["g", "home"]),
+    (Scope::TopMatches, KeyAction::Last, &["G", "end"]),
+    (Scope::TopMatches, KeyAction::FocusSearch, &["/", "i"]),
+    (Scope::TopMatches, KeyAction::Filter, &["F"]),
+    (Scope::League, KeyAction::Up, &["k", "up"]),
+    (Scope::League, KeyAction::Down, &["j", "down"]),
+    (Scope::League, KeyAction::PrevTab, &["h", "shift+tab"]),
+    (Scope::League, KeyAction::NextTab, &["l", "tab"]),
+    (Scope::League, KeyAction::First, &["g", "home"]),
+    (Scope::League, KeyAction::Last, &["G", "end"]),
+    (Scope::League, KeyAction::FocusSearch, &["/", "i"]),
+    (Scope::League, KeyAction::Filter, &["F"]),
+];
+
+/// Entries of the instruction bar: a label and the actions whose first
+/// keys are shown together, e.g. "Navigate <↑/↓>".
+const HINTS: &[(Scope, &str, &[KeyAction])] = &[
+    (Scope::Dashboard, "Search", &[KeyAction::Back]),
+    (Scope::Dashboard, "Open", &[KeyAction::Submit]),
+    (Scope::Dashboard, "Navigate", &[KeyAction::Up, KeyAction::Down]),
+    (Scope::Dashboard, "Reorder", &[KeyAction::MoveUp, KeyAction::MoveDown]),
+    (Scope::Dashboard, "Remove", &[KeyAction::RemoveFavorite]),
+    (Scope::Dashboard, "Refresh", &[KeyAction::Refresh]),
+    (Scope::Dashboard, "Commands", &[KeyAction::CommandPalette]),
+    (Scope::Dashboard, "Help", &[KeyAction::Help]),
+    (Scope::Search, "Quit", &[KeyAction::Quit]),
+    (Scope::Search, "Normal", &[KeyAction::NormalMode]),
+    (Scope::Search, "Search", &[KeyAction::Submit]),
+    (Scope::Search, "Details", &[KeyAction::Details]),
+    (Scope::Search, "Commands", &[KeyAction::CommandPalette]),
+    (Scope::Search, "Country", &[KeyAction::SwitchCountry]),
+    (Scope::Search, "Top Matches", &[KeyAction::TopMatches]),
+    (Scope::Search, "Help", &[KeyAction::Help]),
+    (Scope::Normal, "Quit", &[KeyAction::Quit]),
+    (Scope::Normal, "Type", &[KeyAction::FocusSearch]),
+    (Scope::Normal, "Move", &[KeyAction::Down, KeyAction::Up]),
+    (Scope::Normal, "First/Last", &[KeyAction::First, KeyAction::Last]),
+    (Scope::Normal, "Details", &[KeyAction::Details]),
+    (Scope::Normal, "Top Matches", &[KeyAction::TopMatches]),
+    (Scope::Normal, "League", &[KeyAction::League]),
+    (Scope::Normal, "Help", &[KeyAction::Help]),
+    (Scope::TopMatches, "Back", &[KeyAction::Back]),
+    (Scope::TopMatches, "Select Match", &[KeyAction::Submit]),
+    (
+        Scope::TopMatches,
+        "Navigate",
+        &[KeyAction::Up, KeyAction::Down, KeyAction::Left, KeyAction::Right],
+    ),
+    (Scope::TopMatches, "Competition", &[KeyAction::CycleCompetition]),
+    (Scope::TopMatches, "Filter", &[KeyAction::Filter]),
+    (Scope::TopMatches, "Day", &[KeyAction::Previous, KeyAction::Next]),
+    (Scope::TopMatches, "Help", &[KeyAction::Help]),
+    (Scope::League, "Back", &[KeyAction::Back]),
+    (Scope::League, "Navigate", &[KeyAction::Up, KeyAction::Down]),
+    (Scope::League, "Tabs", &[KeyAction::NextTab, KeyAction::PrevTab]),
+    (Scope::League, "Round", &[KeyAction::Previous, KeyAction::Next]),
+    (Scope::League, "Filter", &[KeyAction::Filter]),
+    (Scope::League, "Sort", &[KeyAction::CycleSort]),
+    (Scope::League, "Refresh", &[KeyAction::Refresh]),
+    (Scope::League, "Search fixture", &[KeyAction::Submit]),
+    (Scope::League, "Help", &[KeyAction::Help]),
+    (Scope::Detail, "Close", &[KeyAction::Back]),
+    (Scope::Detail, "Export", &[KeyAction::Export]),
+    (Scope::Detail, "Copy", &[KeyAction::Copy]),
+    (Scope::Detail, "Search opponent", &[KeyAction::SearchOpponent]),
+    (Scope::Detail, "Help", &[KeyAction::Help]),
+];
+
+/// A key with its modifiers, e.g. Ctrl+c.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // Shift is implied by the character itself ('?', 'G') and by BackTab
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers.difference(KeyModifiers::SHIFT),
+            _ => modifiers,
+        };
+        Self {
+            code,
+            modifiers: modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT),
+        }
+    }
+
+    /// Parses "ctrl+c", "shift+tab", "f1", "esc", "[" and the like.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (mods, key) = match text.rsplit_once('+') {
+            Some((mods, "")) => (mods.strip_suffix('+').unwrap_or(mods), "+"),
+            Some((mods, key)) => (mods, key),
+            None => ("", text),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in mods.split('+').filter(|p| !p.is_empty()) {
+            modifiers |= match part.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+
+        let lower = key.to_lowercase();
+        let code = match lower.as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            _ if lower.len() > 1 && lower.starts_with('f') => KeyCode::F(lower[1..].parse().ok()?),
+            _ => {
+                let mut chars = key.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                // Shift+g is typed as G, which is what arrives; Ctrl+C
+                // arrives as Ctrl+c
+                KeyCode::Char(if modifiers.contains(KeyModifiers::SHIFT) {
+                    c.to_uppercase().next().unwrap_or(c)
+                } else if modifiers.contains(KeyModifiers::CONTROL) {
+                    c.to_ascii_lowercase()
+                } else {
+                    c
+                })
+            }
+        };
+        Some(Self::new(code, modifiers))
+    }
+
+    fn matches(&self, event: &KeyEvent) -> bool {
+        *self == KeyBinding::new(event.code, event.modifiers)
+    }
+
+    /// A character typed without Ctrl or Alt, e.g. "g" or "?".
+    pub fn is_plain_char(&self) -> bool {
+        matches!(self.code, KeyCode::Char(_)) && !self.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+
+    /// How the key is shown for `action`: letters bound to First are
+    /// pressed twice, so "g" reads "gg".
+    fn label(&self, action: KeyAction) -> String {
+        if action == KeyAction::First && self.is_plain_char() {
+            format!("{0}{0}", self)
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            _ => write!(f, "?"),
+        }
+    }
+}
+
+/// The active bindings: defaults with the config's overrides applied.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(Scope, KeyAction, Vec<KeyBinding>)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = DEFAULT_BINDINGS
+            .iter()
+            .map(|(scope, action, keys)| (*scope, *action, parse_keys(keys)))
+            .collect();
+        Self { bindings }
+    }
+}
+
+fn parse_keys(keys: &[&str]) -> Vec<KeyBinding> {
+    keys.iter().filter_map(|k| KeyBinding::parse(k)).collect()
+}
+
+impl Keymap {
+    /// The defaults with vim keys layered on: j/k/h/l, gg/G, and a normal
+    /// mode in the Search view where letters are keys rather than text.
+    pub fn vim() -> Self {
+        let mut keymap = Keymap::default();
+        for (scope, action, keys) in VIM_BINDINGS {
+            keymap.set(*scope, *action, parse_keys(keys));
+        }
+        keymap
+    }
+
+    /// The default keymap with `overrides` applied; see [`Keymap::overridden`].
+    pub fn with_overrides(overrides: &HashMap<String, Vec<String>>) -> (Self, Vec<String>) {
+        Keymap::default().overridden(overrides)
+    }
+
+    /// Applies overrides such as `"switch_country": ["ctrl+o"]`, which
+    /// rebinds the action wherever it is bound, or `"league.refresh": ["f5"]`
+    /// for one scope only. An action with no default in the scope is added
+    /// to it; one bound nowhere needs a scope. Returns the keymap and
+    /// whatever was wrong with the overrides; an override whose keys collide
+    /// with another binding is not applied.
+    pub fn overridden(self, overrides: &HashMap<String, Vec<String>>) -> (Self, Vec<String>) {
+        let mut keymap = self;
+        let mut problems = Vec::new();
+
+        // Sorted, so problems come out in a stable order
+        let mut entries: Vec<_> = overrides.iter().collect();
+        entries.sort_by_key(|(name, _)| name.as_str());
+
+        for (name, keys) in entries {
+            let (scope, action_name) = match name.split_once('.') {
+                Some((scope, action)) => match Scope::parse(scope) {
+                    Some(scope) => (Some(scope), action),
+                    None => {
+                        problems.push(format!("Unknown key scope '{}'", scope));
+                        continue;
+                    }
+                },
+                None => (None, name.as_str()),
+            };
+            let Some(action) = KeyAction::parse(action_name) else {
+                problems.push(format!("Unknown key action '{}'", action_name));
+                continue;
+            };
+
+            let mut parsed = Vec::new();
+            for key in keys {
+                match KeyBinding::parse(key) {
+                    Some(binding) => parsed.push(binding),
+                    None => problems.push(format!("Unknown key '{}' for {}", key, name)),
+                }
+            }
+
+            let scopes: Vec<Scope> = match scope {
+                Some(scope) => vec![scope],
+                None => keymap.scopes_of(action),
+            };
+            if scopes.is_empty() {
+                problems.push(format!(
+                    "Key action '{}' isn't bound anywhere to rebind; name a view, as in 'search.{}'",
+                    action_name, action_name
+                ));
+            }
+            for scope in scopes {
+                let previous = keymap.set(scope, action, parsed.clone());
+                let conflicts = keymap.conflicts();
+                if !conflicts.is_empty() {
+                    problems.extend(conflicts);
+                    keymap.set(scope, action, previous);
+                }
+            }
+        }
+
+        (keymap, problems)
+    }
+
+    fn scopes_of(&self, action: KeyAction) -> Vec<Scope> {
+        self.bindings
+            .iter()
+            .filter(|(_, a, _)| *a == action)
+            .map(|(scope, _, _)| *scope)
+            .collect()
+    }
+
+    /// Replaces the keys of `action` in `scope`, returning the old ones.
+    fn set(&mut self, scope: Scope, action: KeyAction, keys: Vec<KeyBinding>) -> Vec<KeyBinding> {
+        match self.bindings.iter_mut().find(|(s, a, _)| *s == scope && *a == action) {
+            Some((_, _, existing)) => std::mem::replace(existing, keys),
+            None => {
+                self.bindings.push((scope, action, keys));
+                Vec::new()
+            }
+        }
+    }
+
+    /// Keys bound to two actions at once: twice within a scope, or in a
+    /// view and globally. Global bindings of plain characters would also
+    /// swallow typing in the search box.
+    pub fn conflicts(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for scope in [
+            Scope::Dashboard,
+            Scope::Search,
+            Scope::Normal,
+            Scope::TopMatches,
+            Scope::League,
+            Scope::Detail,
+        ] {
+            let mut seen: Vec<(KeyBinding, KeyAction)> = Vec::new();
+            for (s, action, keys) in &self.bindings {
+                if *s != Scope::Global && *s != scope {
+                    continue;
+                }
+                for key in keys {
+                    match seen.iter().find(|(k, _)| k == key) {
+                        Some((_, other)) if other != action => problems.push(format!(
+                            "Key {} is bound to both {} and {} in {}",
+                            key,
+                            other.name(),
+                            action.name(),
+                            scope.name()
+                        )),
+                        Some(_) => {}
+                        None => seen.push((*key, *action)),
+                    }
+                }
+            }
+        }
+
+        for key in self.keys(Scope::Global, KeyAction::ALL.iter().copied()) {
+            let modified = key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+            if matches!(key.code, KeyCode::Char(_)) && !modified {
+                problems.push(format!("Global key {} would block typing a search", key));
+            }
+        }
+        problems.sort();
+        problems.dedup();
+        problems
+    }
+
+    fn keys(&self, scope: Scope, actions: impl Iterator<Item = KeyAction> + Clone) -> Vec<KeyBinding> {
+        self.bindings
+            .iter()
+            .filter(|(s, a, _)| *s == scope && actions.clone().any(|action| action == *a))
+            .flat_map(|(_, _, keys)| keys.iter().copied())
+            .collect()
+    }
+
+    /// The action `event` is bound to in `scope`, global bindings excluded.
+    pub fn action(&self, scope: Scope, event: &KeyEvent) -> Option<KeyAction> {
+        self.bindings
+            .iter()
+            .find(|(s, _, keys)| *s == scope && keys.iter().any(|k| k.matches(event)))
+            .map(|(_, action, _)| *action)
+    }
+
+    /// The action `event` triggers in a view: global bindings first, then
+    /// the view's own (normal mode falling back to Search).
+    pub fn lookup(&self, scope: Scope, event: &KeyEvent) -> Option<KeyAction> {
+        std::iter::once(Scope::Global)
+            .chain(scope.chain().iter().copied())
+            .find_map(|s| self.action(s, event))
+    }
+
+    /// The entry for `action` that applies in `scope`: the first scope of
+    /// the chain binding it, even to nothing, then the global one.
+    fn entry(&self, scope: Scope, action: KeyAction) -> Option<&(Scope, KeyAction, Vec<KeyBinding>)> {
+        scope
+            .chain()
+            .iter()
+            .chain(std::iter::once(&Scope::Global))
+            .find_map(|s| self.bindings.iter().find(|(bs, a, _)| bs == s && *a == action))
+    }
+
+    /// Keys of `action` in `scope`, falling back to its global keys.
+    pub fn keys_for(&self, scope: Scope, action: KeyAction) -> Vec<KeyBinding> {
+        self.entry(scope, action).map(|(_, _, keys)| keys.clone()).unwrap_or_default()
+    }
+
+    /// Instruction bar entries of a view, as (label, "Esc", is_exit).
+    /// Actions left without a key are left out.
+    pub fn hints(&self, scope: Scope) -> Vec<(&'static str, String, bool)> {
+        HINTS
+            .iter()
+            .filter(|(s, _, _)| *s == scope)
+            .filter_map(|(_, label, actions)| {
+                let keys: Vec<String> = actions
+                    .iter()
+                    .filter_map(|a| self.keys_for(scope, *a).first().map(|k| k.label(*a)))
+                    .collect();
+                let exit = actions.iter().any(|a| matches!(a, KeyAction::Quit | KeyAction::Back));
+                (!keys.is_empty()).then(|| (*label, keys.join("/"), exit))
+            })
+            .collect()
+    }
+
+    /// Every binding that works in a view, as (keys, description): the
+    /// view's own first, then the global ones.
+    pub fn help(&self, scope: Scope) -> Vec<(String, &'static str)> {
+        scope
+            .chain()
+            .iter()
+            .chain(std::iter::once(&Scope::Global))
+            .flat_map(|s| self.bindings.iter().filter(move |(bs, _, _)| bs == s))
+            // Normal mode shadows Search bindings of the same action
+            .filter(|(s, action, _)| {
+                *s == Scope::Global || self.entry(scope, *action).is_some_and(|(es, _, _)| es == s)
+            })
+            .filter(|(_, _, keys)| !keys.is_empty())
+            .map(|(s, action, keys)| {
+                let keys: Vec<String> = keys.iter().map(|k| k.label(*action)).collect();
+                (keys.join(", "), help_text(*s, *action))
+            })
+            .collect()
+    }
+}
+
+/// What the help says an action does. Over search results the filter isn't
+/// on `/`, since the search box takes every character typed.
+fn help_text(scope: Scope, action: KeyAction) -> &'static str {
+    match (scope, action) {
+        (Scope::Search, KeyAction::Filter) => "Filter the results (/ types into the search box here)",
+        _ => action.description(),
+    }
+}
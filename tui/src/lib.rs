@@ -1,6 +1,12 @@
 pub mod app;
+pub mod cli;
 pub mod config;
 pub mod handlers;
+pub mod keymap;
 pub mod models;
+pub mod palette;
+pub mod scroll;
+pub mod server;
 pub mod state;
+pub mod suggest;
 pub mod ui;
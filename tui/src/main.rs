@@ -1,10 +1,33 @@
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::execute;
 use std::io;
+use std::panic;
+use std::process::ExitCode;
+use clap::Parser;
 use foot_info_tui::app::App;
+use foot_info_tui::cli::{self, Cli};
 
 #[tokio::main]
-async fn main() -> io::Result<()> {
+async fn main() -> io::Result<ExitCode> {
+    let args = Cli::parse();
+    if let Some(command) = args.command {
+        return Ok(cli::run(command, args.format).await);
+    }
+
     let mut terminal = ratatui::init();
+    // ratatui's hook restores the terminal on a panic; mouse capture has to
+    // be released too, or the shell is left reading mouse reports
+    let restore_terminal = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = execute!(io::stdout(), DisableMouseCapture);
+        restore_terminal(info);
+    }));
+    if let Err(e) = execute!(io::stdout(), EnableMouseCapture) {
+        ratatui::restore();
+        return Err(e);
+    }
     let app_result = App::new().run(&mut terminal).await;
+    let _ = execute!(io::stdout(), DisableMouseCapture);
     ratatui::restore();
-    app_result
-}
\ No newline at end of file
+    app_result.map(|_| ExitCode::SUCCESS)
+}
@@ -1,14 +1,45 @@
+use clap::ValueEnum;
+use foot_info_core::models::{Country, LeagueFixture, Match, StandingRow, TopMatch, TopScorer};
+use std::cmp::{Ordering, Reverse};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ViewMode {
+    /// Next match of every favourite; the startup screen once there are any.
+    Dashboard,
     Search,
     TopMatches,
     League,
 }
 
+/// Whether letters in the Search view are typed (Insert) or act as keys
+/// (Normal). Normal is only reachable with vim keys on.
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputMode {
+    Insert,
+    Normal,
+}
+
+/// What the dashboard knows of one favourite.
+#[derive(Debug, Clone)]
+pub enum FavoriteStatus {
+    Loading,
+    Matches(Vec<Match>),
+    Error(String),
+}
+
+/// Where search results were listed: kept with them, so their details
+/// still name the right page and time zone after switching provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultsSource {
+    pub country: Country,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum LeagueTab {
     Fixtures,
     Table,
+    #[value(alias = "scorers")]
     TopScorers,
 }
 
@@ -37,3 +68,269 @@ impl LeagueTab {
         }
     }
 }
+
+/// Column the Top Scorers tab is ordered by (always descending, best first).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScorerSort {
+    Goals,
+    NonPenaltyGoals,
+    Penalties,
+    Assists,
+    Contributions,
+    Appearances,
+    GoalsPerGame,
+    MinutesPerGoal,
+}
+
+impl ScorerSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Goals => Self::NonPenaltyGoals,
+            Self::NonPenaltyGoals => Self::Penalties,
+            Self::Penalties => Self::Assists,
+            Self::Assists => Self::Contributions,
+            Self::Contributions => Self::Appearances,
+            Self::Appearances => Self::GoalsPerGame,
+            Self::GoalsPerGame => Self::MinutesPerGoal,
+            Self::MinutesPerGoal => Self::Goals,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Goals => "Goals",
+            Self::NonPenaltyGoals => "Non-penalty goals",
+            Self::Penalties => "Penalties",
+            Self::Assists => "Assists",
+            Self::Contributions => "Goals + assists",
+            Self::Appearances => "Appearances",
+            Self::GoalsPerGame => "Goals per game",
+            Self::MinutesPerGoal => "Minutes per goal",
+        }
+    }
+
+    /// Returns the scorers ordered by this column. Players missing the
+    /// metric go last; ties keep the page's own order.
+    pub fn sorted<'a>(self, scorers: impl IntoIterator<Item = &'a TopScorer>) -> Vec<&'a TopScorer> {
+        let key = |s: &TopScorer| -> Option<f64> {
+            match self {
+                Self::Goals => Some(s.goals as f64),
+                Self::NonPenaltyGoals => Some(s.non_penalty_goals() as f64),
+                Self::Penalties => Some(s.penalties as f64),
+                Self::Assists => s.assists.map(f64::from),
+                Self::Contributions => s.goal_contributions().map(f64::from),
+                Self::Appearances => s.appearances.map(f64::from),
+                Self::GoalsPerGame => s.goals_per_game(),
+                // Fewer minutes per goal is better
+                Self::MinutesPerGoal => s.minutes_per_goal().map(|m| -m),
+            }
+        };
+
+        let mut sorted: Vec<&TopScorer> = scorers.into_iter().collect();
+        sorted.sort_by(|a, b| match (key(a), key(b)) {
+            (Some(x), Some(y)) => y.total_cmp(&x),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        sorted
+    }
+}
+
+/// A list that `/` filters, one per view (the League view's per tab).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListKind {
+    Results,
+    TopMatches,
+    Fixtures,
+    Table,
+    Scorers,
+}
+
+/// Whether every word of `filter` appears, ignoring case, in one of
+/// `fields`. An empty filter keeps everything.
+pub fn filter_matches(filter: &str, fields: &[&str]) -> bool {
+    let fields: Vec<String> = fields.iter().map(|f| f.to_lowercase()).collect();
+    filter
+        .to_lowercase()
+        .split_whitespace()
+        .all(|word| fields.iter().any(|f| f.contains(word)))
+}
+
+/// A match as the search results and the top matches list it, for
+/// filtering and sorting them alike.
+pub trait Filterable {
+    fn teams(&self) -> &str;
+    /// Empty when the listing doesn't say.
+    fn competition(&self) -> &str;
+    fn channels(&self) -> &[String];
+
+    /// Whether `filter` is found in the teams, competition or channels.
+    fn matches_filter(&self, filter: &str) -> bool {
+        let mut fields = vec![self.teams(), self.competition()];
+        fields.extend(self.channels().iter().map(String::as_str));
+        filter_matches(filter, &fields)
+    }
+}
+
+impl Filterable for Match {
+    fn teams(&self) -> &str {
+        &self.teams
+    }
+
+    fn competition(&self) -> &str {
+        &self.competition
+    }
+
+    fn channels(&self) -> &[String] {
+        &self.channels
+    }
+}
+
+impl Filterable for TopMatch {
+    fn teams(&self) -> &str {
+        &self.teams
+    }
+
+    fn competition(&self) -> &str {
+        self.competition.as_deref().unwrap_or_default()
+    }
+
+    fn channels(&self) -> &[String] {
+        &self.channels
+    }
+}
+
+/// Order of the search results and the top matches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchSort {
+    /// The site's own order, by kickoff.
+    Kickoff,
+    Competition,
+    Teams,
+    /// By first channel, so each broadcaster's matches sit together.
+    Channel,
+}
+
+impl MatchSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Kickoff => Self::Competition,
+            Self::Competition => Self::Teams,
+            Self::Teams => Self::Channel,
+            Self::Channel => Self::Kickoff,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Kickoff => "Kickoff",
+            Self::Competition => "Competition",
+            Self::Teams => "Teams",
+            Self::Channel => "Channel",
+        }
+    }
+
+    /// Orders two listings, alphabetically ignoring case. Listings without
+    /// a competition or channel go last; ties (and `Kickoff`) are equal, so
+    /// a stable sort keeps the site's order.
+    pub fn compare<T: Filterable>(self, a: &T, b: &T) -> Ordering {
+        let key = |m: &T| -> Option<String> {
+            let text = match self {
+                Self::Kickoff => return None,
+                Self::Competition => m.competition(),
+                Self::Teams => m.teams(),
+                Self::Channel => m.channels().first().map_or("", String::as_str),
+            };
+            (!text.is_empty()).then(|| text.to_lowercase())
+        };
+        match (key(a), key(b)) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// Order of the League view's fixtures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FixtureSort {
+    /// Page order, grouped by matchday.
+    Date,
+    /// Alphabetically by home team.
+    Team,
+}
+
+impl FixtureSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Date => Self::Team,
+            Self::Team => Self::Date,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Date => "Date",
+            Self::Team => "Team",
+        }
+    }
+
+    pub fn sorted<'a>(self, fixtures: impl IntoIterator<Item = &'a LeagueFixture>) -> Vec<&'a LeagueFixture> {
+        let mut sorted: Vec<&LeagueFixture> = fixtures.into_iter().collect();
+        if self == Self::Team {
+            sorted.sort_by_cached_key(|f| f.home_team.to_lowercase());
+        }
+        sorted
+    }
+}
+
+/// Column the league table is ordered by (best first).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TableSort {
+    Position,
+    GoalDifference,
+    /// Points taken from the recent results listed.
+    Form,
+}
+
+impl TableSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Position => Self::GoalDifference,
+            Self::GoalDifference => Self::Form,
+            Self::Form => Self::Position,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Position => "Position",
+            Self::GoalDifference => "Goal difference",
+            Self::Form => "Form",
+        }
+    }
+
+    /// Returns the rows ordered by this column; ties keep table order.
+    pub fn sorted<'a>(self, rows: impl IntoIterator<Item = &'a StandingRow>) -> Vec<&'a StandingRow> {
+        let mut sorted: Vec<&StandingRow> = rows.into_iter().collect();
+        match self {
+            Self::Position => {}
+            Self::GoalDifference => sorted.sort_by_key(|r| Reverse(r.goal_diff)),
+            Self::Form => sorted.sort_by_key(|r| Reverse(form_points(&r.form))),
+        }
+        sorted
+    }
+}
+
+/// Points from a form guide such as W W D L W: three a win, one a draw.
+pub fn form_points(form: &[char]) -> u16 {
+    form.iter()
+        .map(|result| match result.to_ascii_uppercase() {
+            'W' => 3,
+            'D' => 1,
+            _ => 0,
+        })
+        .sum()
+}
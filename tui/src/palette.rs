@@ -0,0 +1,215 @@
+/// Commands offered by the Ctrl+P palette. Each is typed as its keyword,
+/// optionally followed by arguments, e.g. "league serie a".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Search,
+    League,
+    TopMatches,
+    Country,
+    Favorites,
+    AddFavorite,
+    RemoveFavorite,
+    MoveFavorite,
+    Export,
+    Refresh,
+    Timezone,
+    Theme,
+    Help,
+    Quit,
+}
+
+impl Command {
+    pub const ALL: [Command; 14] = [
+        Command::Search,
+        Command::League,
+        Command::TopMatches,
+        Command::Country,
+        Command::Favorites,
+        Command::AddFavorite,
+        Command::RemoveFavorite,
+        Command::MoveFavorite,
+        Command::Export,
+        Command::Refresh,
+        Command::Timezone,
+        Command::Theme,
+        Command::Help,
+        Command::Quit,
+    ];
+
+    /// First word of the command when typed with arguments; also what
+    /// the recent commands are remembered by.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            Command::Search => "search",
+            Command::League => "league",
+            Command::TopMatches => "top",
+            Command::Country => "country",
+            Command::Favorites => "favorites",
+            Command::AddFavorite => "add",
+            Command::RemoveFavorite => "remove",
+            Command::MoveFavorite => "move",
+            Command::Export => "export",
+            Command::Refresh => "refresh",
+            Command::Timezone => "timezone",
+            Command::Theme => "theme",
+            Command::Help => "help",
+            Command::Quit => "quit",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Command::Search => "Search team",
+            Command::League => "Open league",
+            Command::TopMatches => "Top matches",
+            Command::Country => "Switch country",
+            Command::Favorites => "Favorites dashboard",
+            Command::AddFavorite => "Add favorite",
+            Command::RemoveFavorite => "Remove favorite",
+            Command::MoveFavorite => "Move favorite",
+            Command::Export => "Export calendar",
+            Command::Refresh => "Refresh",
+            Command::Timezone => "Change time zone",
+            Command::Theme => "Change theme",
+            Command::Help => "Show keys",
+            Command::Quit => "Quit",
+        }
+    }
+
+    /// Arguments the command takes, as shown in the palette; empty for none.
+    pub fn usage(self) -> &'static str {
+        match self {
+            Command::Search => "<team> [country]",
+            Command::League => "[competition]",
+            Command::Country => "[uk|us|fr]",
+            Command::AddFavorite => "[team] [country]",
+            Command::RemoveFavorite => "[team]",
+            Command::MoveFavorite => "[team] <up|down|position>",
+            Command::Timezone => "[zone]",
+            Command::Theme => "[name]",
+            _ => "",
+        }
+    }
+
+    fn parse(keyword: &str) -> Option<Self> {
+        Command::ALL
+            .into_iter()
+            .find(|c| c.keyword().eq_ignore_ascii_case(keyword))
+    }
+}
+
+/// A command the palette offers for what was typed, with the arguments
+/// that go with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub command: Command,
+    pub args: String,
+}
+
+impl Candidate {
+    fn new(command: Command) -> Self {
+        Self { command, args: String::new() }
+    }
+}
+
+/// How many recently used commands are remembered.
+pub const MAX_RECENT: usize = 8;
+
+/// The open palette: what's typed and which candidate is highlighted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Palette {
+    pub input: String,
+    pub selected: usize,
+}
+
+impl Palette {
+    pub fn candidates(&self, recent: &[String]) -> Vec<Candidate> {
+        candidates(&self.input, recent)
+    }
+}
+
+/// Candidates for `input`, best first:
+///
+/// - nothing typed lists every command, the `recent` ones (keywords,
+///   newest first) ahead of the rest;
+/// - a command's keyword followed by more words is that command with
+///   those words as arguments ("search lyon fr");
+/// - anything else is fuzzy matched against titles and keywords, ties
+///   going to the more recent command. When nothing matches, the input is
+///   offered as a team search.
+pub fn candidates(input: &str, recent: &[String]) -> Vec<Candidate> {
+    let input = input.trim();
+    let recency = |command: Command| {
+        recent
+            .iter()
+            .position(|r| r == command.keyword())
+            .unwrap_or(usize::MAX)
+    };
+
+    if input.is_empty() {
+        let mut commands = Command::ALL.to_vec();
+        commands.sort_by_key(|c| recency(*c));
+        return commands.into_iter().map(Candidate::new).collect();
+    }
+
+    if let Some((head, args)) = input.split_once(char::is_whitespace)
+        && let Some(command) = Command::parse(head)
+        && !command.usage().is_empty()
+    {
+        return vec![Candidate {
+            command,
+            args: args.trim().to_string(),
+        }];
+    }
+
+    let mut scored: Vec<(i32, Command)> = Command::ALL
+        .into_iter()
+        .filter_map(|c| {
+            let score = fuzzy_score(input, c.title()).max(fuzzy_score(input, c.keyword()))?;
+            Some((score, c))
+        })
+        .collect();
+    scored.sort_by_key(|(score, c)| (-score, recency(*c)));
+
+    if scored.is_empty() {
+        return vec![Candidate {
+            command: Command::Search,
+            args: input.to_string(),
+        }];
+    }
+    scored.into_iter().map(|(_, c)| Candidate::new(c)).collect()
+}
+
+/// Scores `query` as a subsequence of `text`, ignoring case and spaces in
+/// the query: `None` when it isn't one, more for letters that follow one
+/// another or start a word, and for matches nearer the start.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = position + text[position..].iter().position(|c| *c == q)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        if previous.is_none() {
+            score -= found.min(10) as i32;
+        }
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+/// `recent` with `command` moved to the front, capped at [`MAX_RECENT`].
+pub fn remember(recent: &mut Vec<String>, command: Command) {
+    recent.retain(|r| r != command.keyword());
+    recent.insert(0, command.keyword().to_string());
+    recent.truncate(MAX_RECENT);
+}
@@ -0,0 +1,135 @@
+use ratatui::layout::{Position, Rect};
+
+/// Where a list is: the selected item, and the first row drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListPosition {
+    pub selected: usize,
+    pub offset: usize,
+}
+
+impl ListPosition {
+    /// `selected`, scrolled from the top.
+    pub fn at(selected: usize) -> Self {
+        Self { selected, offset: 0 }
+    }
+}
+
+/// A list as laid out on screen. Rows are what's drawn, headers included;
+/// offsets count rows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Viewport {
+    /// Where the rows are drawn, inside any border and header.
+    pub area: Rect,
+    /// Lines each row takes.
+    pub heights: Vec<u16>,
+    /// Index into the list each row selects; `None` for headers.
+    pub indices: Vec<Option<usize>>,
+}
+
+impl Viewport {
+    /// One row per item, selecting the item at the same index.
+    pub fn new(area: Rect, heights: Vec<u16>) -> Self {
+        let indices = (0..heights.len()).map(Some).collect();
+        Self { area, heights, indices }
+    }
+
+    /// Row showing the item at `index`.
+    pub fn row_of(&self, index: usize) -> Option<usize> {
+        self.indices.iter().position(|i| *i == Some(index))
+    }
+
+    /// Whether every row fits at once.
+    pub fn fits(&self) -> bool {
+        self.heights.iter().map(|&h| h as usize).sum::<usize>() <= self.area.height as usize
+    }
+
+    /// Rows that fit from `offset` on; at least one, however tall.
+    pub fn visible(&self, offset: usize) -> usize {
+        let mut lines = 0;
+        let mut rows = 0;
+        for &height in self.heights.iter().skip(offset) {
+            lines += height as usize;
+            if lines > self.area.height as usize {
+                break;
+            }
+            rows += 1;
+        }
+        rows.max(1).min(self.heights.len().saturating_sub(offset))
+    }
+
+    /// The furthest offset worth scrolling to: the one showing the last row
+    /// at the bottom.
+    pub fn max_offset(&self) -> usize {
+        let mut lines = 0;
+        for (row, &height) in self.heights.iter().enumerate().rev() {
+            lines += height as usize;
+            if lines > self.area.height as usize {
+                return (row + 1).min(self.heights.len() - 1);
+            }
+        }
+        0
+    }
+
+    /// The offset that shows the row of `selected`, moved as little as
+    /// possible from `offset`. Rows above come back into view once the end
+    /// of the list leaves room for them, e.g. after the terminal grew.
+    pub fn follow(&self, offset: usize, selected: usize) -> usize {
+        let lines = self.area.height as usize;
+        let height = |rows: &[u16]| rows.iter().map(|&h| h as usize).sum::<usize>();
+        let Some(row) = self.row_of(selected) else {
+            return offset.min(self.heights.len().saturating_sub(1));
+        };
+
+        let mut offset = offset.min(row);
+        while offset < row && height(&self.heights[offset..=row]) > lines {
+            offset += 1;
+        }
+        while offset > 0 && height(&self.heights[offset - 1..]) <= lines {
+            offset -= 1;
+        }
+        offset
+    }
+
+    /// The item a page below or above `selected` (a page being the rows
+    /// shown from `offset`), or the nearest item past a header.
+    pub fn page(&self, offset: usize, selected: usize, down: bool) -> Option<usize> {
+        let row = self.row_of(selected)?;
+        let step = self.visible(offset);
+        let last = self.indices.len() - 1;
+        let target = if down { (row + step).min(last) } else { row.saturating_sub(step) };
+
+        let below = || self.indices[target..].iter().flatten().next().copied();
+        let above = || self.indices[..=target].iter().rev().flatten().next().copied();
+        if down {
+            below().or_else(above)
+        } else {
+            above().or_else(below)
+        }
+    }
+
+    /// The item a row below or above `selected`, past any header.
+    pub fn step(&self, selected: usize, down: bool) -> Option<usize> {
+        let row = self.row_of(selected)?;
+        if down {
+            self.indices[row + 1..].iter().flatten().next().copied()
+        } else {
+            self.indices[..row].iter().rev().flatten().next().copied()
+        }
+    }
+
+    /// The item drawn at column `x`, line `y`, scrolled to `offset`;
+    /// `None` for a header or outside the list.
+    pub fn index_at(&self, offset: usize, x: u16, y: u16) -> Option<usize> {
+        if !self.area.contains(Position::new(x, y)) {
+            return None;
+        }
+        let mut top = self.area.y;
+        for (row, &height) in self.heights.iter().enumerate().skip(offset) {
+            if y < top.saturating_add(height) {
+                return self.indices[row];
+            }
+            top = top.saturating_add(height);
+        }
+        None
+    }
+}
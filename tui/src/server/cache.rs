@@ -0,0 +1,61 @@
+use foot_info_core::error::AppError;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Successful responses kept for `ttl`, so several displays polling the
+/// server don't each trigger a scrape. Errors are never cached.
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+    entries: Mutex<HashMap<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The cached value, if it is still fresh.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(key)
+            .filter(|(stored, _)| stored.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, (stored, _)| stored.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), value));
+    }
+
+    /// The cached value, or the result of `fetch`, which is cached on
+    /// success.
+    pub async fn get_or_fetch<F, Fut>(&self, key: K, fetch: F) -> Result<V, AppError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, AppError>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+        self.refresh(key, fetch).await
+    }
+
+    /// Fetches regardless of what is cached and stores the result on
+    /// success; a failure leaves the previous entry in place.
+    pub async fn refresh<F, Fut>(&self, key: K, fetch: F) -> Result<V, AppError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, AppError>>,
+    {
+        let value = fetch().await?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+}
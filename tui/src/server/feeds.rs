@@ -0,0 +1,99 @@
+use super::{parse_country, ApiError, ServerState};
+use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use foot_info_core::error::AppError;
+use foot_info_core::export::{atom, ical};
+use foot_info_core::models::Country;
+use foot_info_core::utils::clock::SystemClock;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// A team whose feed is kept warm in the background.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedTeam {
+    pub team: String,
+    pub country: Country,
+}
+
+/// Refreshes each team's listings every `every`, so subscribed calendars
+/// and readers are answered from the cache with current kickoff times and
+/// channels. Failures are reported and retried on the next round.
+pub fn spawn_feed_refresh(state: Arc<ServerState>, teams: Vec<FeedTeam>, every: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        if teams.is_empty() {
+            return;
+        }
+        let mut ticker = tokio::time::interval(every);
+        loop {
+            ticker.tick().await;
+            for FeedTeam { team, country } in &teams {
+                if let Err(e) = state.refresh_team(team, *country).await {
+                    eprintln!("Feed refresh for {} ({}) failed: {}", team, country, e);
+                }
+            }
+        }
+    })
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct FeedQuery {
+    country: Option<String>,
+}
+
+/// `/feeds/manchester-united.ics` or `.atom`: dashes in the file name stand
+/// for spaces in the team name. An empty schedule is an empty feed rather
+/// than an error, so subscriptions survive the off-season.
+pub(super) async fn feed(
+    State(state): State<Arc<ServerState>>,
+    Path(file): Path<String>,
+    Query(query): Query<FeedQuery>,
+) -> Result<Response, ApiError> {
+    let (slug, kind) = file
+        .rsplit_once('.')
+        .filter(|(slug, kind)| !slug.is_empty() && matches!(*kind, "ics" | "atom"))
+        .ok_or_else(|| ApiError::NotFound(format!("Unknown feed '{}' (expected .ics or .atom)", file)))?;
+    let country = match query.country.as_deref() {
+        Some(code) => parse_country(code)?,
+        None => Country::UK,
+    };
+
+    let team = team_name(slug);
+    let matches = match state.search_team(&team, country).await {
+        Ok(matches) => matches,
+        Err(AppError::NoMatchesScheduled(_)) => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    let response = if kind == "ics" {
+        (
+            [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+            ical::matches_to_ical(&team, &matches, &SystemClock),
+        )
+    } else {
+        let mut feeds = state.feed_revisions.lock().unwrap_or_else(|e| e.into_inner());
+        let revisions = feeds.entry((country, team.to_lowercase())).or_default();
+        (
+            [(header::CONTENT_TYPE, "application/atom+xml; charset=utf-8")],
+            atom::matches_to_atom(&team, &matches, revisions, &SystemClock),
+        )
+    };
+    Ok(response.into_response())
+}
+
+/// "manchester-united" → "Manchester United".
+fn team_name(slug: &str) -> String {
+    slug.split(['-', '_', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
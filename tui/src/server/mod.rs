@@ -0,0 +1,283 @@
+mod cache;
+mod feeds;
+
+pub use cache::TtlCache;
+pub use feeds::{spawn_feed_refresh, FeedTeam};
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use foot_info_core::client::FootballClient;
+use foot_info_core::error::AppError;
+use foot_info_core::export::atom::Revisions;
+use foot_info_core::models::{Country, LeagueStats, Match, MatchDetails, TopMatch};
+use foot_info_core::providers::{league_stats, match_details};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use url::Url;
+
+/// The client behind the API plus one cache per kind of request.
+pub struct ServerState {
+    pub client: FootballClient,
+    search: TtlCache<(Country, String), Vec<Match>>,
+    top: TtlCache<(), Vec<TopMatch>>,
+    league: TtlCache<String, LeagueStats>,
+    details: TtlCache<String, MatchDetails>,
+    feed_revisions: Mutex<HashMap<(Country, String), Revisions>>,
+}
+
+impl ServerState {
+    pub fn new(client: FootballClient, ttl: Duration) -> Self {
+        Self {
+            client,
+            search: TtlCache::new(ttl),
+            top: TtlCache::new(ttl),
+            league: TtlCache::new(ttl),
+            details: TtlCache::new(ttl),
+            feed_revisions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Cached team search; team names are matched case-insensitively.
+    pub async fn search_team(&self, team: &str, country: Country) -> Result<Vec<Match>, AppError> {
+        let key = (country, team.trim().to_lowercase());
+        self.search
+            .get_or_fetch(key, || self.client.search_team(team, country))
+            .await
+    }
+
+    /// Re-fetches a team search into the cache, keeping the previous
+    /// listings if the source site fails.
+    pub async fn refresh_team(&self, team: &str, country: Country) -> Result<Vec<Match>, AppError> {
+        let key = (country, team.trim().to_lowercase());
+        self.search
+            .refresh(key, || self.client.search_team(team, country))
+            .await
+    }
+}
+
+/// Routes of the local API:
+///
+/// - `GET /api/search/{team}` — every country's listings for a team
+/// - `GET /api/search/{country}/{team}` — one country's listings
+/// - `GET /api/top` — featured upcoming matches
+/// - `GET /api/league?competition=premier-league` — a competition's stats
+/// - `GET /api/match?url=/match/...` — one match's details
+/// - `GET /feeds/{team}.ics` / `GET /feeds/{team}.atom` — subscribable
+///   calendar and feed of a team's listings, `?country=` defaulting to uk
+pub fn router(state: Arc<ServerState>) -> Router {
+    Router::new()
+        .route("/api/search/{team}", get(search_all))
+        .route("/api/search/{country}/{team}", get(search_country))
+        .route("/api/top", get(top_matches))
+        .route("/api/league", get(league))
+        .route("/api/match", get(match_details))
+        .route("/feeds/{file}", get(feeds::feed))
+        .with_state(state)
+}
+
+/// Serves the API on `addr` until the process is stopped, keeping the
+/// feeds of `feed_teams` refreshed every `refresh` meanwhile.
+pub async fn serve(
+    addr: SocketAddr,
+    state: ServerState,
+    feed_teams: Vec<FeedTeam>,
+    refresh: Duration,
+) -> io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    let state = Arc::new(state);
+    let refresher = spawn_feed_refresh(state.clone(), feed_teams, refresh);
+    let served = axum::serve(listener, router(state)).await;
+    refresher.abort();
+    served
+}
+
+// ── Errors ───────────────────────────────────────────────────────────────
+
+/// Failures as JSON: `{"error": {"kind": "team_not_found", "message": ...}}`.
+pub enum ApiError {
+    App(AppError),
+    BadRequest(String),
+    NotFound(String),
+}
+
+impl From<AppError> for ApiError {
+    fn from(err: AppError) -> Self {
+        ApiError::App(err)
+    }
+}
+
+/// HTTP status for each error kind. Failures of the scraped sites are the
+/// upstream's fault, hence 502.
+pub fn status_code(err: &AppError) -> StatusCode {
+    match err {
+        AppError::Network(_) | AppError::ProviderError(_) => StatusCode::BAD_GATEWAY,
+        AppError::TeamNotFound(_) | AppError::NoMatchesScheduled(_) => StatusCode::NOT_FOUND,
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorBody {
+    pub kind: &'static str,
+    pub message: String,
+}
+
+impl From<&AppError> for ErrorBody {
+    fn from(err: &AppError) -> Self {
+        Self {
+            kind: err.kind(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, body) = match self {
+            ApiError::App(err) => (status_code(&err), ErrorBody::from(&err)),
+            ApiError::BadRequest(message) => (
+                StatusCode::BAD_REQUEST,
+                ErrorBody { kind: "bad_request", message },
+            ),
+            ApiError::NotFound(message) => (
+                StatusCode::NOT_FOUND,
+                ErrorBody { kind: "not_found", message },
+            ),
+        };
+        (status, Json(json!({ "error": body }))).into_response()
+    }
+}
+
+// ── Handlers ─────────────────────────────────────────────────────────────
+
+fn parse_country(code: &str) -> Result<Country, ApiError> {
+    Country::parse(code).ok_or_else(|| {
+        ApiError::BadRequest(format!("Unknown country '{}' (expected uk, us or fr)", code))
+    })
+}
+
+/// Checks that `url` is a LiveSoccerTV page over https, the only pages
+/// fetched on a caller's behalf; otherwise anyone reaching the server could
+/// point it at other hosts, the local network's included.
+fn livesoccertv_url(url: &str) -> Result<String, ApiError> {
+    let rejected = || {
+        ApiError::BadRequest(format!("Only https://www.livesoccertv.com pages can be fetched, not '{}'", url))
+    };
+    let parsed = Url::parse(url).map_err(|_| rejected())?;
+    let host = parsed.host_str().unwrap_or_default();
+    if parsed.scheme() != "https"
+        || !matches!(host, "livesoccertv.com" | "www.livesoccertv.com")
+        || parsed.port().is_some()
+        || !parsed.username().is_empty()
+    {
+        return Err(rejected());
+    }
+    Ok(parsed.to_string())
+}
+
+async fn search_country(
+    State(state): State<Arc<ServerState>>,
+    Path((country, team)): Path<(String, String)>,
+) -> Result<Json<Vec<Match>>, ApiError> {
+    let country = parse_country(&country)?;
+    Ok(Json(state.search_team(&team, country).await?))
+}
+
+/// One country's outcome within an aggregated search.
+#[derive(Debug, Serialize)]
+pub struct CountryResult {
+    pub country: String,
+    pub provider: String,
+    pub matches: Vec<Match>,
+    pub error: Option<ErrorBody>,
+}
+
+/// Searches every provider concurrently. A failing provider doesn't fail
+/// the request; its error is reported alongside the others' matches.
+async fn search_all(
+    State(state): State<Arc<ServerState>>,
+    Path(team): Path<String>,
+) -> Json<Vec<CountryResult>> {
+    let handles: Vec<_> = state
+        .client
+        .providers()
+        .iter()
+        .map(|provider| {
+            let (state, team) = (state.clone(), team.clone());
+            let (country, name) = (provider.country(), provider.name().to_string());
+            let search = tokio::spawn(async move { state.search_team(&team, country).await });
+            (country, name, search)
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for (country, provider, search) in handles {
+        let outcome = search
+            .await
+            .unwrap_or_else(|e| Err(AppError::ProviderError(e.to_string())));
+        let (matches, error) = match outcome {
+            Ok(matches) => (matches, None),
+            Err(e) => (Vec::new(), Some(ErrorBody::from(&e))),
+        };
+        results.push(CountryResult {
+            country: country.to_string(),
+            provider,
+            matches,
+            error,
+        });
+    }
+    Json(results)
+}
+
+async fn top_matches(State(state): State<Arc<ServerState>>) -> Result<Json<Vec<TopMatch>>, ApiError> {
+    let matches = state
+        .top
+        .get_or_fetch((), || state.client.fetch_top_matches())
+        .await?;
+    Ok(Json(matches))
+}
+
+#[derive(Debug, Deserialize)]
+struct LeagueQuery {
+    competition: String,
+}
+
+async fn league(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<LeagueQuery>,
+) -> Result<Json<LeagueStats>, ApiError> {
+    let url = league_stats::competition_url(&query.competition).ok_or_else(|| {
+        ApiError::NotFound(format!("Unknown competition '{}'", query.competition))
+    })?;
+    let url = livesoccertv_url(&url)?;
+    let stats = state
+        .league
+        .get_or_fetch(url.clone(), || state.client.fetch_league_stats(&url))
+        .await?;
+    Ok(Json(stats))
+}
+
+#[derive(Debug, Deserialize)]
+struct MatchQuery {
+    url: String,
+}
+
+async fn match_details(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<MatchQuery>,
+) -> Result<Json<MatchDetails>, ApiError> {
+    let url = livesoccertv_url(&match_details::absolute_url(&query.url))?;
+    let details = state
+        .details
+        .get_or_fetch(url.clone(), || state.client.fetch_match_details(&url))
+        .await?;
+    Ok(Json(details))
+}
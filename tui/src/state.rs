@@ -1,9 +1,23 @@
-use crate::config::Config;
-use crate::models::{LeagueTab, ViewMode};
+use crate::config::{Config, Favorite};
+use crate::keymap::{KeyAction, Keymap, Scope};
+use crate::models::{
+    filter_matches, FavoriteStatus, FixtureSort, InputMode, LeagueTab, ListKind, MatchSort, ResultsSource,
+    ScorerSort, TableSort, ViewMode,
+};
+use crate::palette::Palette;
+use crate::suggest::{self, Suggestion};
+use crate::ui::theme::{ColorDepth, Theme};
+use crate::ui::Hit;
+use chrono::{DateTime, NaiveDate, Utc};
+use foot_info_core::changes::Change;
 use foot_info_core::client::FootballClient;
-use foot_info_core::models::{LeagueStats, Match, TopMatch};
+use foot_info_core::models::{Country, LeagueFixture, LeagueStats, Match, StandingRow, TopMatch, TopScorer};
 use foot_info_core::providers::FootballProvider;
+use foot_info_core::utils::format::KickoffFormat;
+use foot_info_core::utils::time::DisplayZone;
+use ratatui::layout::Rect;
 use std::sync::Arc;
+use std::time::Instant;
 
 pub const DEFAULT_LEAGUE_URL: &str =
     "https://www.livesoccertv.com/competitions/england/premier-league/";
@@ -12,17 +26,57 @@ pub const DEFAULT_LEAGUE_URL: &str =
 /// This is what the UI reads from and what handlers mutate.
 pub struct AppState {
     pub search_input: String,
+    /// Teams seen in fetched league tables, suggested while typing.
+    pub team_index: Vec<String>,
+    /// Teams matching what's typed, shown under the search bar.
+    pub suggestions: Vec<Suggestion>,
+    /// Suggestion highlighted with Down; `None` keeps the typed text.
+    pub selected_suggestion: Option<usize>,
+    /// How far back through the country's searches Up has gone.
+    pub history_cursor: Option<usize>,
+    /// What was typed before going back through the searches.
+    pub search_draft: String,
+    /// Search results shown, i.e. after the filter and in the chosen order.
     pub matches: Vec<Match>,
+    pub all_matches: Vec<Match>,
+    pub match_filter: String,
+    pub match_sort: MatchSort,
+    pub selected_match_index: usize,
+    /// First row of each list drawn, kept so the selection shows.
+    pub match_offset: usize,
+    /// Team the results on screen were searched for.
+    pub searched_team: Option<String>,
+    /// Where the results on screen were listed.
+    pub results_source: Option<ResultsSource>,
+    /// The search result whose details are open.
+    pub match_detail: Option<Match>,
     pub error_message: Option<String>,
     pub status_message: Option<String>,
     pub is_loading: bool,
     pub exit: bool,
     pub config: Config,
     pub client: FootballClient,
+    /// Runtime toggle: show times as the broadcaster lists them instead of
+    /// in the configured zone.
+    pub show_source_zone: bool,
     pub current_provider_index: usize,
     pub view_mode: ViewMode,
+    // Dashboard
+    /// Last fetch of each favourite, whatever their current order.
+    pub dashboard: Vec<(Favorite, FavoriteStatus)>,
+    pub selected_favorite_index: usize,
+    pub favorite_offset: usize,
+    /// Top matches currently shown, i.e. after the competition filter.
     pub top_matches: Vec<TopMatch>,
+    pub all_top_matches: Vec<TopMatch>,
+    pub top_match_competition: Option<String>,
+    pub top_match_filter: String,
+    pub top_match_sort: MatchSort,
+    /// Day whose full schedule is shown; `None` for the featured top matches.
+    pub schedule_date: Option<NaiveDate>,
     pub selected_top_match_index: usize,
+    /// Within the selected match's day column.
+    pub top_match_offset: usize,
     // League view
     pub league_stats: Option<LeagueStats>,
     pub league_url: String,
@@ -30,34 +84,299 @@ pub struct AppState {
     pub selected_fixture_index: usize,
     pub selected_table_index: usize,
     pub selected_scorer_index: usize,
+    pub fixture_offset: usize,
+    pub table_offset: usize,
+    pub scorer_offset: usize,
+    pub fixture_filter: String,
+    pub fixture_sort: FixtureSort,
+    pub table_filter: String,
+    pub table_sort: TableSort,
+    pub scorer_filter: String,
+    pub scorer_sort: ScorerSort,
+    /// Whether keys are typed into the current list's filter (after `/`).
+    pub filtering: bool,
+    /// Instant countdowns are measured from, advanced by `Action::Tick`.
+    pub now: DateTime<Utc>,
+    /// Changes since the last look at the current search or competition.
+    pub changes: Vec<Change>,
+    /// Snapshot key of that search or competition, which `changes` must
+    /// come from.
+    pub changes_key: Option<String>,
+    pub keymap: Keymap,
+    pub input_mode: InputMode,
+    /// A letter bound to `First` was pressed once; the second press jumps.
+    pub pending_first: bool,
+    /// Whether the key help overlay is open.
+    pub show_help: bool,
+    /// The command palette, while open.
+    pub palette: Option<Palette>,
+    pub theme: Theme,
+    /// Size of the terminal, for laying lists out between draws. Empty
+    /// until the app runs, which leaves scrolling to the widgets.
+    pub screen: Rect,
+    /// When and where the last click landed, to tell a double-click.
+    pub last_click: Option<(Instant, Hit)>,
 }
 
 impl AppState {
     pub fn new() -> Self {
-        let config = Config::load();
+        Self::with_config(Config::load())
+    }
+
+    /// A state running on `config` rather than the user's own.
+    pub fn with_config(config: Config) -> Self {
+        let client = FootballClient::with_zone(config.display_zone());
+        let (keymap, key_problems) = config.keymap();
+        let mut problems: Vec<String> = key_problems.first().map(|first| match key_problems.len() {
+            1 => format!("Keybindings: {}", first),
+            n => format!("Keybindings: {} (+{} more)", first, n - 1),
+        }).into_iter().collect();
+        let theme = config.theme().unwrap_or_else(|err| {
+            problems.push(format!("Theme: {}", err));
+            Theme::default().for_depth(ColorDepth::detect())
+        });
         Self {
             search_input: String::new(),
+            team_index: suggest::load_index(Config::team_index_path().as_deref()),
+            suggestions: Vec::new(),
+            selected_suggestion: None,
+            history_cursor: None,
+            search_draft: String::new(),
             matches: Vec::new(),
+            all_matches: Vec::new(),
+            match_filter: String::new(),
+            match_sort: MatchSort::Kickoff,
+            selected_match_index: 0,
+            match_offset: 0,
+            searched_team: None,
+            results_source: None,
+            match_detail: None,
             error_message: None,
-            status_message: None,
+            status_message: (!problems.is_empty()).then(|| problems.join(" · ")),
             is_loading: false,
             exit: false,
             config,
-            client: FootballClient::new(),
+            client,
+            show_source_zone: false,
             current_provider_index: 0,
             view_mode: ViewMode::Search,
+            dashboard: Vec::new(),
+            selected_favorite_index: 0,
+            favorite_offset: 0,
             top_matches: Vec::new(),
+            all_top_matches: Vec::new(),
+            top_match_competition: None,
+            top_match_filter: String::new(),
+            top_match_sort: MatchSort::Kickoff,
+            schedule_date: None,
             selected_top_match_index: 0,
+            top_match_offset: 0,
             league_stats: None,
             league_url: DEFAULT_LEAGUE_URL.to_string(),
             league_tab: LeagueTab::Fixtures,
             selected_fixture_index: 0,
             selected_table_index: 0,
             selected_scorer_index: 0,
+            fixture_offset: 0,
+            table_offset: 0,
+            scorer_offset: 0,
+            fixture_filter: String::new(),
+            fixture_sort: FixtureSort::Date,
+            table_filter: String::new(),
+            table_sort: TableSort::Position,
+            scorer_filter: String::new(),
+            scorer_sort: ScorerSort::Goals,
+            filtering: false,
+            now: Utc::now(),
+            changes: Vec::new(),
+            changes_key: None,
+            keymap,
+            input_mode: InputMode::Insert,
+            pending_first: false,
+            show_help: false,
+            palette: None,
+            theme,
+            screen: Rect::default(),
+            last_click: None,
+        }
+    }
+
+    /// Where key bindings are looked up: the open detail popup, the
+    /// current view, or normal mode while the Search view isn't being typed
+    /// into.
+    pub fn key_scope(&self) -> Scope {
+        if self.match_detail.is_some() {
+            return Scope::Detail;
+        }
+        match (self.view_mode, self.input_mode) {
+            (ViewMode::Search, InputMode::Normal) => Scope::Normal,
+            (mode, _) => mode.into(),
+        }
+    }
+
+    /// The list on screen, if there is one to filter.
+    pub fn current_list(&self) -> Option<ListKind> {
+        match self.view_mode {
+            ViewMode::Dashboard => None,
+            ViewMode::Search => Some(ListKind::Results),
+            ViewMode::TopMatches => Some(ListKind::TopMatches),
+            ViewMode::League => Some(match self.league_tab {
+                LeagueTab::Fixtures => ListKind::Fixtures,
+                LeagueTab::Table => ListKind::Table,
+                LeagueTab::TopScorers => ListKind::Scorers,
+            }),
+        }
+    }
+
+    /// Selection and scroll offset of the list in the current view.
+    pub fn list_position_mut(&mut self) -> (&mut usize, &mut usize) {
+        match self.view_mode {
+            ViewMode::Dashboard => (&mut self.selected_favorite_index, &mut self.favorite_offset),
+            ViewMode::Search => (&mut self.selected_match_index, &mut self.match_offset),
+            ViewMode::TopMatches => (&mut self.selected_top_match_index, &mut self.top_match_offset),
+            ViewMode::League => match self.league_tab {
+                LeagueTab::Fixtures => (&mut self.selected_fixture_index, &mut self.fixture_offset),
+                LeagueTab::Table => (&mut self.selected_table_index, &mut self.table_offset),
+                LeagueTab::TopScorers => (&mut self.selected_scorer_index, &mut self.scorer_offset),
+            },
+        }
+    }
+
+    pub fn filter(&self, list: ListKind) -> &str {
+        match list {
+            ListKind::Results => &self.match_filter,
+            ListKind::TopMatches => &self.top_match_filter,
+            ListKind::Fixtures => &self.fixture_filter,
+            ListKind::Table => &self.table_filter,
+            ListKind::Scorers => &self.scorer_filter,
+        }
+    }
+
+    pub fn filter_mut(&mut self, list: ListKind) -> &mut String {
+        match list {
+            ListKind::Results => &mut self.match_filter,
+            ListKind::TopMatches => &mut self.top_match_filter,
+            ListKind::Fixtures => &mut self.fixture_filter,
+            ListKind::Table => &mut self.table_filter,
+            ListKind::Scorers => &mut self.scorer_filter,
+        }
+    }
+
+    /// Fixtures on the Fixtures tab: filtered on the teams and channels.
+    pub fn shown_fixtures(&self) -> Vec<&LeagueFixture> {
+        let Some(stats) = &self.league_stats else {
+            return Vec::new();
+        };
+        self.fixture_sort.sorted(stats.fixtures.iter().filter(|f| {
+            let mut fields = vec![f.home_team.as_str(), f.away_team.as_str()];
+            fields.extend(f.channels.iter().map(String::as_str));
+            filter_matches(&self.fixture_filter, &fields)
+        }))
+    }
+
+    /// Rows on the Table tab: filtered on the team and its zone.
+    pub fn shown_table(&self) -> Vec<&StandingRow> {
+        let Some(stats) = &self.league_stats else {
+            return Vec::new();
+        };
+        self.table_sort.sorted(stats.table.iter().filter(|row| {
+            let zone = row.zone.as_ref().map_or("", |z| z.label.as_str());
+            filter_matches(&self.table_filter, &[&row.team, zone])
+        }))
+    }
+
+    /// Players on the Top Scorers tab: filtered on the player and team.
+    pub fn shown_scorers(&self) -> Vec<&TopScorer> {
+        let Some(stats) = &self.league_stats else {
+            return Vec::new();
+        };
+        self.scorer_sort.sorted(
+            stats.top_scorers.iter().filter(|s| filter_matches(&self.scorer_filter, &[&s.player, &s.team])),
+        )
+    }
+
+    /// Rows of `list` shown and in all.
+    fn list_counts(&self, list: ListKind) -> (usize, usize) {
+        let stats = self.league_stats.as_ref();
+        match list {
+            ListKind::Results => (self.matches.len(), self.all_matches.len()),
+            ListKind::TopMatches => (self.top_matches.len(), self.all_top_matches.len()),
+            ListKind::Fixtures => (self.shown_fixtures().len(), stats.map_or(0, |s| s.fixtures.len())),
+            ListKind::Table => (self.shown_table().len(), stats.map_or(0, |s| s.table.len())),
+            ListKind::Scorers => (self.shown_scorers().len(), stats.map_or(0, |s| s.top_scorers.len())),
+        }
+    }
+
+    /// The status bar's text: the current list's filter, while it's typed
+    /// or set, then the status message.
+    pub fn status_line(&self) -> Option<String> {
+        let filter = self.current_list().and_then(|list| {
+            let text = self.filter(list);
+            if !self.filtering && text.is_empty() {
+                return None;
+            }
+            let cursor = if self.filtering { "_" } else { "" };
+            let (shown, total) = self.list_counts(list);
+            Some(format!("Filter: {}{} ({} of {})", text, cursor, shown, total))
+        });
+        match (filter, &self.status_message) {
+            (Some(filter), Some(message)) => Some(format!("{} · {}", filter, message)),
+            (filter, message) => filter.or_else(|| message.clone()),
+        }
+    }
+
+    /// Teams searched in the current provider's country, newest first.
+    pub fn searches(&self) -> &[String] {
+        self.config.searches(self.get_current_provider().country())
+    }
+
+    /// What the dashboard last heard of `favorite`, if it was fetched.
+    pub fn favorite_status(&self, favorite: &Favorite) -> Option<&FavoriteStatus> {
+        self.dashboard.iter().find(|(f, _)| f == favorite).map(|(_, status)| status)
+    }
+
+    /// Records what was heard of `favorite`, replacing what was.
+    pub fn set_favorite_status(&mut self, favorite: &Favorite, status: FavoriteStatus) {
+        match self.dashboard.iter_mut().find(|(f, _)| f == favorite) {
+            Some((_, existing)) => *existing = status,
+            None => self.dashboard.push((favorite.clone(), status)),
+        }
+    }
+
+    pub fn display_zone(&self) -> DisplayZone {
+        if self.show_source_zone {
+            DisplayZone::Source
+        } else {
+            self.config.display_zone()
         }
     }
 
+    pub fn kickoff_format(&self) -> KickoffFormat {
+        KickoffFormat::new(self.config.locale(), self.config.hour_cycle(), self.display_zone())
+    }
+
+    /// Rebuilds the client after the display zone changed, keeping the
+    /// selected provider.
+    pub fn refresh_client_zone(&mut self) {
+        self.client = FootballClient::with_zone(self.display_zone());
+    }
+
     pub fn get_current_provider(&self) -> Arc<dyn FootballProvider> {
         self.client.providers()[self.current_provider_index].clone()
     }
+
+    /// Country the results on screen were listed in, which switching
+    /// provider since doesn't change.
+    pub fn results_country(&self) -> Country {
+        self.results_source
+            .as_ref()
+            .map_or_else(|| self.get_current_provider().country(), |source| source.country)
+    }
+
+    /// The key that adds the searched team to the favourites, as shown to
+    /// the user; `None` when the keymap leaves it unbound.
+    pub fn save_favorite_key(&self) -> Option<String> {
+        let keys = self.keymap.keys_for(Scope::Search, KeyAction::SaveFavorite);
+        keys.first().map(|key| key.to_string())
+    }
 }
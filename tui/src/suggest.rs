@@ -0,0 +1,117 @@
+use crate::palette::fuzzy_score;
+use foot_info_core::models::LeagueStats;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Searches kept per country.
+pub const MAX_HISTORY: usize = 50;
+/// Suggestions shown under the search bar.
+pub const MAX_SUGGESTIONS: usize = 5;
+
+/// Where a suggested team comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    History,
+    Favorite,
+    /// A table of a league fetched before.
+    League,
+}
+
+impl Source {
+    pub fn label(self) -> &'static str {
+        match self {
+            Source::History => "recent",
+            Source::Favorite => "favorite",
+            Source::League => "league",
+        }
+    }
+
+    /// Ties between equally good matches go to teams searched before,
+    /// then to favourites.
+    fn bonus(self) -> i32 {
+        match self {
+            Source::History => 4,
+            Source::Favorite => 2,
+            Source::League => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub team: String,
+    pub source: Source,
+}
+
+/// `history` with `team` moved to the front, ignoring case, capped at
+/// [`MAX_HISTORY`].
+pub fn remember(history: &mut Vec<String>, team: &str) {
+    let team = team.trim();
+    if team.is_empty() {
+        return;
+    }
+    history.retain(|t| !t.eq_ignore_ascii_case(team));
+    history.insert(0, team.to_string());
+    history.truncate(MAX_HISTORY);
+}
+
+/// Teams `input` fuzzily matches, best first and each named once. The team
+/// already typed out isn't suggested.
+pub fn suggest(input: &str, history: &[String], favorites: &[&str], index: &[String]) -> Vec<Suggestion> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    let candidates = history
+        .iter()
+        .map(|team| (team.as_str(), Source::History))
+        .chain(favorites.iter().map(|team| (*team, Source::Favorite)))
+        .chain(index.iter().map(|team| (team.as_str(), Source::League)));
+
+    let mut scored: Vec<(i32, Suggestion)> = Vec::new();
+    for (team, source) in candidates {
+        if team.eq_ignore_ascii_case(input) || scored.iter().any(|(_, s)| s.team.eq_ignore_ascii_case(team)) {
+            continue;
+        }
+        if let Some(score) = fuzzy_score(input, team) {
+            let team = team.to_string();
+            scored.push((score + source.bonus(), Suggestion { team, source }));
+        }
+    }
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().take(MAX_SUGGESTIONS).map(|(_, s)| s).collect()
+}
+
+/// Adds the teams of `stats`' table to `index`, kept sorted; true if any
+/// were new.
+pub fn index_teams(index: &mut Vec<String>, stats: &LeagueStats) -> bool {
+    let before = index.len();
+    for row in &stats.table {
+        let team = row.team.trim();
+        if !team.is_empty() && !index.iter().any(|t| t.eq_ignore_ascii_case(team)) {
+            index.push(team.to_string());
+        }
+    }
+    if index.len() == before {
+        return false;
+    }
+    index.sort_by_key(|team| team.to_lowercase());
+    true
+}
+
+/// The team index saved at `path`; empty when there is none yet or it
+/// can't be read.
+pub fn load_index(path: Option<&Path>) -> Vec<String> {
+    path.and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_index(path: &Path, index: &[String]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string(index)?)
+}
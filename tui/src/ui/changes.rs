@@ -0,0 +1,21 @@
+use crate::ui::theme::Theme;
+use foot_info_core::changes::Change;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Span;
+
+/// " ● rescheduled, channels changed" for a row that changed since the
+/// last look, or nothing.
+pub fn marker(changes: &[Change], key: &str, theme: &Theme) -> Option<Span<'static>> {
+    let labels: Vec<&str> = changes
+        .iter()
+        .filter(|c| c.listing.key == key)
+        .map(|c| c.kind.label())
+        .collect();
+    if labels.is_empty() {
+        return None;
+    }
+    Some(Span::styled(
+        format!(" ● {}", labels.join(", ")),
+        Style::default().fg(theme.warning).add_modifier(Modifier::BOLD),
+    ))
+}
@@ -0,0 +1,68 @@
+use crate::palette::Palette;
+use crate::ui::theme::Theme;
+use ratatui::layout::Rect;
+use ratatui::{
+    Frame,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Renders the palette near the top of `area`: the typed command, then
+/// the matching commands with the highlighted one marked. Commands in
+/// `recent` are tagged as such.
+pub fn render(frame: &mut Frame, area: Rect, palette: &Palette, recent: &[String], theme: &Theme) {
+    let candidates = palette.candidates(recent);
+
+    let width = area.width.min(60);
+    let height = (candidates.len() as u16 + 5).min(area.height);
+    let popup = Rect::new(area.x + (area.width - width) / 2, area.y + 1.min(area.height), width, height);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Commands — Enter to run, Tab to complete, Esc to close ")
+        .style(Style::default().bg(theme.background).fg(theme.text));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+    if inner.height < 2 {
+        return;
+    }
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled("> ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Span::raw(palette.input.as_str()),
+    ]));
+    frame.render_widget(input, Rect::new(inner.x, inner.y, inner.width, 1));
+
+    let items: Vec<ListItem> = candidates
+        .iter()
+        .map(|candidate| {
+            let command = candidate.command;
+            let mut spans = vec![Span::styled(
+                command.title(),
+                Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+            )];
+            if !candidate.args.is_empty() {
+                spans.push(Span::raw(format!(" · {}", candidate.args)));
+            } else if !command.usage().is_empty() {
+                spans.push(Span::styled(
+                    format!("  {} {}", command.keyword(), command.usage()),
+                    Style::default().fg(theme.text).add_modifier(Modifier::DIM),
+                ));
+            }
+            if recent.iter().any(|r| r == command.keyword()) {
+                spans.push(Span::styled("  recent", Style::default().fg(theme.warning)));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .highlight_style(theme.selected())
+        .highlight_symbol(">> ");
+    let mut list_state = ListState::default().with_selected(Some(palette.selected));
+    let list_area = Rect::new(inner.x, inner.y + 2, inner.width, inner.height - 2);
+    frame.render_stateful_widget(list, list_area, &mut list_state);
+}
@@ -0,0 +1,156 @@
+use crate::config::Favorite;
+use crate::models::FavoriteStatus;
+use crate::scroll::{ListPosition, Viewport};
+use crate::ui::components::scrollbar;
+use crate::ui::kickoff::{self, KickoffLabels};
+use crate::ui::layout;
+use crate::ui::theme::Theme;
+use foot_info_core::models::{Match, MatchStatus};
+use foot_info_core::utils::format::Countdown;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// One favourite as the dashboard shows it, with the labels for its
+/// country's listings.
+pub struct FavoriteCard<'a> {
+    pub favorite: &'a Favorite,
+    /// `None` until it's first fetched.
+    pub status: Option<&'a FavoriteStatus>,
+    pub labels: KickoffLabels,
+}
+
+/// The cards as laid out in `area`.
+pub fn viewport(area: Rect, cards: &[FavoriteCard]) -> Viewport {
+    Viewport::new(layout::results_horizontal(area)[1], cards.iter().map(card_height).collect())
+}
+
+/// Renders every favourite with its next match: kickoff, countdown and
+/// channels, highlighting and scrolling to the selected one. With none yet,
+/// explains how to add one, naming `save_key` when it's bound.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    cards: &[FavoriteCard],
+    position: ListPosition,
+    save_key: Option<&str>,
+    theme: &Theme,
+) {
+    if cards.is_empty() {
+        let text = match save_key {
+            Some(key) => format!(
+                "No favorites yet. Search a team and add it with {}, or run \"add <team>\" from the command palette.",
+                key
+            ),
+            None => "No favorites yet. Run \"add <team>\" from the command palette.".to_string(),
+        };
+        let empty = Paragraph::new(text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = cards
+        .iter()
+        .enumerate()
+        .map(|(i, card)| card_item(i, card, theme))
+        .collect();
+
+    let list = List::new(items)
+        .highlight_style(theme.selected())
+        .highlight_symbol(">> ");
+
+    let viewport = viewport(area, cards);
+    let offset = viewport.follow(position.offset, position.selected);
+    let mut list_state = ListState::default()
+        .with_offset(offset)
+        .with_selected(Some(position.selected));
+    frame.render_stateful_widget(list, viewport.area, &mut list_state);
+    scrollbar::render(frame, viewport.area, &viewport, offset, theme);
+}
+
+/// Lines `card_item` gives a card: the team, the next match's three lines
+/// or a one-line status, and a blank line.
+fn card_height(card: &FavoriteCard) -> u16 {
+    let next_match_lines = match card.status {
+        Some(FavoriteStatus::Matches(matches)) if next_match(matches, &card.labels).is_some() => 3,
+        _ => 1,
+    };
+    next_match_lines + 2
+}
+
+fn card_item<'a>(index: usize, card: &FavoriteCard<'a>, theme: &Theme) -> ListItem<'a> {
+    let favorite = card.favorite;
+    let labels = &card.labels;
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format!(" {}. {} ", index + 1, favorite.team),
+            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!("({})", favorite.country), Style::default().fg(theme.muted)),
+    ])];
+
+    let mut countdown = None;
+    match card.status {
+        None | Some(FavoriteStatus::Loading) => {
+            lines.push(Line::styled("    Fetching...", Style::default().fg(theme.muted)));
+        }
+        Some(FavoriteStatus::Error(err)) => {
+            lines.push(Line::styled(format!("    Error: {}", err), Style::default().fg(theme.warning)));
+        }
+        Some(FavoriteStatus::Matches(matches)) => match next_match(matches, labels) {
+            Some(m) => {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("    {}", m.teams), Style::default().fg(theme.text).add_modifier(Modifier::BOLD)),
+                    Span::styled(format!(" - {}", m.competition), Style::default().fg(theme.muted)),
+                ]));
+
+                let mut time_spans = vec![
+                    Span::raw("    📅 "),
+                    Span::raw(labels.day(m.kickoff, &m.date)),
+                    Span::raw(" ⏰ "),
+                    Span::raw(labels.time(m.kickoff, &m.time)),
+                ];
+                countdown = labels.countdown(m.kickoff, MatchStatus::Upcoming);
+                if let Some(countdown) = countdown {
+                    time_spans.push(Span::styled(
+                        format!("  ⏳ {}", labels.countdown_label(countdown)),
+                        kickoff::countdown_style(countdown, theme),
+                    ));
+                }
+                lines.push(Line::from(time_spans));
+
+                let channels = if m.channels.is_empty() {
+                    "No TV info".to_string()
+                } else {
+                    m.channels.join(", ")
+                };
+                lines.push(Line::from(vec![
+                    Span::styled("    📺 ", Style::default().fg(theme.warning)),
+                    Span::styled(channels, Style::default().fg(theme.info)),
+                ]));
+            }
+            None => lines.push(Line::styled("    No upcoming match listed", Style::default().fg(theme.muted))),
+        },
+    }
+    lines.push(Line::raw(""));
+
+    ListItem::new(Text::from(lines)).style(kickoff::imminent_style(countdown, theme))
+}
+
+/// The first listing that isn't over yet. Listings without a parsed kickoff
+/// can't be told apart, so they count as upcoming.
+pub fn next_match<'a>(matches: &'a [Match], labels: &KickoffLabels) -> Option<&'a Match> {
+    matches.iter().find(|m| {
+        !matches!(
+            labels.countdown(m.kickoff, MatchStatus::Upcoming),
+            Some(Countdown::Finished(_))
+        )
+    })
+}
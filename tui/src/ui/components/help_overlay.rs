@@ -0,0 +1,47 @@
+use crate::ui::theme::Theme;
+use ratatui::layout::Rect;
+use ratatui::{
+    Frame,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Renders the key help as a box centred over `area`, one binding per line.
+pub fn render(frame: &mut Frame, area: Rect, entries: &[(String, &str)], theme: &Theme) {
+    let key_width = entries.iter().map(|(keys, _)| keys.chars().count()).max().unwrap_or(0);
+    let text_width = entries.iter().map(|(_, text)| text.chars().count()).max().unwrap_or(0);
+
+    let width = ((key_width + text_width + 7) as u16).min(area.width);
+    let height = (entries.len() as u16 + 2).min(area.height);
+    let popup = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let lines: Vec<Line> = entries
+        .iter()
+        .map(|(keys, text)| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}  ", keys, width = key_width),
+                    Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(*text),
+            ])
+        })
+        .collect();
+
+    let help = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Keys — any key to close ")
+            .style(Style::default().bg(theme.background).fg(theme.text)),
+    );
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(help, popup);
+}
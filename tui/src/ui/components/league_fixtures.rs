@@ -1,67 +1,158 @@
-use foot_info_core::models::LeagueStats;
-use crate::ui::theme::{BEIGE, BG_BLACK, GOLD};
+use foot_info_core::changes::{fixture_key, Change};
+use foot_info_core::models::LeagueFixture;
+use crate::scroll::Viewport;
+use crate::ui::changes;
+use crate::ui::components::scrollbar;
+use crate::ui::kickoff::KickoffLabels;
+use crate::ui::theme::Theme;
 use ratatui::{
     Frame,
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    layout::{Margin, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 
-pub fn draw(frame: &mut Frame, area: Rect, stats: &LeagueStats, selected_index: usize) {
+/// The fixtures shown, after the filter and in the chosen order.
+pub struct FixtureList<'a> {
+    pub fixtures: &'a [&'a LeagueFixture],
+    /// In page order, so each matchday starts with a header.
+    pub by_round: bool,
+    pub selected_index: usize,
+    /// First line of the list drawn.
+    pub offset: usize,
+}
+
+/// A line of the list: a matchday header, or the fixture at an index.
+enum Entry {
+    Round(u16),
+    Fixture(usize),
+}
+
+/// Fixtures grouped by round: a header starts every new matchday.
+fn entries(list: &FixtureList) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut current_round: Option<u16> = None;
+    for (i, f) in list.fixtures.iter().enumerate() {
+        if list.by_round
+            && let Some(round) = f.matchday
+            && current_round != Some(round)
+        {
+            current_round = Some(round);
+            entries.push(Entry::Round(round));
+        }
+        entries.push(Entry::Fixture(i));
+    }
+    entries
+}
+
+/// The list as laid out in `area`, headers included.
+pub fn viewport(area: Rect, list: &FixtureList) -> Viewport {
+    let indices: Vec<Option<usize>> = entries(list)
+        .into_iter()
+        .map(|entry| match entry {
+            Entry::Round(_) => None,
+            Entry::Fixture(i) => Some(i),
+        })
+        .collect();
+    Viewport { area: Block::bordered().inner(area), heights: vec![1; indices.len()], indices }
+}
+
+pub fn draw(
+    frame: &mut Frame,
+    area: Rect,
+    list: &FixtureList,
+    labels: &KickoffLabels,
+    changes: &[Change],
+    theme: &Theme,
+) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(BEIGE))
+        .border_style(Style::default().fg(theme.text))
         .title(" Upcoming / Recent Fixtures ")
-        .style(Style::default().bg(BG_BLACK).fg(BEIGE));
+        .style(Style::default().bg(theme.background).fg(theme.text));
 
-    if stats.fixtures.is_empty() {
+    if list.fixtures.is_empty() {
         let empty = List::new(vec![ListItem::new("No fixtures found.")])
             .block(block);
         frame.render_widget(empty, area);
         return;
     }
 
-    let items: Vec<ListItem> = stats
-        .fixtures
-        .iter()
-        .map(|f| {
-            let mut spans = vec![];
-            
-            // Date mapping
-            spans.push(Span::styled(
-                format!("{:<15} ", f.date),
-                Style::default().fg(Color::DarkGray),
-            ));
-
-            // Time mapping
-            spans.push(Span::styled(
-                format!("{:<8} ", f.time),
-                Style::default().fg(Color::Yellow),
-            ));
-
-            // Teams mapping
-            spans.push(Span::styled(
-                format!("{} - {}", f.home_team, f.away_team),
-                Style::default().fg(BEIGE).add_modifier(Modifier::BOLD),
-            ));
-
-            ListItem::new(Line::from(spans))
+    // The selected list row is offset by the headers above it
+    let items: Vec<ListItem> = entries(list)
+        .into_iter()
+        .map(|entry| match entry {
+            Entry::Round(round) => round_header(list.fixtures, round, theme),
+            Entry::Fixture(i) => fixture_item(list.fixtures[i], labels, changes, theme),
         })
         .collect();
+    let viewport = viewport(area, list);
+    let selected_row = viewport.row_of(list.selected_index).unwrap_or(0);
+    let offset = viewport.follow(list.offset, list.selected_index);
 
-    let list = List::new(items)
+    let widget = List::new(items)
         .block(block)
         .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(GOLD)
-                .add_modifier(Modifier::BOLD),
+            theme.selected().fg(theme.accent),
         )
         .highlight_symbol(">> ");
 
-    let mut state = ListState::default();
-    state.select(Some(selected_index));
+    let mut state = ListState::default().with_offset(offset);
+    state.select(Some(selected_row));
+
+    frame.render_stateful_widget(widget, area, &mut state);
+    scrollbar::render(frame, area.inner(Margin::new(0, 1)), &viewport, offset, theme);
+}
+
+/// "Matchday 31 · Results" / "Matchday 32 · Upcoming" separator line,
+/// going by the round's fixtures shown.
+fn round_header(fixtures: &[&LeagueFixture], round: u16, theme: &Theme) -> ListItem<'static> {
+    let games: Vec<&LeagueFixture> = fixtures.iter().copied().filter(|f| f.matchday == Some(round)).collect();
+    let suffix = if games.iter().all(|f| f.score.is_some()) {
+        " · Results"
+    } else if games.iter().all(|f| f.score.is_none()) {
+        " · Upcoming"
+    } else {
+        ""
+    };
+
+    ListItem::new(Line::from(Span::styled(
+        format!("── Matchday {}{} ──", round, suffix),
+        Style::default().fg(theme.warning).add_modifier(Modifier::BOLD),
+    )))
+}
+
+fn fixture_item<'a>(
+    f: &'a LeagueFixture,
+    labels: &KickoffLabels,
+    changes: &[Change],
+    theme: &Theme,
+) -> ListItem<'a> {
+    let mut spans = vec![];
+
+    // Date mapping
+    spans.push(Span::styled(
+        format!("{:<18} ", labels.day(f.kickoff, &f.date)),
+        Style::default().fg(theme.muted),
+    ));
+
+    // Time mapping
+    spans.push(Span::styled(
+        format!("{:<8} ", labels.time(f.kickoff, &f.time)),
+        Style::default().fg(theme.accent),
+    ));
+
+    // Teams mapping, with the score in between once played
+    let teams = match &f.score {
+        Some(score) => format!("{} {} {}", f.home_team, score, f.away_team),
+        None => format!("{} - {}", f.home_team, f.away_team),
+    };
+    spans.push(Span::styled(
+        teams,
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+    ));
+    spans.extend(changes::marker(changes, &fixture_key(f), theme));
 
-    frame.render_stateful_widget(list, area, &mut state);
+    ListItem::new(Line::from(spans))
 }
@@ -1,68 +1,113 @@
-use foot_info_core::models::LeagueStats;
-use crate::ui::theme::{BEIGE, BG_BLACK, GOLD};
+use foot_info_core::models::TopScorer;
+use crate::models::ScorerSort;
+use crate::scroll::ListPosition;
+use crate::ui::components::league_table::viewport;
+use crate::ui::components::scrollbar;
+use crate::ui::theme::Theme;
 use ratatui::{
     Frame,
-    layout::{Constraint, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Margin, Rect},
+    style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
 };
 
-pub fn draw(frame: &mut Frame, area: Rect, stats: &LeagueStats, selected_index: usize) {
+/// Header label and the sort it corresponds to, if any.
+const COLUMNS: [(&str, Option<ScorerSort>); 10] = [
+    ("Player", None),
+    ("Team", None),
+    ("Goals", Some(ScorerSort::Goals)),
+    ("Pen", Some(ScorerSort::Penalties)),
+    ("NPG", Some(ScorerSort::NonPenaltyGoals)),
+    ("Ast", Some(ScorerSort::Assists)),
+    ("G+A", Some(ScorerSort::Contributions)),
+    ("Apps", Some(ScorerSort::Appearances)),
+    ("G/Gm", Some(ScorerSort::GoalsPerGame)),
+    ("Min/G", Some(ScorerSort::MinutesPerGoal)),
+];
+
+pub fn draw(
+    frame: &mut Frame,
+    area: Rect,
+    scorers: &[&TopScorer],
+    position: ListPosition,
+    sort: ScorerSort,
+    theme: &Theme,
+) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(BEIGE))
-        .title(" Top Scorers ")
-        .style(Style::default().bg(BG_BLACK).fg(BEIGE));
+        .border_style(Style::default().fg(theme.text))
+        .title(format!(" Top Scorers — by {} ", sort.label()))
+        .style(Style::default().bg(theme.background).fg(theme.text));
 
-    if stats.top_scorers.is_empty() {
+    if scorers.is_empty() {
         let empty = Table::new(
-            Vec::<Row>::new(), 
+            Vec::<Row>::new(),
             [Constraint::Percentage(100)]
         ).block(block);
         frame.render_widget(empty, area);
         return;
     }
 
-    let header_cells = ["Player", "Team", "Goals"]
-        .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(GOLD).add_modifier(Modifier::BOLD)));
+    let header_cells = COLUMNS.iter().map(|(label, column_sort)| {
+        let text = if *column_sort == Some(sort) {
+            format!("{}▼", label)
+        } else {
+            label.to_string()
+        };
+        Cell::from(text).style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+    });
     let header = Row::new(header_cells)
-        .style(Style::default().bg(Color::DarkGray))
+        .style(Style::default().bg(theme.selection))
         .height(1)
         .bottom_margin(1);
 
-    let rows: Vec<Row> = stats
-        .top_scorers
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+    let rows: Vec<Row> = scorers
         .iter()
         .map(|s| {
             let cells = vec![
                 Cell::from(s.player.clone()),
                 Cell::from(s.team.clone()),
                 Cell::from(s.goals.to_string()),
+                Cell::from(s.penalties.to_string()),
+                Cell::from(s.non_penalty_goals().to_string()),
+                Cell::from(or_dash(s.assists.map(|a| a.to_string()))),
+                Cell::from(or_dash(s.goal_contributions().map(|c| c.to_string()))),
+                Cell::from(or_dash(s.appearances.map(|a| a.to_string()))),
+                Cell::from(or_dash(s.goals_per_game().map(|g| format!("{:.2}", g)))),
+                Cell::from(or_dash(s.minutes_per_goal().map(|m| format!("{:.0}", m)))),
             ];
             Row::new(cells).height(1)
         })
         .collect();
 
     let widths = [
-        Constraint::Min(25),    // Player
-        Constraint::Min(25),    // Team
+        Constraint::Min(18),    // Player
+        Constraint::Min(18),    // Team
         Constraint::Length(6),  // Goals
+        Constraint::Length(5),  // Penalties
+        Constraint::Length(5),  // Non-penalty goals
+        Constraint::Length(5),  // Assists
+        Constraint::Length(5),  // Goals + assists
+        Constraint::Length(5),  // Appearances
+        Constraint::Length(6),  // Goals per game
+        Constraint::Length(6),  // Minutes per goal
     ];
 
     let table = Table::new(rows.into_iter(), widths)
         .header(header)
         .block(block)
         .row_highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(GOLD)
-                .add_modifier(Modifier::BOLD),
+            theme.selected().fg(theme.accent),
         )
         .highlight_symbol(">> ");
 
-    let mut state = TableState::default();
-    state.select(Some(selected_index));
+    let viewport = viewport(area, scorers.len());
+    let offset = viewport.follow(position.offset, position.selected);
+    let mut state = TableState::default().with_offset(offset);
+    state.select(Some(position.selected));
 
     frame.render_stateful_widget(table, area, &mut state);
+    scrollbar::render(frame, area.inner(Margin::new(0, 1)), &viewport, offset, theme);
 }
@@ -1,20 +1,90 @@
-use foot_info_core::models::LeagueStats;
-use crate::ui::theme::{BEIGE, BG_BLACK, GOLD};
+use foot_info_core::models::{LeagueStats, StandingRow, Zone, ZoneKind};
+use crate::models::TableSort;
+use crate::scroll::{ListPosition, Viewport};
+use crate::ui::components::scrollbar;
+use crate::ui::theme::Theme;
 use ratatui::{
     Frame,
-    layout::{Constraint, Rect},
+    layout::{Constraint, Margin, Rect},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
 };
 
-pub fn draw(frame: &mut Frame, area: Rect, stats: &LeagueStats, selected_index: usize) {
-    let block = Block::default()
+const QUALIFICATION_COLORS: [Color; 4] = [Color::Green, Color::Blue, Color::Magenta, Color::LightCyan];
+
+/// Assigns a colour to each zone. Several qualification zones (e.g. Champions
+/// League and Europa League) get distinct colours, in table order.
+pub fn zone_colors<'a>(zones: &[&'a Zone]) -> Vec<(&'a Zone, Color)> {
+    let mut qualification_count = 0;
+    zones
+        .iter()
+        .map(|zone| {
+            let color = match zone.kind {
+                ZoneKind::Promotion => Color::LightGreen,
+                ZoneKind::Playoff => Color::Cyan,
+                ZoneKind::Relegation => Color::Red,
+                ZoneKind::Qualification => {
+                    let c = QUALIFICATION_COLORS[qualification_count % QUALIFICATION_COLORS.len()];
+                    qualification_count += 1;
+                    c
+                }
+            };
+            (*zone, color)
+        })
+        .collect()
+}
+
+/// Header label and the sort it corresponds to, if any.
+const COLUMNS: [(&str, Option<TableSort>); 9] = [
+    ("Pos", Some(TableSort::Position)),
+    ("Team", None),
+    ("P", None),
+    ("W", None),
+    ("D", None),
+    ("L", None),
+    ("GD", Some(TableSort::GoalDifference)),
+    ("Pts", None),
+    ("Form", Some(TableSort::Form)),
+];
+
+/// `rows` rows as laid out in `area`, under the border and the header
+/// with its blank line. The top scorers are laid out alike.
+pub fn viewport(area: Rect, rows: usize) -> Viewport {
+    let inner = Block::bordered().inner(area);
+    let body = Rect { y: inner.y + 2, height: inner.height.saturating_sub(2), ..inner };
+    Viewport::new(body, vec![1; rows])
+}
+
+/// Draws `rows`, the table as filtered and sorted; `stats` gives the
+/// zones of the legend.
+pub fn draw(
+    frame: &mut Frame,
+    area: Rect,
+    stats: &LeagueStats,
+    rows: &[&StandingRow],
+    position: ListPosition,
+    sort: TableSort,
+    theme: &Theme,
+) {
+    let mut block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(BEIGE))
+        .border_style(Style::default().fg(theme.text))
         .title(" League Table ")
-        .style(Style::default().bg(BG_BLACK).fg(BEIGE));
+        .style(Style::default().bg(theme.background).fg(theme.text));
 
-    if stats.table.is_empty() {
+    // Legend of the zones present in the table
+    let colors = zone_colors(&stats.zones());
+    if !colors.is_empty() {
+        let mut legend = vec![Span::raw(" ")];
+        for (zone, color) in &colors {
+            legend.push(Span::styled("■ ", Style::default().fg(theme.fixed(*color))));
+            legend.push(Span::raw(format!("{} ", zone.label)));
+        }
+        block = block.title_bottom(Line::from(legend));
+    }
+
+    if rows.is_empty() {
         let empty = Table::new(
             Vec::<Row>::new(), 
             [Constraint::Percentage(100)]
@@ -23,16 +93,21 @@ pub fn draw(frame: &mut Frame, area: Rect, stats: &LeagueStats, selected_index:
         return;
     }
 
-    let header_cells = ["Pos", "Team", "P", "W", "D", "L", "GD", "Pts"]
-        .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(GOLD).add_modifier(Modifier::BOLD)));
+    let header_cells = COLUMNS.iter().map(|(label, column_sort)| {
+        let text = if *column_sort == Some(sort) {
+            format!("{}▼", label)
+        } else {
+            label.to_string()
+        };
+        Cell::from(text).style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+    });
     let header = Row::new(header_cells)
-        .style(Style::default().bg(Color::DarkGray))
+        .style(Style::default().bg(theme.selection))
         .height(1)
         .bottom_margin(1);
 
-    let rows: Vec<Row> = stats
-        .table
+    let viewport = viewport(area, rows.len());
+    let rows: Vec<Row> = rows
         .iter()
         .map(|s| {
             let cells = vec![
@@ -44,8 +119,13 @@ pub fn draw(frame: &mut Frame, area: Rect, stats: &LeagueStats, selected_index:
                 Cell::from(s.lost.to_string()),
                 Cell::from(s.goal_diff.to_string()),
                 Cell::from(s.points.to_string()),
+                Cell::from(s.form.iter().collect::<String>()),
             ];
-            Row::new(cells).height(1)
+            let row = Row::new(cells).height(1);
+            match colors.iter().find(|(z, _)| Some(*z) == s.zone.as_ref()) {
+                Some((_, color)) => row.style(Style::default().fg(theme.fixed(*color))),
+                None => row,
+            }
         })
         .collect();
 
@@ -58,21 +138,21 @@ pub fn draw(frame: &mut Frame, area: Rect, stats: &LeagueStats, selected_index:
         Constraint::Length(4),  // Lost
         Constraint::Length(5),  // GD
         Constraint::Length(5),  // Pts
+        Constraint::Length(7),  // Form
     ];
 
     let table = Table::new(rows.into_iter(), widths)
         .header(header)
         .block(block)
         .row_highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(GOLD)
-                .add_modifier(Modifier::BOLD),
+            theme.selected().fg(theme.accent),
         )
         .highlight_symbol(">> ");
 
-    let mut state = TableState::default();
-    state.select(Some(selected_index));
+    let offset = viewport.follow(position.offset, position.selected);
+    let mut state = TableState::default().with_offset(offset);
+    state.select(Some(position.selected));
 
     frame.render_stateful_widget(table, area, &mut state);
+    scrollbar::render(frame, area.inner(Margin::new(0, 1)), &viewport, offset, theme);
 }
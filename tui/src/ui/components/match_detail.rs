@@ -0,0 +1,100 @@
+use crate::ui::theme::Theme;
+use chrono_tz::Tz;
+use foot_info_core::models::Match;
+use foot_info_core::utils::channels::ChannelKind;
+use foot_info_core::utils::format::KickoffFormat;
+use foot_info_core::utils::time::DisplayZone;
+use ratatui::layout::Rect;
+use ratatui::{
+    Frame,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// A search result as its detail popup shows it.
+pub struct MatchDetail<'a> {
+    pub listing: &'a Match,
+    /// The user's format, in their configured zone.
+    pub format: KickoffFormat,
+    /// Zone the listing's site uses.
+    pub source: Tz,
+    /// Page the listing was scraped from.
+    pub url: Option<String>,
+}
+
+/// Renders the details of one match in a box centred over `area`: both
+/// teams in full, the competition, the kickoff in the user's and the
+/// site's zones, every channel with its kind, and where it was listed.
+pub fn render(frame: &mut Frame, area: Rect, detail: &MatchDetail, theme: &Theme) {
+    let listing = detail.listing;
+    let label = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+    let field = |name: &str, value: String| {
+        Line::from(vec![Span::styled(format!(" {:<12}", name), label), Span::raw(value)])
+    };
+
+    let mut lines = match listing.sides() {
+        Some((home, away)) => vec![field("Home", home.to_string()), field("Away", away.to_string())],
+        None => vec![field("Teams", listing.teams.clone())],
+    };
+    lines.push(field("Competition", listing.competition.clone()));
+
+    match listing.kickoff {
+        Some(kickoff) => {
+            let local = detail.format;
+            let source = KickoffFormat { zone: DisplayZone::Source, ..local };
+            for (name, format) in [("Kickoff", local), ("Listed as", source)] {
+                let at = format.zone.convert(&kickoff, detail.source);
+                let zone = match format.zone {
+                    DisplayZone::Source => detail.source.name().to_string(),
+                    zone => zone.label(),
+                };
+                lines.push(field(
+                    name,
+                    format!(
+                        "{} {} (UTC{}, {})",
+                        format.date(kickoff, detail.source),
+                        format.time(kickoff, detail.source),
+                        at.format("%:z"),
+                        zone
+                    ),
+                ));
+            }
+        }
+        None => lines.push(field("Kickoff", format!("{} {} (as listed)", listing.date, listing.time))),
+    }
+
+    lines.push(Line::raw(""));
+    if listing.channels.is_empty() {
+        lines.push(field("Channels", "No TV info".to_string()));
+    }
+    for (i, channel) in listing.channels.iter().enumerate() {
+        let name = if i == 0 { "Channels" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {:<12}", name), label),
+            Span::styled(channel.as_str(), Style::default().fg(theme.info)),
+            Span::styled(format!("  {}", ChannelKind::of(channel).label()), Style::default().fg(theme.muted)),
+        ]));
+    }
+    if let Some(url) = &detail.url {
+        lines.push(Line::raw(""));
+        lines.push(field("Source", url.clone()));
+    }
+
+    let width = area.width.min(80);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .title(format!(" {} ", listing.teams))
+        .style(Style::default().bg(theme.background).fg(theme.text));
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: false }), popup);
+}
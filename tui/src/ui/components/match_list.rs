@@ -1,21 +1,44 @@
+use crate::scroll::{ListPosition, Viewport};
+use crate::ui::changes;
+use crate::ui::components::scrollbar;
+use crate::ui::kickoff::{self, KickoffLabels};
 use crate::ui::layout;
-use crate::ui::theme::{BEIGE, GOLD, RUST_ORANGE};
-use foot_info_core::models::Match;
+use crate::ui::theme::Theme;
+use foot_info_core::changes::{match_key, Change};
+use foot_info_core::models::{Match, MatchStatus};
 use ratatui::layout::Rect;
 use ratatui::{
     layout::Alignment,
     style::{Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-/// Renders the results area: loading spinner, error message, or match list.
-pub fn render(frame: &mut Frame, area: Rect, state: &ResultsState) {
+/// Lines each result takes: teams, kickoff, channels and a blank line.
+const ITEM_HEIGHT: u16 = 4;
+
+/// The results as laid out in `area`, the whole results section.
+pub fn viewport(area: Rect, matches: &[Match]) -> Viewport {
+    Viewport::new(layout::results_horizontal(area)[1], vec![ITEM_HEIGHT; matches.len()])
+}
+
+/// Renders the results area: loading spinner, error message, or match list,
+/// marking matches among `changes` since the last look and highlighting
+/// and scrolling to the selected one at `position`, if any.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    state: &ResultsState,
+    labels: &KickoffLabels,
+    changes: &[Change],
+    position: Option<ListPosition>,
+    theme: &Theme,
+) {
     match state {
         ResultsState::Loading => {
             let loading = Paragraph::new("Scraping matches... please wait.")
-                .style(Style::default().fg(GOLD).add_modifier(Modifier::BOLD))
+                .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
                 .alignment(Alignment::Center)
                 .block(Block::default().borders(Borders::NONE));
             frame.render_widget(loading, area);
@@ -24,7 +47,7 @@ pub fn render(frame: &mut Frame, area: Rect, state: &ResultsState) {
             let error_msg = Paragraph::new(format!("Error: {}", err))
                 .style(
                     Style::default()
-                        .fg(RUST_ORANGE)
+                        .fg(theme.warning)
                         .add_modifier(Modifier::BOLD),
                 )
                 .alignment(Alignment::Center)
@@ -35,21 +58,33 @@ pub fn render(frame: &mut Frame, area: Rect, state: &ResultsState) {
             let items: Vec<ListItem> = matches
                 .iter()
                 .map(|m| {
-                    let header = Line::from(vec![
+                    let mut header_spans = vec![
                         Span::styled(
                             format!(" {} ", m.teams),
-                            Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
+                            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
                         ),
                         Span::raw(" - "),
-                        Span::styled(&m.competition, Style::default().fg(BEIGE)),
-                    ]);
+                        Span::styled(&m.competition, Style::default().fg(theme.text)),
+                    ];
+                    header_spans.extend(changes::marker(changes, &match_key(m), theme));
+                    let header = Line::from(header_spans);
 
-                    let time_info = Line::from(vec![
+                    // Broadcaster listings carry no status, so it's
+                    // inferred from the kickoff.
+                    let countdown = labels.countdown(m.kickoff, MatchStatus::Upcoming);
+                    let mut time_spans = vec![
                         Span::raw(" 📅 "),
-                        Span::raw(&m.date),
+                        Span::raw(labels.day(m.kickoff, &m.date)),
                         Span::raw(" ⏰ "),
-                        Span::raw(&m.time),
-                    ]);
+                        Span::raw(labels.time(m.kickoff, &m.time)),
+                    ];
+                    if let Some(countdown) = countdown {
+                        time_spans.push(Span::styled(
+                            format!("  ⏳ {}", labels.countdown_label(countdown)),
+                            kickoff::countdown_style(countdown, theme),
+                        ));
+                    }
+                    let time_info = Line::from(time_spans);
 
                     let channels = if m.channels.is_empty() {
                         "No TV info".to_string()
@@ -58,22 +93,28 @@ pub fn render(frame: &mut Frame, area: Rect, state: &ResultsState) {
                     };
 
                     let channel_info = Line::from(vec![
-                        Span::styled(" 📺 ", Style::default().fg(RUST_ORANGE)),
+                        Span::styled(" 📺 ", Style::default().fg(theme.warning)),
                         Span::raw(channels),
                     ]);
 
                     let content = Text::from(vec![header, time_info, channel_info, Line::raw("")]);
 
-                    ListItem::new(content)
+                    ListItem::new(content).style(kickoff::imminent_style(countdown, theme))
                 })
                 .collect();
 
             let list = List::new(items)
                 .block(Block::default().borders(Borders::NONE))
-                .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+                .highlight_style(theme.selected())
+                .highlight_symbol(">> ");
 
-            let cols = layout::results_horizontal(area);
-            frame.render_widget(list, cols[1]);
+            let viewport = viewport(area, matches);
+            let offset = position.map_or(0, |p| viewport.follow(p.offset, p.selected));
+            let mut list_state = ListState::default()
+                .with_offset(offset)
+                .with_selected(position.map(|p| p.selected));
+            frame.render_stateful_widget(list, viewport.area, &mut list_state);
+            scrollbar::render(frame, viewport.area, &viewport, offset, theme);
         }
         ResultsState::Empty => {}
     }
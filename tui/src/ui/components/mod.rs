@@ -1,7 +1,13 @@
+pub mod command_palette;
+pub mod favorites_dashboard;
+pub mod help_overlay;
 pub mod league_fixtures;
 pub mod league_scorers;
 pub mod league_table;
+pub mod match_detail;
 pub mod match_list;
+pub mod scrollbar;
 pub mod search_bar;
+pub mod search_suggestions;
 pub mod status_bar;
 pub mod top_matches_list;
@@ -0,0 +1,25 @@
+use crate::scroll::Viewport;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+
+/// Draws a scrollbar down `area` (usually a list's right border) when the
+/// rows of `viewport` don't all fit, its thumb where `offset` is.
+pub fn render(frame: &mut Frame, area: Rect, viewport: &Viewport, offset: usize, theme: &Theme) {
+    if viewport.fits() {
+        return;
+    }
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .thumb_style(Style::default().fg(theme.accent))
+        .track_style(Style::default().fg(theme.muted));
+    let mut state = ScrollbarState::new(viewport.max_offset() + 1)
+        .position(offset)
+        .viewport_content_length(viewport.visible(offset));
+    frame.render_stateful_widget(scrollbar, area, &mut state);
+}
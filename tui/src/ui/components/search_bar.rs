@@ -1,4 +1,5 @@
-use crate::ui::theme::{BEIGE, BG_BLACK, GOLD};
+use crate::models::InputMode;
+use crate::ui::theme::Theme;
 use ratatui::layout::Rect;
 use ratatui::{
     Frame,
@@ -8,23 +9,31 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-/// Renders the search input bar.
-pub fn render(frame: &mut Frame, area: Rect, search_input: &str) {
+/// Renders the search input bar, titled with the vim `mode` when there
+/// is one.
+pub fn render(frame: &mut Frame, area: Rect, search_input: &str, mode: Option<InputMode>, theme: &Theme) {
     let input_text = Line::from(vec![
         Span::styled(
             "Enter Team: ",
-            Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
         ),
         Span::raw(search_input),
     ]);
 
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.text))
+        .style(Style::default().bg(theme.background));
+    if let Some(mode) = mode {
+        let label = match mode {
+            InputMode::Insert => " -- INSERT -- ",
+            InputMode::Normal => " -- NORMAL -- ",
+        };
+        block = block.title(Span::styled(label, Style::default().fg(theme.accent)));
+    }
+
     let input_paragraph = Paragraph::new(input_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(BEIGE))
-                .style(Style::default().bg(BG_BLACK)),
-        )
+        .block(block)
         .alignment(Alignment::Center);
 
     frame.render_widget(input_paragraph, area);
@@ -0,0 +1,51 @@
+use crate::suggest::Suggestion;
+use crate::ui::theme::Theme;
+use ratatui::layout::Rect;
+use ratatui::{
+    Frame,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+/// Renders `suggestions` in a dropdown just under the search bar at
+/// `input`, each tagged with where it comes from, within `area`.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    input: Rect,
+    suggestions: &[Suggestion],
+    selected: Option<usize>,
+    theme: &Theme,
+) {
+    let top = input.bottom();
+    if suggestions.is_empty() || top >= area.bottom() {
+        return;
+    }
+    let height = (suggestions.len() as u16 + 2).min(area.bottom() - top);
+    let popup = Rect::new(input.x, top, input.width, height);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .title(" Tab to complete ")
+        .style(Style::default().bg(theme.background).fg(theme.text));
+
+    let items: Vec<ListItem> = suggestions
+        .iter()
+        .map(|suggestion| {
+            ListItem::new(Line::from(vec![
+                Span::styled(suggestion.team.as_str(), Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  {}", suggestion.source.label()), Style::default().fg(theme.muted)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme.selected())
+        .highlight_symbol(">> ");
+    let mut list_state = ListState::default().with_selected(selected);
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut list_state);
+}
@@ -1,12 +1,12 @@
-use crate::ui::theme::GOLD;
+use crate::ui::theme::Theme;
 use ratatui::layout::Rect;
 use ratatui::{Frame, layout::Alignment, style::Style, widgets::Paragraph};
 
 /// Renders an optional status message (e.g. "Saved favorite: Arsenal").
-pub fn render(frame: &mut Frame, area: Rect, message: Option<&str>) {
+pub fn render(frame: &mut Frame, area: Rect, message: Option<&str>, theme: &Theme) {
     if let Some(msg) = message {
         let status_text = Paragraph::new(msg.to_string())
-            .style(Style::default().fg(GOLD))
+            .style(Style::default().fg(theme.accent))
             .alignment(Alignment::Center);
         frame.render_widget(status_text, area);
     }
@@ -1,20 +1,23 @@
-use crate::ui::theme::{BEIGE, GOLD, RUST_ORANGE};
-use foot_info_core::models::TopMatch;
+use crate::scroll::{ListPosition, Viewport};
+use crate::ui::components::scrollbar;
+use crate::ui::kickoff::{self, KickoffLabels};
+use crate::ui::theme::Theme;
+use foot_info_core::models::{MatchStatus, TopMatch};
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 use std::collections::BTreeMap;
 
 /// Renders the top matches grouped by date in side-by-side columns.
-pub fn render(frame: &mut Frame, area: Rect, state: &TopMatchesState) {
+pub fn render(frame: &mut Frame, area: Rect, state: &TopMatchesState, labels: &KickoffLabels, theme: &Theme) {
     match state {
         TopMatchesState::Loading => {
             let loading = Paragraph::new("Fetching upcoming top matches... please wait.")
-                .style(Style::default().fg(GOLD).add_modifier(Modifier::BOLD))
+                .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
                 .alignment(Alignment::Center)
                 .block(Block::default().borders(Borders::NONE));
             frame.render_widget(loading, area);
@@ -23,42 +26,50 @@ pub fn render(frame: &mut Frame, area: Rect, state: &TopMatchesState) {
             let error_msg = Paragraph::new(format!("Error: {}", err))
                 .style(
                     Style::default()
-                        .fg(RUST_ORANGE)
+                        .fg(theme.warning)
                         .add_modifier(Modifier::BOLD),
                 )
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true });
             frame.render_widget(error_msg, area);
         }
-        TopMatchesState::Matches {
-            matches,
-            selected_index,
-        } => {
-            render_columns(frame, area, matches, *selected_index);
+        TopMatchesState::Matches { matches, position } => {
+            render_columns(frame, area, matches, *position, labels, theme);
         }
         TopMatchesState::Empty => {}
     }
 }
 
-/// Groups matches by date and renders them in responsive columns (max 3 per row).
-fn render_columns(frame: &mut Frame, area: Rect, matches: &[TopMatch], selected_index: usize) {
+/// One day of matches, and where its column goes.
+struct DayColumn<'a> {
+    date: String,
+    /// Each match with its index in the whole list.
+    matches: Vec<(usize, &'a TopMatch)>,
+    area: Rect,
+    /// Neighbouring columns share a border.
+    borders: Borders,
+}
+
+/// Groups matches by date into responsive columns (max 3 per row).
+fn columns<'a>(area: Rect, matches: &'a [TopMatch], labels: &KickoffLabels) -> Vec<DayColumn<'a>> {
     // Group matches by date, preserving insertion order
     let mut groups: Vec<(String, Vec<(usize, &TopMatch)>)> = Vec::new();
     let mut seen_dates: BTreeMap<String, usize> = BTreeMap::new();
 
     for (i, m) in matches.iter().enumerate() {
-        if let Some(&group_idx) = seen_dates.get(&m.date) {
+        let date = labels.date(m.kickoff, &m.date);
+        if let Some(&group_idx) = seen_dates.get(&date) {
             groups[group_idx].1.push((i, m));
         } else {
             let idx = groups.len();
-            seen_dates.insert(m.date.clone(), idx);
-            groups.push((m.date.clone(), vec![(i, m)]));
+            seen_dates.insert(date.clone(), idx);
+            groups.push((date, vec![(i, m)]));
         }
     }
 
     let num_groups = groups.len();
     if num_groups == 0 {
-        return;
+        return Vec::new();
     }
 
     // Determine how many columns we can fit per row (targeting 3, falling back to 2 or 1 if extremely narrow)
@@ -71,44 +82,14 @@ fn render_columns(frame: &mut Frame, area: Rect, matches: &[TopMatch], selected_
     let num_rows = (num_groups + cols_per_row - 1) / cols_per_row;
     let row_constraints = vec![Constraint::Ratio(1, num_rows as u32); num_rows];
     let row_areas = Layout::vertical(row_constraints).split(area);
+    let col_constraints = vec![Constraint::Ratio(1, cols_per_row as u32); cols_per_row];
 
-    // Chunk the groups and render each chunk into a row area
-    for (row_idx, chunk) in groups.chunks(cols_per_row).enumerate() {
-        let current_row_area = row_areas[row_idx];
-
-        let col_constraints = vec![Constraint::Ratio(1, cols_per_row as u32); cols_per_row];
-        let col_areas = Layout::horizontal(col_constraints).split(current_row_area);
-
-        for (col_idx, (date, day_matches)) in chunk.iter().enumerate() {
-            let col_area = col_areas[col_idx];
-
-            let mut items: Vec<ListItem> = Vec::new();
-
-            for (global_idx, m) in day_matches {
-                let is_selected = *global_idx == selected_index;
-                let marker = if is_selected { " ▸ " } else { "   " };
-                let header_style = if is_selected {
-                    Style::default().fg(GOLD).add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(BEIGE)
-                };
-
-                let header = Line::from(vec![
-                    Span::styled(marker, header_style),
-                    Span::styled(&m.teams, header_style),
-                ]);
-
-                let time_line = Line::from(vec![
-                    Span::raw("   ⏰ "),
-                    Span::styled(&m.time, Style::default().fg(BEIGE)),
-                ]);
-
-                items.push(ListItem::new(Text::from(vec![
-                    header,
-                    time_line,
-                    Line::raw(""),
-                ])));
-            }
+    groups
+        .into_iter()
+        .enumerate()
+        .map(|(i, (date, day_matches))| {
+            let (row_idx, col_idx) = (i / cols_per_row, i % cols_per_row);
+            let col_areas = Layout::horizontal(col_constraints.clone()).split(row_areas[row_idx]);
             let mut borders = Borders::ALL;
             if col_idx > 0 {
                 borders.remove(Borders::LEFT);
@@ -116,30 +97,156 @@ fn render_columns(frame: &mut Frame, area: Rect, matches: &[TopMatch], selected_
             if row_idx > 0 {
                 borders.remove(Borders::TOP);
             }
+            DayColumn { date, matches: day_matches, area: col_areas[col_idx], borders }
+        })
+        .collect()
+}
+
+/// The column's frame: its borders and the date on top.
+fn column_block(column: &DayColumn) -> Block<'static> {
+    Block::default()
+        .borders(column.borders)
+        .title(format!(" 📅 {} ", column.date))
+        .title_alignment(Alignment::Center)
+}
+
+/// Lines a match card takes: teams, kickoff, competition and channels if
+/// known, and a blank line.
+fn card_height(m: &TopMatch) -> u16 {
+    3 + u16::from(m.competition.is_some()) + u16::from(!m.channels.is_empty())
+}
+
+fn column_viewport(column: &DayColumn) -> Viewport {
+    Viewport {
+        area: column_block(column).inner(column.area),
+        heights: column.matches.iter().map(|(_, m)| card_height(m)).collect(),
+        indices: column.matches.iter().map(|(i, _)| Some(*i)).collect(),
+    }
+}
+
+/// Every day column as laid out in `area`; each one's rows select matches
+/// by their index in `matches`.
+pub fn viewports(area: Rect, matches: &[TopMatch], labels: &KickoffLabels) -> Vec<Viewport> {
+    columns(area, matches, labels).iter().map(column_viewport).collect()
+}
+
+/// The day column of the `selected` match, which is the one that scrolls.
+pub fn viewport(area: Rect, matches: &[TopMatch], selected: usize, labels: &KickoffLabels) -> Option<Viewport> {
+    viewports(area, matches, labels).into_iter().find(|v| v.row_of(selected).is_some())
+}
+
+fn render_columns(
+    frame: &mut Frame,
+    area: Rect,
+    matches: &[TopMatch],
+    position: ListPosition,
+    labels: &KickoffLabels,
+    theme: &Theme,
+) {
+    let selected_index = position.selected;
+    for column in columns(area, matches, labels) {
+        let mut items: Vec<ListItem> = Vec::new();
+
+        for (global_idx, m) in &column.matches {
+            let is_selected = *global_idx == selected_index;
+            let marker = if is_selected { " ▸ " } else { "   " };
+            let header_style = if is_selected {
+                Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
 
-            let list = List::new(items).block(
-                Block::default()
-                    .borders(borders)
-                    .border_style(Style::default().fg(BEIGE))
-                    .title(Span::styled(
-                        format!(" 📅 {} ", date),
-                        Style::default()
-                            .fg(RUST_ORANGE)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                    .title_alignment(Alignment::Center),
-            );
-
-            frame.render_widget(list, col_area);
+            let header = Line::from(vec![
+                Span::styled(marker, header_style),
+                Span::styled(&m.teams, header_style),
+            ]);
+
+            let mut time_spans = vec![
+                Span::raw("   ⏰ "),
+                Span::styled(labels.time(m.kickoff, &m.time), Style::default().fg(theme.text)),
+            ];
+            let countdown = labels.countdown(m.kickoff, m.status);
+            if let Some(countdown) = countdown {
+                time_spans.push(Span::styled(
+                    format!("  {}", labels.countdown_label(countdown)),
+                    kickoff::countdown_style(countdown, theme),
+                ));
+            } else if m.status != MatchStatus::Upcoming {
+                time_spans.push(Span::styled(
+                    format!("  {}", m.status.label()),
+                    status_style(m.status, theme),
+                ));
+            }
+
+            let mut lines = vec![header, Line::from(time_spans)];
+            if let Some(competition) = &m.competition {
+                lines.push(Line::from(vec![
+                    Span::raw("   🏆 "),
+                    Span::styled(competition, Style::default().fg(theme.muted)),
+                ]));
+            }
+            if !m.channels.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::raw("   📺 "),
+                    Span::styled(channel_summary(&m.channels), Style::default().fg(theme.info)),
+                ]));
+            }
+            lines.push(Line::raw(""));
+
+            items.push(ListItem::new(Text::from(lines)).style(kickoff::imminent_style(countdown, theme)));
         }
+
+        let list = List::new(items).block(
+            column_block(&column)
+                .border_style(Style::default().fg(theme.text))
+                .title_style(Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+        );
+
+        // A full day's schedule can outgrow the column, so the selected
+        // match's column keeps it scrolled into view.
+        let viewport = column_viewport(&column);
+        let selected_row = column.matches.iter().position(|(i, _)| *i == selected_index);
+        let offset = match selected_row {
+            Some(_) => viewport.follow(position.offset, selected_index),
+            None => 0,
+        };
+        let mut list_state = ListState::default().with_offset(offset).with_selected(selected_row);
+        frame.render_stateful_widget(list, column.area, &mut list_state);
+
+        let track = Rect { x: column.area.right().saturating_sub(1), width: 1, ..viewport.area };
+        scrollbar::render(frame, track, &viewport, offset, theme);
+    }
+}
+
+fn status_style(status: MatchStatus, theme: &Theme) -> Style {
+    match status {
+        MatchStatus::Live => Style::default().fg(theme.live).add_modifier(Modifier::BOLD),
+        MatchStatus::Postponed => Style::default().fg(theme.warning),
+        _ => Style::default().fg(theme.muted),
     }
 }
+
+/// First few channels, then "+N" for the rest so the column stays narrow.
+fn channel_summary(channels: &[String]) -> String {
+    const SHOWN: usize = 2;
+    let mut summary = channels
+        .iter()
+        .take(SHOWN)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if channels.len() > SHOWN {
+        summary.push_str(&format!(" +{}", channels.len() - SHOWN));
+    }
+    summary
+}
+
 pub enum TopMatchesState<'a> {
     Loading,
     Error(&'a str),
     Matches {
         matches: &'a [TopMatch],
-        selected_index: usize,
+        position: ListPosition,
     },
     Empty,
 }
@@ -0,0 +1,80 @@
+use crate::ui::theme::Theme;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use foot_info_core::providers::livesoccertv;
+use foot_info_core::models::MatchStatus;
+use foot_info_core::utils::format::{Countdown, KickoffFormat};
+use ratatui::style::{Modifier, Style};
+
+/// Everything needed to label kickoffs in one frame: the user's format,
+/// the zone the listing's site uses, and the instant "today" is relative to.
+#[derive(Debug, Clone, Copy)]
+pub struct KickoffLabels {
+    pub format: KickoffFormat,
+    pub source: Tz,
+    pub now: DateTime<Utc>,
+}
+
+impl KickoffLabels {
+    pub fn new(format: KickoffFormat, source: Tz, now: DateTime<Utc>) -> Self {
+        Self { format, source, now }
+    }
+
+    /// "Tonight", "Tomorrow", ... or the full date. Listings whose kickoff
+    /// couldn't be parsed keep the site's own text.
+    pub fn day(&self, kickoff: Option<DateTime<Utc>>, fallback: &str) -> String {
+        kickoff
+            .map(|k| self.format.day(k, self.source, self.now))
+            .unwrap_or_else(|| fallback.to_string())
+    }
+
+    /// Full date, for grouping and headers.
+    pub fn date(&self, kickoff: Option<DateTime<Utc>>, fallback: &str) -> String {
+        kickoff
+            .map(|k| self.format.date(k, self.source))
+            .unwrap_or_else(|| fallback.to_string())
+    }
+
+    pub fn time(&self, kickoff: Option<DateTime<Utc>>, fallback: &str) -> String {
+        kickoff
+            .map(|k| self.format.time(k, self.source))
+            .unwrap_or_else(|| fallback.to_string())
+    }
+
+    /// Where the match stands relative to `now`; `None` when the kickoff
+    /// couldn't be parsed.
+    pub fn countdown(&self, kickoff: Option<DateTime<Utc>>, status: MatchStatus) -> Option<Countdown> {
+        kickoff.map(|k| Countdown::at(k, self.now, status))
+    }
+
+    pub fn countdown_label(&self, countdown: Countdown) -> String {
+        countdown.label(self.format.locale)
+    }
+}
+
+impl Default for KickoffLabels {
+    fn default() -> Self {
+        Self::new(KickoffFormat::default(), livesoccertv::SOURCE_ZONE, Utc::now())
+    }
+}
+
+pub fn countdown_style(countdown: Countdown, theme: &Theme) -> Style {
+    match countdown {
+        Countdown::Live => Style::default().fg(theme.live).add_modifier(Modifier::BOLD),
+        Countdown::Postponed => Style::default().fg(theme.warning),
+        Countdown::Finished(_) => Style::default().fg(theme.muted),
+        Countdown::Upcoming(_) if countdown.is_imminent() => {
+            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)
+        }
+        Countdown::Upcoming(_) => Style::default().fg(theme.text),
+    }
+}
+
+/// Background for whole list items kicking off within the hour.
+pub fn imminent_style(countdown: Option<Countdown>, theme: &Theme) -> Style {
+    if countdown.is_some_and(|c| c.is_imminent()) {
+        Style::default().bg(theme.soon)
+    } else {
+        Style::default()
+    }
+}
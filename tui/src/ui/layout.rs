@@ -1,4 +1,10 @@
 use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::widgets::Block;
+
+/// Inside the main block's border, where the views are drawn.
+pub fn main_inner(area: Rect) -> Rect {
+    Block::bordered().inner(area)
+}
 
 /// Main vertical layout: Input (3 rows) | Status (1 row) | Results (rest).
 pub fn main_vertical(area: Rect) -> Vec<Rect> {
@@ -32,3 +38,14 @@ pub fn results_horizontal(area: Rect) -> Vec<Rect> {
     .split(area)
     .to_vec()
 }
+
+/// League view layout: Tabs (3 rows) | Content (rest) | Status (1 row).
+pub fn league_vertical(area: Rect) -> Vec<Rect> {
+    Layout::vertical([
+        Constraint::Length(3), // Tabs
+        Constraint::Min(0),    // Content
+        Constraint::Length(1), // Status
+    ])
+    .split(area)
+    .to_vec()
+}
@@ -1,7 +1,9 @@
+pub mod changes;
 pub mod components;
+pub mod kickoff;
 pub mod layout;
 pub mod render;
 pub mod theme;
 pub mod views;
 
-pub use render::draw;
+pub use render::{draw, hit, viewport, Hit};
@@ -1,7 +1,12 @@
-use crate::models::ViewMode;
+use crate::keymap::KeyAction;
+use crate::models::{LeagueTab, ViewMode};
+use crate::scroll::Viewport;
 use crate::state::AppState;
-use crate::ui::theme::{BEIGE, BG_BLACK, GOLD, RUST_ORANGE};
-use crate::ui::views;
+use crate::ui::components::match_detail::{self, MatchDetail};
+use crate::ui::components::{command_palette, help_overlay};
+use crate::ui::theme::Theme;
+use crate::ui::{layout, views};
+use foot_info_core::utils::format::KickoffFormat;
 use ratatui::{
     Frame,
     layout::Alignment,
@@ -13,122 +18,117 @@ use ratatui::{
 /// Main draw function — composes all UI components onto the frame.
 pub fn draw(frame: &mut Frame, app: &AppState) {
     let area = frame.area();
+    let theme = &app.theme;
 
-    // Instructions bar (changes based on mode)
-    let instructions = match app.view_mode {
-        ViewMode::Search => Line::from(vec![
-            Span::raw(" Quit "),
-            Span::styled(
-                "<Esc> ",
-                Style::default()
-                    .fg(RUST_ORANGE)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Search "),
-            Span::styled(
-                "<Enter> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Save Fav "),
-            Span::styled(
-                "<Ctrl+s> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Load Fav "),
-            Span::styled(
-                "<Ctrl+f> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Switch Country "),
-            Span::styled(
-                "<Ctrl+c> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Top Matches "),
-            Span::styled(
-                "<Ctrl+t> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-        ]),
-        ViewMode::TopMatches => Line::from(vec![
-            Span::raw(" Back "),
-            Span::styled(
-                "<Esc> ",
-                Style::default()
-                    .fg(RUST_ORANGE)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Select Match "),
-            Span::styled(
-                "<Enter> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Navigate "),
-            Span::styled(
-                "<↑/↓/←/→> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Switch Country "),
-            Span::styled(
-                "<Ctrl+c> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-        ]),
-        ViewMode::League => Line::from(vec![
-            Span::raw(" Back "),
-            Span::styled(
-                "<Esc> ",
-                Style::default()
-                    .fg(RUST_ORANGE)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Navigate "),
-            Span::styled(
-                "<↑/↓> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Tabs "),
-            Span::styled(
-                "<Tab/Shift+Tab> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Refresh "),
-            Span::styled(
-                "<r> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("| Search fixture "),
-            Span::styled(
-                "<Enter> ",
-                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
-            ),
-        ]),
-    };
+    // Instructions bar, from the active keymap of the current view
+    let scope = app.key_scope();
+    let instructions = instructions(&app.keymap.hints(scope), theme);
 
     let current_provider = app.get_current_provider();
+    let zone_key = app
+        .keymap
+        .keys_for(scope, KeyAction::ToggleZone)
+        .first()
+        .map(|key| format!(" <{}>", key))
+        .unwrap_or_default();
     let title = format!(
-        " FOOTBALL MATCH CHANNELS INFO [{}] ",
-        current_provider.country()
+        " FOOTBALL MATCH CHANNELS INFO [{}] · {}{} ",
+        current_provider.country(),
+        app.display_zone().label(),
+        zone_key
     );
 
     // Main block (border + title + instructions)
     let main_block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(BEIGE))
+        .border_style(Style::default().fg(theme.text))
         .title(Span::styled(
             title,
-            Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center)
         .title_bottom(instructions.centered())
-        .style(Style::default().bg(BG_BLACK).fg(BEIGE));
+        .style(Style::default().bg(theme.background).fg(theme.text));
 
     frame.render_widget(main_block.clone(), area);
-    let inner_area = main_block.inner(area);
+    let inner_area = layout::main_inner(area);
 
     match app.view_mode {
+        ViewMode::Dashboard => views::dashboard::draw(frame, inner_area, app),
         ViewMode::Search => views::search::draw(frame, inner_area, app),
         ViewMode::TopMatches => views::top_matches::draw(frame, inner_area, app),
         ViewMode::League => views::league::draw(frame, inner_area, app),
     }
+
+    if let Some(listing) = &app.match_detail {
+        let detail = MatchDetail {
+            listing,
+            format: KickoffFormat::new(app.config.locale(), app.config.hour_cycle(), app.config.display_zone()),
+            source: app.results_country().source_zone(),
+            url: app.results_source.as_ref().and_then(|source| source.url.clone()),
+        };
+        match_detail::render(frame, inner_area, &detail, theme);
+    }
+
+    if let Some(palette) = &app.palette {
+        command_palette::render(frame, inner_area, palette, &app.config.recent_commands, theme);
+    }
+
+    if app.show_help {
+        help_overlay::render(frame, inner_area, &app.keymap.help(scope), theme);
+    }
+}
+
+/// What a click landed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hit {
+    /// An item of the list on screen, drawn scrolled to `offset`.
+    Row { index: usize, offset: usize },
+    Tab(LeagueTab),
+    SearchBar,
+}
+
+/// What `draw` put at column `x`, line `y` of `app.screen`; `None` before
+/// the first resize or where there's nothing to click.
+pub fn hit(app: &AppState, x: u16, y: u16) -> Option<Hit> {
+    if app.screen.is_empty() {
+        return None;
+    }
+    let inner_area = layout::main_inner(app.screen);
+    match app.view_mode {
+        ViewMode::Dashboard => views::dashboard::hit(inner_area, app, x, y),
+        ViewMode::Search => views::search::hit(inner_area, app, x, y),
+        ViewMode::TopMatches => views::top_matches::hit(inner_area, app, x, y),
+        ViewMode::League => views::league::hit(inner_area, app, x, y),
+    }
+}
+
+/// The list of the current view as `draw` lays it out on `app.screen`;
+/// `None` before the first resize or while no list is shown.
+pub fn viewport(app: &AppState) -> Option<Viewport> {
+    if app.screen.is_empty() {
+        return None;
+    }
+    let inner_area = layout::main_inner(app.screen);
+    match app.view_mode {
+        ViewMode::Dashboard => views::dashboard::viewport(inner_area, app),
+        ViewMode::Search => views::search::viewport(inner_area, app),
+        ViewMode::TopMatches => views::top_matches::viewport(inner_area, app),
+        ViewMode::League => views::league::viewport(inner_area, app),
+    }
+}
+
+/// " Quit <Esc> | Search <Enter> | ..." — exits stand out in orange.
+fn instructions(hints: &[(&'static str, String, bool)], theme: &Theme) -> Line<'static> {
+    let mut spans = Vec::new();
+    for (i, (label, keys, exit)) in hints.iter().enumerate() {
+        let separator = if i == 0 { " " } else { "| " };
+        let color = if *exit { theme.warning } else { theme.accent };
+        spans.push(Span::raw(format!("{}{} ", separator, label)));
+        spans.push(Span::styled(
+            format!("<{}> ", keys),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+    Line::from(spans)
 }
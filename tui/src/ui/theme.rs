@@ -1,6 +1,352 @@
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
 
-pub const BG_BLACK: Color = Color::Rgb(27, 26, 23); // #1B1A17
-pub const GOLD: Color = Color::Rgb(240, 165, 0); // #F0A500
-pub const RUST_ORANGE: Color = Color::Rgb(228, 88, 38); // #E45826
-pub const BEIGE: Color = Color::Rgb(230, 213, 184); // #E6D5B8
+/// Bundled themes, in the order the palette cycles through them.
+pub const BUNDLED: [&str; 4] = ["dark", "light", "solarized", "high-contrast"];
+
+/// Colours by what they mean rather than what they are.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub background: Color,
+    /// Body text and borders.
+    pub text: Color,
+    /// Titles, team names, keys: what the eye should find first.
+    pub accent: Color,
+    /// Errors, exits and anything that changed.
+    pub warning: Color,
+    /// Background of the highlighted row.
+    pub selection: Color,
+    pub live: Color,
+    /// Secondary text: dates, competitions, finished games.
+    pub muted: Color,
+    /// Background of matches about to kick off.
+    pub soon: Color,
+    /// Channels and other extra details.
+    pub info: Color,
+    /// How many colours the terminal shows; roles are already adapted to it.
+    pub depth: ColorDepth,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".into(),
+            background: Color::Rgb(27, 26, 23),  // #1B1A17
+            text: Color::Rgb(230, 213, 184),     // #E6D5B8
+            accent: Color::Rgb(240, 165, 0),     // #F0A500
+            warning: Color::Rgb(228, 88, 38),    // #E45826
+            selection: Color::DarkGray,
+            live: Color::Green,
+            muted: Color::Gray,
+            soon: Color::Rgb(58, 46, 20),        // #3A2E14
+            info: Color::Cyan,
+            depth: ColorDepth::TrueColor,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            name: "light".into(),
+            background: Color::Rgb(250, 247, 240),
+            text: Color::Rgb(45, 42, 38),
+            accent: Color::Rgb(150, 85, 0),
+            warning: Color::Rgb(190, 50, 20),
+            selection: Color::Rgb(222, 214, 196),
+            live: Color::Rgb(0, 125, 40),
+            muted: Color::Rgb(115, 110, 100),
+            soon: Color::Rgb(250, 232, 190),
+            info: Color::Rgb(0, 105, 135),
+            depth: ColorDepth::TrueColor,
+        }
+    }
+
+    pub fn solarized() -> Self {
+        Self {
+            name: "solarized".into(),
+            background: Color::Rgb(0, 43, 54),   // base03
+            text: Color::Rgb(147, 161, 161),     // base1
+            accent: Color::Rgb(181, 137, 0),     // yellow
+            warning: Color::Rgb(203, 75, 22),    // orange
+            selection: Color::Rgb(7, 54, 66),    // base02
+            live: Color::Rgb(133, 153, 0),       // green
+            muted: Color::Rgb(101, 123, 131),    // base00
+            soon: Color::Rgb(40, 54, 30),
+            info: Color::Rgb(42, 161, 152),      // cyan
+            depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Only the 16 basic colours, so it looks the same everywhere.
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast".into(),
+            background: Color::Black,
+            text: Color::White,
+            accent: Color::LightYellow,
+            warning: Color::LightRed,
+            selection: Color::Blue,
+            live: Color::LightGreen,
+            muted: Color::Gray,
+            soon: Color::DarkGray,
+            info: Color::LightCyan,
+            depth: ColorDepth::TrueColor,
+        }
+    }
+
+    pub fn bundled(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "solarized" => Some(Self::solarized()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// The theme called `name`: `<name>.json` in `user_dir` if there is
+    /// one, otherwise a bundled theme.
+    pub fn named(name: &str, user_dir: Option<&Path>) -> Result<Self, String> {
+        if let Some(path) = user_dir.map(|dir| dir.join(format!("{}.json", name)))
+            && path.is_file()
+        {
+            return Self::from_file(&path);
+        }
+        Self::bundled(name).ok_or_else(|| format!("Unknown theme '{}'", name))
+    }
+
+    /// Reads a user theme: a JSON object of role colours ("#F0A500",
+    /// "yellow" or a 256-colour index such as "214"), starting from `base`
+    /// (a bundled theme, dark by default) for the roles it leaves out.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let text = fs::read_to_string(path).map_err(|e| format!("Can't read theme '{}': {}", name, e))?;
+        let file: ThemeFile =
+            serde_json::from_str(&text).map_err(|e| format!("Bad theme '{}': {}", name, e))?;
+
+        let base = file.base.as_deref().unwrap_or("dark");
+        let mut theme = Self::bundled(base).ok_or_else(|| format!("Theme '{}': unknown base '{}'", name, base))?;
+        theme.name = name.clone();
+        let roles = [
+            ("background", &file.background, &mut theme.background),
+            ("text", &file.text, &mut theme.text),
+            ("accent", &file.accent, &mut theme.accent),
+            ("warning", &file.warning, &mut theme.warning),
+            ("selection", &file.selection, &mut theme.selection),
+            ("live", &file.live, &mut theme.live),
+            ("muted", &file.muted, &mut theme.muted),
+            ("soon", &file.soon, &mut theme.soon),
+            ("info", &file.info, &mut theme.info),
+        ];
+        for (role, value, color) in roles {
+            if let Some(value) = value {
+                *color = Color::from_str(value)
+                    .map_err(|_| format!("Theme '{}': '{}' is not a colour for {}", name, value, role))?;
+            }
+        }
+        Ok(theme)
+    }
+
+    /// Names of the bundled themes, then of the user themes in `user_dir`.
+    pub fn available(user_dir: Option<&Path>) -> Vec<String> {
+        let mut names: Vec<String> = BUNDLED.iter().map(|name| name.to_string()).collect();
+        let mut user: Vec<String> = user_dir
+            .and_then(|dir| fs::read_dir(dir).ok())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != "json" {
+                    return None;
+                }
+                Some(path.file_stem()?.to_string_lossy().into_owned())
+            })
+            .filter(|name| !names.contains(name))
+            .collect();
+        user.sort();
+        names.append(&mut user);
+        names
+    }
+
+    /// The theme with every role brought within `depth`.
+    pub fn for_depth(self, depth: ColorDepth) -> Self {
+        Self {
+            background: depth.adapt(self.background),
+            text: depth.adapt(self.text),
+            accent: depth.adapt(self.accent),
+            warning: depth.adapt(self.warning),
+            selection: depth.adapt(self.selection),
+            live: depth.adapt(self.live),
+            muted: depth.adapt(self.muted),
+            soon: depth.adapt(self.soon),
+            info: depth.adapt(self.info),
+            depth,
+            ..self
+        }
+    }
+
+    /// The highlighted row: bold on the selection colour, or reversed when
+    /// there are no colours to highlight it with.
+    pub fn selected(&self) -> Style {
+        let style = Style::default().bg(self.selection).add_modifier(Modifier::BOLD);
+        if self.depth == ColorDepth::NoColor {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    }
+
+    /// A colour that isn't one of the roles (e.g. a table zone), brought
+    /// within the theme's depth.
+    pub fn fixed(&self, color: Color) -> Color {
+        self.depth.adapt(color)
+    }
+}
+
+#[derive(Deserialize)]
+struct ThemeFile {
+    base: Option<String>,
+    background: Option<String>,
+    text: Option<String>,
+    accent: Option<String>,
+    warning: Option<String>,
+    selection: Option<String>,
+    live: Option<String>,
+    muted: Option<String>,
+    soon: Option<String>,
+    info: Option<String>,
+}
+
+/// How many colours the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorDepth {
+    #[default]
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    /// `NO_COLOR` is set: only the terminal's own colours.
+    NoColor,
+}
+
+impl ColorDepth {
+    pub fn detect() -> Self {
+        Self::from_env(|key| std::env::var(key).ok())
+    }
+
+    /// Reads the depth from environment variables: any non-empty
+    /// `NO_COLOR` turns colours off, `COLORTERM=truecolor` (or `24bit`, or
+    /// Windows Terminal) keeps RGB, a `TERM` mentioning 256 colours gets
+    /// that palette, and anything else the 16 basic colours.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return ColorDepth::NoColor;
+        }
+        let colorterm = var("COLORTERM").unwrap_or_default().to_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" || var("WT_SESSION").is_some() {
+            ColorDepth::TrueColor
+        } else if var("TERM").is_some_and(|term| term.contains("256")) {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// The closest colour this depth can show.
+    pub fn adapt(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorDepth::NoColor, _) | (_, Color::Reset) => Color::Reset,
+            (ColorDepth::TrueColor, color) => color,
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_256(r, g, b)),
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => nearest_basic(r, g, b),
+            (ColorDepth::Ansi16, Color::Indexed(index)) => {
+                let (r, g, b) = indexed_to_rgb(index);
+                nearest_basic(r, g, b)
+            }
+            (_, color) => color,
+        }
+    }
+}
+
+// ── Colour conversion helpers ────────────────────────────────────────────
+
+/// Levels of each channel in the 6×6×6 cube of the 256-colour palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 16 basic colours, with the RGB values xterm uses for them.
+const BASIC: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+/// Closest of the cube and the grey ramp of the 256-colour palette.
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| CUBE_LEVELS.iter().enumerate().min_by_key(|(_, l)| v.abs_diff(**l)).map_or(0, |(i, _)| i);
+    let (qr, qg, qb) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[qr], CUBE_LEVELS[qg], CUBE_LEVELS[qb]);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 8 + 10 * step;
+
+    if distance((grey, grey, grey), (r, g, b)) < distance(cube, (r, g, b)) {
+        232 + step
+    } else {
+        (16 + 36 * qr + 6 * qg + qb) as u8
+    }
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASIC[index as usize].1,
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let grey = 8 + 10 * (index - 232);
+            (grey, grey, grey)
+        }
+    }
+}
+
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    BASIC
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
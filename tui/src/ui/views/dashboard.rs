@@ -0,0 +1,46 @@
+use crate::scroll::{ListPosition, Viewport};
+use crate::state::AppState;
+use crate::ui::components::favorites_dashboard::{self, FavoriteCard};
+use crate::ui::components::status_bar;
+use crate::ui::kickoff::KickoffLabels;
+use crate::ui::layout;
+use crate::ui::Hit;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+pub fn draw(frame: &mut Frame, inner_area: Rect, app: &AppState) {
+    let sections = layout::main_vertical(inner_area);
+
+    let message = app.error_message.as_deref().or(app.status_message.as_deref());
+    status_bar::render(frame, sections[0], message, &app.theme);
+
+    let position = ListPosition { selected: app.selected_favorite_index, offset: app.favorite_offset };
+    let save_key = app.save_favorite_key();
+    favorites_dashboard::render(frame, sections[2], &cards(app), position, save_key.as_deref(), &app.theme);
+}
+
+/// The favourite cards as laid out in `inner_area`.
+pub fn viewport(inner_area: Rect, app: &AppState) -> Option<Viewport> {
+    let sections = layout::main_vertical(inner_area);
+    Some(favorites_dashboard::viewport(sections[2], &cards(app)))
+}
+
+/// The card at column `x`, line `y`.
+pub fn hit(inner_area: Rect, app: &AppState, x: u16, y: u16) -> Option<Hit> {
+    let offset = app.favorite_offset;
+    let index = viewport(inner_area, app)?.index_at(offset, x, y)?;
+    Some(Hit::Row { index, offset })
+}
+
+/// Each favourite's listings come from its own country's site.
+fn cards(app: &AppState) -> Vec<FavoriteCard<'_>> {
+    app.config
+        .favorites
+        .iter()
+        .map(|favorite| FavoriteCard {
+            favorite,
+            status: app.favorite_status(favorite),
+            labels: KickoffLabels::new(app.kickoff_format(), favorite.country.source_zone(), app.now),
+        })
+        .collect()
+}
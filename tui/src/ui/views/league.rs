@@ -1,44 +1,35 @@
-use crate::models::LeagueTab;
+use crate::models::{FixtureSort, LeagueTab};
+use crate::scroll::{ListPosition, Viewport};
 use crate::state::AppState;
-use crate::ui::components::{league_fixtures, league_scorers, league_table, status_bar};
-use crate::ui::theme::{BEIGE, BG_BLACK, GOLD, RUST_ORANGE};
+use crate::ui::components::league_fixtures::{self, FixtureList};
+use crate::ui::components::{league_scorers, league_table, status_bar};
+use crate::ui::kickoff::KickoffLabels;
+use crate::ui::layout;
+use crate::ui::Hit;
+use foot_info_core::models::LeagueFixture;
+use foot_info_core::providers::livesoccertv;
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Position, Rect},
     style::Style,
     text::Span,
     widgets::{Block, Borders, Paragraph, Tabs},
 };
 
+/// Tabs in the order they're drawn.
+const TABS: [LeagueTab; 3] = [LeagueTab::Fixtures, LeagueTab::Table, LeagueTab::TopScorers];
+
 pub fn draw(frame: &mut Frame, area: Rect, app: &AppState) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(
-            [
-                Constraint::Length(3), // Tabs
-                Constraint::Min(0),    // Content
-                Constraint::Length(1), // StatusBar
-            ]
-            .as_ref(),
-        )
-        .split(area);
+    let theme = &app.theme;
+    let chunks = layout::league_vertical(area);
 
     // 1. Tabs
-    let tab_titles = vec![
-        LeagueTab::Fixtures.label(),
-        LeagueTab::Table.label(),
-        LeagueTab::TopScorers.label(),
-    ];
-    let selected_tab_index = match app.league_tab {
-        LeagueTab::Fixtures => 0,
-        LeagueTab::Table => 1,
-        LeagueTab::TopScorers => 2,
-    };
+    let selected_tab_index = TABS.iter().position(|tab| *tab == app.league_tab).unwrap_or(0);
 
-    let tabs = Tabs::new(tab_titles.into_iter().map(Span::raw).collect::<Vec<_>>())
+    let tabs = Tabs::new(TABS.iter().map(|tab| Span::raw(tab.label())).collect::<Vec<_>>())
         .block(Block::default().borders(Borders::ALL).title("League Data"))
         .select(selected_tab_index)
-        .highlight_style(Style::default().fg(GOLD))
+        .highlight_style(Style::default().fg(theme.accent))
         .divider(Span::raw("|"));
     frame.render_widget(tabs, chunks[0]);
 
@@ -48,28 +39,92 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &AppState) {
     if app.is_loading {
         let loading_block = Block::default()
             .borders(Borders::ALL)
-            .style(Style::default().bg(BG_BLACK).fg(BEIGE));
+            .style(Style::default().bg(theme.background).fg(theme.text));
         let p = Paragraph::new("Loading...").alignment(Alignment::Center).block(loading_block);
         frame.render_widget(p, content_area);
     } else if let Some(err) = &app.error_message {
         let err_block = Block::default()
             .borders(Borders::ALL)
-            .style(Style::default().bg(BG_BLACK).fg(RUST_ORANGE));
+            .style(Style::default().bg(theme.background).fg(theme.warning));
         let p = Paragraph::new(err.to_string()).alignment(Alignment::Center).block(err_block);
         frame.render_widget(p, content_area);
     } else if let Some(stats) = &app.league_stats {
         match app.league_tab {
-            LeagueTab::Fixtures => league_fixtures::draw(frame, content_area, stats, app.selected_fixture_index),
-            LeagueTab::Table => league_table::draw(frame, content_area, stats, app.selected_table_index),
-            LeagueTab::TopScorers => league_scorers::draw(frame, content_area, stats, app.selected_scorer_index),
+            LeagueTab::Fixtures => {
+                let labels = KickoffLabels::new(app.kickoff_format(), livesoccertv::SOURCE_ZONE, app.now);
+                let fixtures = app.shown_fixtures();
+                let list = fixture_list(app, &fixtures);
+                league_fixtures::draw(frame, content_area, &list, &labels, &app.changes, theme)
+            }
+            LeagueTab::Table => {
+                let rows = app.shown_table();
+                let position = ListPosition { selected: app.selected_table_index, offset: app.table_offset };
+                league_table::draw(frame, content_area, stats, &rows, position, app.table_sort, theme)
+            }
+            LeagueTab::TopScorers => {
+                let scorers = app.shown_scorers();
+                let position = ListPosition { selected: app.selected_scorer_index, offset: app.scorer_offset };
+                league_scorers::draw(frame, content_area, &scorers, position, app.scorer_sort, theme)
+            }
         }
     } else {
         let empty_block = Block::default()
             .borders(Borders::ALL)
-            .style(Style::default().bg(BG_BLACK).fg(BEIGE));
+            .style(Style::default().bg(theme.background).fg(theme.text));
         frame.render_widget(empty_block, content_area);
     }
 
     // 3. Status Bar
-    status_bar::render(frame, chunks[2], app.status_message.as_deref());
+    status_bar::render(frame, chunks[2], app.status_line().as_deref(), theme);
+}
+
+/// The current tab's list as laid out in `area`, once the league is shown.
+pub fn viewport(area: Rect, app: &AppState) -> Option<Viewport> {
+    if app.is_loading || app.error_message.is_some() || app.league_stats.is_none() {
+        return None;
+    }
+    let content_area = layout::league_vertical(area)[1];
+    Some(match app.league_tab {
+        LeagueTab::Fixtures => league_fixtures::viewport(content_area, &fixture_list(app, &app.shown_fixtures())),
+        LeagueTab::Table => league_table::viewport(content_area, app.shown_table().len()),
+        LeagueTab::TopScorers => league_table::viewport(content_area, app.shown_scorers().len()),
+    })
+}
+
+fn fixture_list<'a>(app: &AppState, fixtures: &'a [&'a LeagueFixture]) -> FixtureList<'a> {
+    FixtureList {
+        fixtures,
+        by_round: app.fixture_sort == FixtureSort::Date,
+        selected_index: app.selected_fixture_index,
+        offset: app.fixture_offset,
+    }
+}
+
+/// The tab or the current tab's row at column `x`, line `y`.
+pub fn hit(area: Rect, app: &AppState, x: u16, y: u16) -> Option<Hit> {
+    let tabs_area = Block::default().borders(Borders::ALL).inner(layout::league_vertical(area)[0]);
+    if tabs_area.contains(Position::new(x, y)) {
+        return tab_at(tabs_area, x).map(Hit::Tab);
+    }
+    let offset = match app.league_tab {
+        LeagueTab::Fixtures => app.fixture_offset,
+        LeagueTab::Table => app.table_offset,
+        LeagueTab::TopScorers => app.scorer_offset,
+    };
+    let index = viewport(area, app)?.index_at(offset, x, y)?;
+    Some(Hit::Row { index, offset })
+}
+
+/// The tab whose title is drawn at column `x`: `Tabs` pads each title
+/// with a space either side and puts a one-column divider between them.
+fn tab_at(tabs_area: Rect, x: u16) -> Option<LeagueTab> {
+    let mut left = tabs_area.x;
+    for tab in TABS {
+        let right = left + Span::raw(tab.label()).width() as u16 + 2;
+        if (left..right).contains(&x) {
+            return Some(tab);
+        }
+        left = right + 1;
+    }
+    None
 }
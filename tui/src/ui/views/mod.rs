@@ -1,3 +1,4 @@
+pub mod dashboard;
 pub mod league;
 pub mod search;
 pub mod top_matches;
@@ -1,17 +1,70 @@
+use crate::models::InputMode;
+use crate::scroll::{ListPosition, Viewport};
 use crate::state::AppState;
 use crate::ui::components::match_list::{self, ResultsState};
-use crate::ui::components::{search_bar, status_bar};
+use crate::ui::components::{search_bar, search_suggestions, status_bar};
+use crate::ui::kickoff::KickoffLabels;
 use crate::ui::layout;
+use crate::ui::Hit;
 use ratatui::Frame;
+use ratatui::layout::{Position, Rect};
 
-pub fn draw(frame: &mut Frame, inner_area: ratatui::layout::Rect, app: &AppState) {
+pub fn draw(frame: &mut Frame, inner_area: Rect, app: &AppState) {
     let sections = layout::main_vertical(inner_area);
     let input_cols = layout::input_horizontal(sections[0]);
 
-    search_bar::render(frame, input_cols[1], &app.search_input);
-    status_bar::render(frame, sections[1], app.status_message.as_deref());
+    let mode = app.config.vim_keys().then_some(app.input_mode);
+    search_bar::render(frame, input_cols[1], &app.search_input, mode, &app.theme);
+    status_bar::render(frame, sections[1], app.status_line().as_deref(), &app.theme);
 
-    let results_state = if app.is_loading {
+    let source = app.get_current_provider().country().source_zone();
+    let labels = KickoffLabels::new(app.kickoff_format(), source, app.now);
+    let position = ListPosition { selected: app.selected_match_index, offset: app.match_offset };
+    match_list::render(
+        frame,
+        sections[2],
+        &results_state(app),
+        &labels,
+        &app.changes,
+        Some(position),
+        &app.theme,
+    );
+
+    // Drawn last, over the results
+    if app.input_mode == InputMode::Insert && app.match_detail.is_none() {
+        search_suggestions::render(
+            frame,
+            inner_area,
+            input_cols[1],
+            &app.suggestions,
+            app.selected_suggestion,
+            &app.theme,
+        );
+    }
+}
+
+/// The results as laid out in `inner_area`, while they're shown.
+pub fn viewport(inner_area: Rect, app: &AppState) -> Option<Viewport> {
+    let sections = layout::main_vertical(inner_area);
+    match results_state(app) {
+        ResultsState::Matches(matches) => Some(match_list::viewport(sections[2], matches)),
+        _ => None,
+    }
+}
+
+/// The search bar or the result at column `x`, line `y`.
+pub fn hit(inner_area: Rect, app: &AppState, x: u16, y: u16) -> Option<Hit> {
+    let input_cols = layout::input_horizontal(layout::main_vertical(inner_area)[0]);
+    if input_cols[1].contains(Position::new(x, y)) {
+        return Some(Hit::SearchBar);
+    }
+    let offset = app.match_offset;
+    let index = viewport(inner_area, app)?.index_at(offset, x, y)?;
+    Some(Hit::Row { index, offset })
+}
+
+fn results_state(app: &AppState) -> ResultsState<'_> {
+    if app.is_loading {
         ResultsState::Loading
     } else if let Some(ref err) = app.error_message {
         ResultsState::Error(err)
@@ -19,7 +72,5 @@ pub fn draw(frame: &mut Frame, inner_area: ratatui::layout::Rect, app: &AppState
         ResultsState::Matches(&app.matches)
     } else {
         ResultsState::Empty
-    };
-
-    match_list::render(frame, sections[2], &results_state);
+    }
 }
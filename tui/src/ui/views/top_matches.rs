@@ -1,14 +1,19 @@
+use crate::scroll::{ListPosition, Viewport};
 use crate::state::AppState;
 use crate::ui::components::status_bar;
 use crate::ui::components::top_matches_list::{self, TopMatchesState};
+use crate::ui::kickoff::KickoffLabels;
 use crate::ui::layout;
+use crate::ui::Hit;
+use foot_info_core::providers::livesoccertv;
 use ratatui::Frame;
+use ratatui::layout::Rect;
 
-pub fn draw(frame: &mut Frame, inner_area: ratatui::layout::Rect, app: &AppState) {
+pub fn draw(frame: &mut Frame, inner_area: Rect, app: &AppState) {
     let sections = layout::main_vertical(inner_area);
 
     // Status bar in the top section
-    status_bar::render(frame, sections[0], app.status_message.as_deref());
+    status_bar::render(frame, sections[0], app.status_line().as_deref(), &app.theme);
 
     // Top matches columns in the full results area
     let top_state = if app.is_loading {
@@ -18,11 +23,42 @@ pub fn draw(frame: &mut Frame, inner_area: ratatui::layout::Rect, app: &AppState
     } else if !app.top_matches.is_empty() {
         TopMatchesState::Matches {
             matches: &app.top_matches,
-            selected_index: app.selected_top_match_index,
+            position: ListPosition { selected: app.selected_top_match_index, offset: app.top_match_offset },
         }
     } else {
         TopMatchesState::Empty
     };
 
-    top_matches_list::render(frame, sections[2], &top_state);
+    top_matches_list::render(frame, sections[2], &top_state, &labels(app), &app.theme);
+}
+
+/// The selected match's day column as laid out in `inner_area`, while the
+/// matches are shown.
+pub fn viewport(inner_area: Rect, app: &AppState) -> Option<Viewport> {
+    if app.is_loading || app.error_message.is_some() {
+        return None;
+    }
+    let sections = layout::main_vertical(inner_area);
+    top_matches_list::viewport(sections[2], &app.top_matches, app.selected_top_match_index, &labels(app))
+}
+
+/// The match card at column `x`, line `y`, in whichever day column.
+pub fn hit(inner_area: Rect, app: &AppState, x: u16, y: u16) -> Option<Hit> {
+    if app.is_loading || app.error_message.is_some() {
+        return None;
+    }
+    let sections = layout::main_vertical(inner_area);
+    let selected = app.selected_top_match_index;
+    top_matches_list::viewports(sections[2], &app.top_matches, &labels(app))
+        .into_iter()
+        .find_map(|viewport| {
+            // Only the selected match's column is scrolled
+            let offset = if viewport.row_of(selected).is_some() { app.top_match_offset } else { 0 };
+            let index = viewport.index_at(offset, x, y)?;
+            Some(Hit::Row { index, offset })
+        })
+}
+
+fn labels(app: &AppState) -> KickoffLabels {
+    KickoffLabels::new(app.kickoff_format(), livesoccertv::SOURCE_ZONE, app.now)
 }
@@ -0,0 +1,311 @@
+use chrono_tz::UTC;
+use clap::Parser;
+use foot_info_core::changes::{Change, ChangeKind, Listing};
+use foot_info_core::error::AppError;
+use foot_info_core::models::{Country, Match, MatchStatus, TopMatch};
+use foot_info_core::utils::format::{HourCycle, KickoffFormat, Locale};
+use foot_info_core::utils::time::DisplayZone;
+use foot_info_tui::config::Favorite;
+use foot_info_tui::cli::{self, Cli, Command, OutputFormat};
+use foot_info_tui::server::FeedTeam;
+use foot_info_tui::models::LeagueTab;
+use foot_info_tui::ui::kickoff::KickoffLabels;
+
+fn labels() -> KickoffLabels {
+    KickoffLabels::new(
+        KickoffFormat::new(Locale::En, HourCycle::H24, DisplayZone::Source),
+        UTC,
+        "2026-02-21T09:00:00Z".parse().unwrap(),
+    )
+}
+
+fn sample_matches() -> Vec<Match> {
+    vec![
+        Match {
+            teams: "Arsenal v Chelsea".into(),
+            competition: "Premier League".into(),
+            date: "Sat 21 Feb".into(),
+            time: "15:00".into(),
+            kickoff: Some("2026-02-21T15:00:00Z".parse().unwrap()),
+            channels: vec!["Sky Sports".into(), "NBC".into()],
+        },
+        Match {
+            teams: "Arsenal v \"Spurs\"".into(),
+            competition: "FA Cup".into(),
+            date: "TBC".into(),
+            time: "??:??".into(),
+            kickoff: None,
+            channels: vec![],
+        },
+    ]
+}
+
+// ── Argument parsing tests ───────────────────────────────────────────────
+
+#[test]
+fn test_no_arguments_starts_tui() {
+    let cli = Cli::try_parse_from(["foot-info-tui"]).unwrap();
+    assert!(cli.command.is_none());
+}
+
+#[test]
+fn test_search_parses_team_country_and_format() {
+    let cli = Cli::try_parse_from(["foot-info-tui", "search", "Arsenal", "--country", "fr", "--format", "json"]).unwrap();
+
+    assert_eq!(cli.format, OutputFormat::Json);
+    match cli.command {
+        Some(Command::Search { team, country }) => {
+            assert_eq!(team, "Arsenal");
+            assert_eq!(country, Country::FR);
+        }
+        other => panic!("Expected search, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_search_defaults_to_uk_and_table() {
+    let cli = Cli::try_parse_from(["foot-info-tui", "search", "Arsenal"]).unwrap();
+
+    assert_eq!(cli.format, OutputFormat::Table);
+    assert!(matches!(cli.command, Some(Command::Search { country: Country::UK, .. })));
+}
+
+#[test]
+fn test_league_parses_tab() {
+    let cli = Cli::try_parse_from(["foot-info-tui", "league", "premier-league", "--tab", "scorers", "-f", "csv"]).unwrap();
+
+    assert_eq!(cli.format, OutputFormat::Csv);
+    match cli.command {
+        Some(Command::League { competition, tab }) => {
+            assert_eq!(competition, "https://www.livesoccertv.com/competitions/england/premier-league/");
+            assert_eq!(tab, LeagueTab::TopScorers);
+        }
+        other => panic!("Expected league, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_country_is_rejected() {
+    assert!(Cli::try_parse_from(["foot-info-tui", "search", "Arsenal", "--country", "de"]).is_err());
+}
+
+#[test]
+fn test_unknown_competition_is_a_usage_error() {
+    let err = Cli::try_parse_from(["foot-info-tui", "league", "nowhere"]).unwrap_err();
+    assert_eq!(err.exit_code(), 2);
+    assert!(err.to_string().contains("unknown competition 'nowhere'"));
+
+    assert!(Cli::try_parse_from(["foot-info-tui", "changes", "--league", "nowhere"]).is_err());
+}
+
+// ── Output format tests ──────────────────────────────────────────────────
+
+#[test]
+fn test_table_aligns_columns() {
+    let output = cli::matches_report(&sample_matches(), &labels()).render(OutputFormat::Table);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with("Date             Time   Teams"));
+    assert!(lines[1].starts_with("---------------  -----  "));
+    assert!(lines[2].starts_with("Sat 21 Feb 2026  15:00  Arsenal v Chelsea"));
+    assert!(lines[3].starts_with("TBC              ??:??  "));
+}
+
+#[test]
+fn test_csv_quotes_fields_with_separators_and_quotes() {
+    let output = cli::matches_report(&sample_matches(), &labels()).render(OutputFormat::Csv);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[0], "Date,Time,Teams,Competition,Channels");
+    assert_eq!(lines[1], "Sat 21 Feb 2026,15:00,Arsenal v Chelsea,Premier League,\"Sky Sports, NBC\"");
+    assert_eq!(lines[2], "TBC,??:??,\"Arsenal v \"\"Spurs\"\"\",FA Cup,");
+}
+
+#[test]
+fn test_markdown_renders_pipe_table() {
+    let mut matches = sample_matches();
+    matches[0].competition = "Premier | League".into();
+    let output = cli::matches_report(&matches, &labels()).render(OutputFormat::Markdown);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[0], "| Date | Time | Teams | Competition | Channels |");
+    assert_eq!(lines[1], "| --- | --- | --- | --- | --- |");
+    assert!(lines[2].contains("Premier \\| League"), "Pipes in cells should be escaped");
+}
+
+#[test]
+fn test_json_serializes_the_matches() {
+    let output = cli::matches_report(&sample_matches(), &labels()).render(OutputFormat::Json);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(json[0]["teams"], "Arsenal v Chelsea");
+    assert_eq!(json[0]["kickoff"], "2026-02-21T15:00:00Z");
+    assert_eq!(json[0]["channels"][1], "NBC");
+    assert!(json[1]["kickoff"].is_null());
+}
+
+#[test]
+fn test_top_matches_report_includes_status_and_url() {
+    let matches = vec![TopMatch {
+        teams: "Team A - Team B".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "20:00".into(),
+        kickoff: None,
+        match_url: "/match/a-vs-b/1#1".into(),
+        competition: Some("England - Premier League".into()),
+        channels: vec![],
+        status: MatchStatus::Live,
+    }];
+    let output = cli::top_matches_report(&matches, &labels()).render(OutputFormat::Csv);
+
+    assert_eq!(output.lines().nth(1), Some("Sat 21 Feb 2026,20:00,Team A - Team B,England - Premier League,Live,,/match/a-vs-b/1#1"));
+}
+
+// ── Exit code tests ──────────────────────────────────────────────────────
+
+#[test]
+fn test_exit_codes_distinguish_error_kinds() {
+    let codes = [
+        cli::exit_code(&AppError::ProviderError("x".into())),
+        cli::exit_code(&AppError::TeamNotFound("x".into())),
+        cli::exit_code(&AppError::NoMatchesScheduled("x".into())),
+    ];
+
+    assert_eq!(codes, [4, 5, 6]);
+    assert!(codes.iter().all(|&code| code > 2), "0-2 are success, I/O and usage errors");
+}
+
+#[test]
+fn test_serve_feed_teams_start_with_the_favourites() {
+    let cli = Cli::try_parse_from(["foot-info-tui", "serve", "--team", "Chelsea", "--team", "arsenal", "--country", "us"]).unwrap();
+    let Some(Command::Serve { teams, country, .. }) = cli.command else {
+        panic!("expected serve");
+    };
+
+    let favorites = [Favorite::new("Arsenal", Country::UK), Favorite::new("Lyon", Country::FR)];
+    let feed_teams = cli::feed_teams(&favorites, teams, country);
+    assert_eq!(
+        feed_teams,
+        [
+            FeedTeam { team: "Arsenal".into(), country: Country::UK },
+            FeedTeam { team: "Lyon".into(), country: Country::FR },
+            FeedTeam { team: "Chelsea".into(), country: Country::US },
+        ]
+    );
+}
+
+#[test]
+fn test_serve_rejects_a_zero_cache_ttl() {
+    assert!(Cli::try_parse_from(["foot-info-tui", "serve", "--cache-ttl", "0"]).is_err());
+    assert!(Cli::try_parse_from(["foot-info-tui", "serve", "--cache-ttl", "1"]).is_ok());
+}
+
+#[test]
+fn test_changes_report_describes_each_change() {
+    let listing = Listing::from_match(&sample_matches()[0]);
+    let changes = vec![
+        Change {
+            kind: ChangeKind::Rescheduled {
+                from_date: "Sat 21 Feb 2026".into(),
+                from_time: "12:30".into(),
+                from_kickoff: Some("2026-02-21T12:30:00Z".parse().unwrap()),
+            },
+            listing: listing.clone(),
+        },
+        Change {
+            kind: ChangeKind::ChannelsChanged {
+                added: vec!["NBC".into()],
+                removed: vec!["Peacock".into()],
+            },
+            listing,
+        },
+    ];
+
+    let report = cli::changes_report(&changes, &labels());
+    assert_eq!(report.rows[0][0], "rescheduled");
+    assert_eq!(report.rows[0][5], "was Sat 21 Feb 2026 12:30");
+    assert_eq!(report.rows[1][5], "+NBC, -Peacock");
+    assert_eq!(report.json[1]["kind"], "channels_changed");
+    assert_eq!(report.json[1]["listing"]["teams"], report.rows[1][3]);
+}
+
+#[test]
+fn test_changes_takes_a_team_or_a_league() {
+    let cli = Cli::try_parse_from(["foot-info-tui", "changes", "--league", "premier-league"]).unwrap();
+    assert!(matches!(cli.command, Some(Command::Changes { team: None, league: Some(_), .. })));
+
+    assert!(Cli::try_parse_from(["foot-info-tui", "changes"]).is_err());
+    assert!(Cli::try_parse_from(["foot-info-tui", "changes", "arsenal", "--league", "premier-league"]).is_err());
+}
+
+#[cfg(feature = "history")]
+#[test]
+fn test_history_reports_query_the_database() {
+    use foot_info_core::history::History;
+    use foot_info_tui::cli::HistoryQuery;
+
+    let history = History::open_in_memory().unwrap();
+    history
+        .record_matches("Fake", Country::UK, &sample_matches(), "2026-02-20T08:00:00Z".parse().unwrap())
+        .unwrap();
+
+    let query = HistoryQuery::Broadcasters { team: "arsenal".into() };
+    let report = cli::history_report(&history, &query, &labels()).unwrap();
+    assert_eq!(report.rows[0], ["NBC", "1", "Fri 20 Feb 2026 08:00"]);
+    assert_eq!(report.rows[1][0], "Sky Sports");
+
+    let query = HistoryQuery::FreeTv { team: "arsenal".into() };
+    let report = cli::history_report(&history, &query, &labels()).unwrap();
+    assert_eq!(report.rows[0], ["2", "1", "50%"]);
+}
+
+#[cfg(feature = "history")]
+#[test]
+fn test_history_positions_take_the_league_short_names() {
+    use foot_info_core::history::History;
+    use foot_info_core::models::{LeagueStats, StandingRow};
+    use foot_info_tui::cli::HistoryQuery;
+
+    let history = History::open_in_memory().unwrap();
+    let stats = LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![],
+        table: vec![StandingRow {
+            position: 2,
+            team: "Arsenal".into(),
+            played: 26,
+            won: 17,
+            drawn: 5,
+            lost: 4,
+            goals_for: 50,
+            goals_against: 20,
+            goal_diff: 30,
+            points: 56,
+            form: vec![],
+            zone: None,
+        }],
+        top_scorers: vec![],
+        previous_page: None,
+        next_page: None,
+    };
+    history.record_league("LiveSoccerTV", &stats, "2026-02-20T08:00:00Z".parse().unwrap()).unwrap();
+
+    for league in ["premier-league", "epl"] {
+        let args = ["foot-info-tui", "history", "positions", "arsenal", "--league", league];
+        let Some(Command::History { query }) = Cli::try_parse_from(args).unwrap().command else {
+            panic!("Expected the history command");
+        };
+        let report = cli::history_report(&history, &query, &labels()).unwrap();
+        assert_eq!(report.rows, [["Fri 20 Feb 2026 08:00", "2", "26", "56"]]);
+    }
+
+    let query = HistoryQuery::Positions {
+        team: "arsenal".into(),
+        league: "https://www.livesoccertv.com/competitions/spain/primera-division/".into(),
+    };
+    let error = cli::history_report(&history, &query, &labels()).unwrap_err();
+    assert!(error.starts_with("no table recorded for"));
+
+    assert!(Cli::try_parse_from(["foot-info-tui", "history", "positions", "arsenal", "-l", "nowhere"]).is_err());
+}
@@ -1,10 +1,23 @@
+use chrono::{Local, NaiveDate};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use foot_info_core::changes::{self, Change, ChangeKind, Listing};
 use foot_info_core::error::AppError;
-use foot_info_core::models::{Match, TopMatch};
+use foot_info_core::models::{
+    Country, FixturesPage, LeagueFixture, LeagueStats, Match, MatchStatus, PageCursor, PageDirection,
+    StandingRow, TopMatch,
+};
+use foot_info_core::utils::time::DisplayZone;
 use foot_info_tui::app::Action;
-use foot_info_tui::handlers::{handle_action, handle_key_event};
-use foot_info_tui::models::ViewMode;
+use foot_info_tui::config::{Config, Favorite};
+use foot_info_tui::handlers::{self, handle_action, handle_key_event};
+use foot_info_tui::keymap::Keymap;
+use foot_info_tui::models::{
+    FavoriteStatus, FixtureSort, InputMode, LeagueTab, MatchSort, ResultsSource, ScorerSort, TableSort, ViewMode,
+};
 use foot_info_tui::state::AppState;
+use foot_info_tui::ui::theme::{ColorDepth, Theme};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 // ── Helpers ──────────────────────────────────────────────────────────────
 
@@ -16,8 +29,17 @@ fn ctrl(c: char) -> KeyEvent {
     KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
 }
 
+/// A state on a scratch config of its own, so tests neither read nor
+/// overwrite the developer's.
 fn make_state() -> AppState {
-    AppState::new()
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let path = std::env::temp_dir().join(format!(
+        "foot-info-config-{}-{}.json",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::SeqCst)
+    ));
+    let _ = std::fs::remove_file(&path);
+    AppState::with_config(Config::at(path))
 }
 
 fn sample_top_matches() -> Vec<TopMatch> {
@@ -26,50 +48,175 @@ fn sample_top_matches() -> Vec<TopMatch> {
             teams: "Team A - Team B".into(),
             date: "Mon 20 Feb 2026".into(),
             time: "20:00".into(),
+            kickoff: None,
             match_url: "/match/1".into(),
+            competition: Some("Premier League".into()),
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
         TopMatch {
             teams: "Team C - Team D".into(),
             date: "Mon 20 Feb 2026".into(),
             time: "21:00".into(),
+            kickoff: None,
             match_url: "/match/2".into(),
+            competition: Some("Premier League".into()),
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
         TopMatch {
             teams: "Team E - Team F".into(),
             date: "Tue 21 Feb 2026".into(),
             time: "18:00".into(),
+            kickoff: None,
             match_url: "/match/3".into(),
+            competition: Some("LaLiga".into()),
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
         TopMatch {
             teams: "Team G - Team H".into(),
             date: "Tue 21 Feb 2026".into(),
             time: "20:00".into(),
+            kickoff: None,
             match_url: "/match/4".into(),
+            competition: Some("Premier League".into()),
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
     ]
 }
 
+fn fixture(home: &str, matchday: u16) -> LeagueFixture {
+    LeagueFixture {
+        home_team: home.into(),
+        away_team: "Away".into(),
+        date: "Sat 21 Mar 2026".into(),
+        time: "15:00".into(),
+        kickoff: None,
+        score: None,
+        channels: vec![],
+        match_url: format!("/match/{}", home),
+        matchday: Some(matchday),
+    }
+}
+
+fn cursor(direction: PageDirection) -> PageCursor {
+    PageCursor {
+        direction,
+        competition_id: "6".into(),
+        offset: if direction == PageDirection::Next { 1 } else { -1 },
+        anchor_time: "2026-03-21 13:30:00".into(),
+        anchor_match: "Everton+vs+Chelsea".into(),
+    }
+}
+
+fn league_state() -> AppState {
+    let mut state = make_state();
+    state.view_mode = ViewMode::League;
+    state.league_tab = LeagueTab::Fixtures;
+    state.league_stats = Some(LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![fixture("A", 30), fixture("B", 30), fixture("C", 31), fixture("D", 31)],
+        table: vec![],
+        top_scorers: vec![],
+        previous_page: Some(cursor(PageDirection::Previous)),
+        next_page: None,
+    });
+    state
+}
+
 // ── Global shortcut tests ────────────────────────────────────────────────
 
 #[test]
-fn test_ctrl_c_cycles_provider() {
+fn test_ctrl_n_cycles_provider() {
     let mut state = make_state();
     assert_eq!(state.current_provider_index, 0);
 
-    let action = handle_key_event(&mut state, ctrl('c'));
+    let action = handle_key_event(&mut state, ctrl('n'));
     assert!(action.is_none());
     assert_eq!(state.current_provider_index, 1);
     assert!(state.status_message.is_some());
 
     // Cycle again
-    handle_key_event(&mut state, ctrl('c'));
+    handle_key_event(&mut state, ctrl('n'));
     assert_eq!(state.current_provider_index, 2);
 
     // Wrap around
-    handle_key_event(&mut state, ctrl('c'));
+    handle_key_event(&mut state, ctrl('n'));
     assert_eq!(state.current_provider_index, 0);
 }
 
+#[test]
+fn test_ctrl_c_quits_from_every_view() {
+    for mode in [ViewMode::Search, ViewMode::TopMatches, ViewMode::League] {
+        let mut state = make_state();
+        state.view_mode = mode;
+        handle_key_event(&mut state, ctrl('c'));
+        assert!(state.exit, "Ctrl+c should quit from {:?}", mode);
+    }
+}
+
+#[test]
+fn test_keybinding_overrides_apply() {
+    let mut state = make_state();
+    let overrides = [("switch_country".to_string(), vec!["ctrl+o".to_string()])].into();
+    state.keymap = Keymap::with_overrides(&overrides).0;
+
+    handle_key_event(&mut state, ctrl('o'));
+    assert_eq!(state.current_provider_index, 1);
+
+    // The old key is free again, so it is nothing
+    handle_key_event(&mut state, ctrl('n'));
+    assert_eq!(state.current_provider_index, 1);
+    assert!(state.search_input.is_empty(), "Unbound chords should not be typed");
+}
+
+#[test]
+fn test_help_overlay_opens_and_closes() {
+    let mut state = make_state();
+    handle_key_event(&mut state, key(KeyCode::F(1)));
+    assert!(state.show_help);
+
+    // Any key closes it without doing anything else
+    handle_key_event(&mut state, key(KeyCode::Char('a')));
+    assert!(!state.show_help);
+    assert!(state.search_input.is_empty());
+
+    state.view_mode = ViewMode::League;
+    handle_key_event(&mut state, key(KeyCode::Char('?')));
+    assert!(state.show_help);
+}
+
+#[test]
+fn test_ctrl_z_toggles_broadcaster_zone() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+    let configured = state.display_zone();
+
+    let action = handle_key_event(&mut state, ctrl('z'));
+    assert!(state.show_source_zone);
+    assert_eq!(state.display_zone(), DisplayZone::Source);
+    assert_eq!(state.client.zone(), DisplayZone::Source);
+    assert!(matches!(action, Some(Action::FetchTopMatches)));
+    assert!(state.status_message.as_ref().unwrap().contains("broadcaster"));
+
+    handle_key_event(&mut state, ctrl('z'));
+    assert!(!state.show_source_zone);
+    assert_eq!(state.client.zone(), configured);
+}
+
+#[test]
+fn test_ctrl_z_keeps_selected_provider() {
+    let mut state = make_state();
+    state.current_provider_index = 2;
+
+    let action = handle_key_event(&mut state, ctrl('z'));
+    assert!(action.is_none(), "Nothing to refetch without results");
+    assert_eq!(state.current_provider_index, 2);
+    assert_eq!(state.client.providers().len(), 3);
+}
+
 // ── Search mode tests ────────────────────────────────────────────────────
 
 #[test]
@@ -127,46 +274,72 @@ fn test_search_ctrl_t_switches_to_top_matches() {
     assert!(matches!(action, Some(Action::FetchTopMatches)));
 }
 
-#[test]
-fn test_search_ctrl_s_saves_favorite() {
+fn state_with_favorites(teams: &[(&str, Country)]) -> AppState {
     let mut state = make_state();
+    state.config.favorites = teams.iter().map(|(team, country)| Favorite::new(team, *country)).collect();
+    state
+}
+
+#[test]
+fn test_search_ctrl_s_adds_favorite_in_current_country() {
+    let mut state = state_with_favorites(&[("Chelsea", Country::UK)]);
+    handle_key_event(&mut state, ctrl('n')); // US
     state.search_input = "Liverpool".into();
     let action = handle_key_event(&mut state, ctrl('s'));
     assert!(action.is_none());
-    assert_eq!(state.config.favorite_team, Some("Liverpool".to_string()));
-    assert!(state.status_message.as_ref().unwrap().contains("Saved"));
+    assert_eq!(
+        state.config.favorites,
+        [Favorite::new("Chelsea", Country::UK), Favorite::new("Liverpool", Country::US)]
+    );
+    assert_eq!(state.status_message.as_deref(), Some("Added favorite: Liverpool (US)"));
+
+    // Adding it again changes nothing
+    state.search_input = "liverpool".into();
+    handle_key_event(&mut state, ctrl('s'));
+    assert_eq!(state.config.favorites.len(), 2);
+    assert!(state.status_message.as_ref().unwrap().starts_with("Already a favorite"));
 }
 
 #[test]
 fn test_search_ctrl_s_empty_does_nothing() {
-    let mut state = make_state();
-    state.config.favorite_team = None; // Clear any loaded favorite
+    let mut state = state_with_favorites(&[]);
     let action = handle_key_event(&mut state, ctrl('s'));
     assert!(action.is_none());
-    // favorite_team should remain None when search_input is empty
-    assert!(state.config.favorite_team.is_none());
+    assert!(state.config.favorites.is_empty());
 }
 
 #[test]
-fn test_search_ctrl_f_loads_favorite() {
-    let mut state = make_state();
-    state.config.favorite_team = Some("Chelsea".into());
+fn test_search_ctrl_f_opens_dashboard_and_fetches_favorites() {
+    let mut state = state_with_favorites(&[("Chelsea", Country::UK), ("Lyon", Country::FR)]);
     let action = handle_key_event(&mut state, ctrl('f'));
-    assert_eq!(state.search_input, "Chelsea");
-    assert!(matches!(action, Some(Action::Search(ref t)) if t == "Chelsea"));
+    assert_eq!(state.view_mode, ViewMode::Dashboard);
+    assert!(matches!(action, Some(Action::FetchDashboard(ref favorites)) if favorites.len() == 2));
 }
 
 #[test]
 fn test_search_ctrl_f_no_favorite_shows_message() {
-    let mut state = make_state();
-    state.config.favorite_team = None; // Clear any loaded favorite
+    let mut state = state_with_favorites(&[]);
     let action = handle_key_event(&mut state, ctrl('f'));
     assert!(action.is_none());
+    assert_eq!(state.view_mode, ViewMode::Dashboard);
     assert!(state
         .status_message
         .as_ref()
         .unwrap()
-        .contains("No favorite"));
+        .contains("No favorites"));
+}
+
+#[test]
+fn test_no_favorite_message_names_the_bound_key() {
+    let mut state = state_with_favorites(&[]);
+    let overrides = [("save_favorite".to_string(), vec!["f2".to_string()])].into();
+    state.keymap = Keymap::with_overrides(&overrides).0;
+
+    handle_key_event(&mut state, ctrl('f'));
+    assert_eq!(
+        state.status_message.as_deref(),
+        Some("No favorites yet: search a team and add it with F2.")
+    );
 }
 
 #[test]
@@ -177,6 +350,303 @@ fn test_search_unknown_key_does_nothing() {
     assert!(!state.exit);
 }
 
+// ── Search history and suggestion tests ─────────────────────────────────
+
+/// A Search view with `history` searched in the UK and `index` known from
+/// league tables, and no favourites.
+fn state_with_history(history: &[&str], index: &[&str]) -> AppState {
+    let mut state = state_with_favorites(&[]);
+    state.config.search_history =
+        HashMap::from([(Country::UK, history.iter().map(|t| t.to_string()).collect())]);
+    state.team_index = index.iter().map(|t| t.to_string()).collect();
+    state
+}
+
+fn type_text(state: &mut AppState, text: &str) {
+    for c in text.chars() {
+        handle_key_event(state, key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_up_recalls_searches_and_down_restores_typing() {
+    let mut state = state_with_history(&["Arsenal", "Chelsea"], &[]);
+    type_text(&mut state, "xy");
+
+    handle_key_event(&mut state, key(KeyCode::Up));
+    assert_eq!(state.search_input, "Arsenal");
+    handle_key_event(&mut state, key(KeyCode::Up));
+    assert_eq!(state.search_input, "Chelsea");
+    handle_key_event(&mut state, key(KeyCode::Up));
+    assert_eq!(state.search_input, "Chelsea", "Stops at the oldest");
+
+    handle_key_event(&mut state, key(KeyCode::Down));
+    assert_eq!(state.search_input, "Arsenal");
+    handle_key_event(&mut state, key(KeyCode::Down));
+    assert_eq!(state.search_input, "xy");
+}
+
+#[test]
+fn test_searches_are_remembered_per_country() {
+    let mut state = state_with_history(&["Arsenal"], &[]);
+    handle_action(&mut state, &Action::Search("Chelsea".into()));
+    handle_action(&mut state, &Action::Search("arsenal".into()));
+    assert_eq!(state.searches(), ["arsenal", "Chelsea"]);
+
+    handle_key_event(&mut state, ctrl('n')); // US
+    assert!(state.searches().is_empty());
+    handle_key_event(&mut state, key(KeyCode::Up));
+    assert_eq!(state.search_input, "", "Nothing to recall in the US yet");
+}
+
+#[test]
+fn test_typing_suggests_and_enter_searches_highlighted() {
+    let mut state = state_with_history(&["Manchester City"], &["Manchester United", "Newcastle United"]);
+    state.config.favorites = vec![Favorite::new("Man Utd Women", Country::UK)];
+    type_text(&mut state, "man u");
+
+    let suggested: Vec<&str> = state.suggestions.iter().map(|s| s.team.as_str()).collect();
+    assert_eq!(suggested, ["Man Utd Women", "Manchester United"]);
+
+    handle_key_event(&mut state, key(KeyCode::Down));
+    handle_key_event(&mut state, key(KeyCode::Down));
+    assert_eq!(state.selected_suggestion, Some(1));
+    let action = handle_key_event(&mut state, key(KeyCode::Enter));
+    assert!(matches!(action, Some(Action::Search(ref t)) if t == "Manchester United"));
+    assert!(state.suggestions.is_empty());
+}
+
+#[test]
+fn test_tab_completes_first_suggestion() {
+    let mut state = state_with_history(&[], &["Borussia Dortmund", "Bayern Munich"]);
+    type_text(&mut state, "dort");
+    handle_key_event(&mut state, key(KeyCode::Tab));
+    assert_eq!(state.search_input, "Borussia Dortmund");
+    assert!(state.suggestions.is_empty(), "The completed team isn't suggested again");
+}
+
+#[test]
+fn test_up_backs_out_of_suggestions_first() {
+    let mut state = state_with_history(&["Lens"], &["Lyon"]);
+    type_text(&mut state, "ly");
+    handle_key_event(&mut state, key(KeyCode::Down));
+    assert_eq!(state.selected_suggestion, Some(0));
+
+    handle_key_event(&mut state, key(KeyCode::Up));
+    assert_eq!(state.selected_suggestion, None);
+    assert_eq!(state.search_input, "ly");
+    handle_key_event(&mut state, key(KeyCode::Up));
+    assert_eq!(state.search_input, "Lens");
+}
+
+#[test]
+fn test_league_tables_feed_the_team_index() {
+    let mut state = state_with_history(&[], &["Arsenal"]);
+    let mut stats = league_state().league_stats.unwrap();
+    stats.table = ["Arsenal", "Brentford"]
+        .iter()
+        .enumerate()
+        .map(|(i, team)| StandingRow {
+            position: i as u8 + 1,
+            team: team.to_string(),
+            played: 0,
+            won: 0,
+            drawn: 0,
+            lost: 0,
+            goals_for: 0,
+            goals_against: 0,
+            goal_diff: 0,
+            points: 0,
+            form: vec![],
+            zone: None,
+        })
+        .collect();
+    handle_action(&mut state, &Action::LeagueStatsFound(stats));
+    assert_eq!(state.team_index, ["Arsenal", "Brentford"]);
+}
+
+// ── Match detail tests ───────────────────────────────────────────────────
+
+fn uk_source() -> ResultsSource {
+    ResultsSource {
+        country: Country::UK,
+        url: Some("https://www.wheresthematch.com/arsenal".into()),
+    }
+}
+
+/// Results of a search for Arsenal, the second one kicking off at a known time.
+fn state_with_results() -> AppState {
+    let mut state = state_with_history(&[], &[]);
+    handle_action(&mut state, &Action::Search("Arsenal".into()));
+    let mut second = search_match("Chelsea v Arsenal");
+    second.kickoff = Some("2026-02-21T15:00:00Z".parse().unwrap());
+    second.channels = vec!["Sky Sports Main Event".into(), "BBC One".into()];
+    handle_action(&mut state, &Action::MatchesFound(uk_source(), vec![search_match("Arsenal v Spurs"), second]));
+    state
+}
+
+#[test]
+fn test_arrows_select_results_and_ctrl_d_opens_details() {
+    let mut state = state_with_results();
+    handle_key_event(&mut state, key(KeyCode::Down));
+    handle_key_event(&mut state, key(KeyCode::Down));
+    assert_eq!(state.selected_match_index, 1, "Stops at the last result");
+
+    handle_key_event(&mut state, ctrl('d'));
+    assert_eq!(state.match_detail.as_ref().map(|m| m.teams.as_str()), Some("Chelsea v Arsenal"));
+
+    // Letters are the popup's keys, not text
+    handle_key_event(&mut state, key(KeyCode::Char('z')));
+    assert_eq!(state.search_input, "");
+
+    handle_key_event(&mut state, key(KeyCode::Esc));
+    assert!(state.match_detail.is_none());
+    assert!(!state.exit, "Esc closes the popup only");
+
+    handle_key_event(&mut state, key(KeyCode::Up));
+    assert_eq!(state.selected_match_index, 0);
+}
+
+#[test]
+fn test_ctrl_d_without_results_does_nothing() {
+    let mut state = state_with_history(&[], &[]);
+    handle_key_event(&mut state, ctrl('d'));
+    assert!(state.match_detail.is_none());
+}
+
+#[test]
+fn test_detail_searches_the_opponent() {
+    let mut state = state_with_results();
+    state.selected_match_index = 1;
+    handle_key_event(&mut state, ctrl('d'));
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char('o')));
+    assert!(matches!(action, Some(Action::Search(ref t)) if t == "Chelsea"));
+    assert_eq!(state.search_input, "Chelsea");
+    assert!(state.match_detail.is_none());
+}
+
+#[test]
+fn test_detail_without_two_sides_has_no_opponent() {
+    let mut state = state_with_results();
+    state.matches[0].teams = "Premier League Review".into();
+    handle_key_event(&mut state, ctrl('d'));
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char('o')));
+    assert!(action.is_none());
+    assert!(state.status_message.as_ref().unwrap().contains("opponent"));
+    assert!(state.match_detail.is_some());
+}
+
+#[test]
+fn test_detail_copies_the_match() {
+    let mut state = state_with_results();
+    state.config.timezone = Some("UTC".into());
+    state.config.locale = None;
+    state.config.clock = None;
+    state.selected_match_index = 1;
+    handle_key_event(&mut state, ctrl('d'));
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char('c')));
+    let Some(Action::Copy(text)) = action else {
+        panic!("c should copy");
+    };
+    assert_eq!(
+        text,
+        "Chelsea v Arsenal · Premier League · Sat 21 Feb 2026 15:00 · Sky Sports Main Event, BBC One"
+    );
+    assert!(handle_action(&mut state, &Action::Copy(text)), "The app writes the clipboard");
+}
+
+#[test]
+fn test_detail_keeps_the_results_source_after_switching_provider() {
+    let mut state = state_with_results();
+    state.config.locale = None;
+    state.config.clock = None;
+    state.show_source_zone = true;
+    handle_key_event(&mut state, ctrl('n'));
+    assert_ne!(state.get_current_provider().country(), Country::UK);
+
+    state.selected_match_index = 1;
+    handle_key_event(&mut state, ctrl('d'));
+    let Some(Action::Copy(text)) = handle_key_event(&mut state, key(KeyCode::Char('c'))) else {
+        panic!("c should copy");
+    };
+    assert!(text.contains("15:00"), "Still the UK listing's time: {}", text);
+    assert_eq!(state.results_country(), Country::UK);
+}
+
+#[test]
+fn test_detail_exports_only_this_match() {
+    let mut state = state_with_results();
+    let dir = export_dir("detail-export");
+    state.config.export_dir = Some(dir.to_string_lossy().into_owned());
+    state.selected_match_index = 1;
+    handle_key_event(&mut state, ctrl('d'));
+
+    handle_key_event(&mut state, key(KeyCode::Char('e')));
+    let calendar = std::fs::read_to_string(dir.join("chelsea-v-arsenal.ics")).unwrap();
+    assert!(calendar.contains("SUMMARY:Chelsea v Arsenal"));
+    assert!(!calendar.contains("Spurs"));
+    assert!(state.status_message.as_ref().unwrap().contains("Exported 1 matches"));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_new_search_closes_details() {
+    let mut state = state_with_results();
+    handle_key_event(&mut state, ctrl('d'));
+    handle_action(&mut state, &Action::Search("Spurs".into()));
+    assert!(state.match_detail.is_none());
+    assert_eq!(state.searched_team.as_deref(), Some("Spurs"));
+}
+
+fn export_dir(test: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("foot-info-{}-{}", test, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_ctrl_e_exports_search_results_to_calendar() {
+    let mut state = make_state();
+    let dir = export_dir("search-export");
+    state.config.export_dir = Some(dir.to_string_lossy().into_owned());
+    // Typing has started on the next search; the results are still Arsenal's
+    state.searched_team = Some("Arsenal".to_string());
+    state.search_input = "Chel".to_string();
+    state.matches = vec![Match {
+        teams: "Arsenal v Chelsea".into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: Some("2026-02-21T15:00:00Z".parse().unwrap()),
+        channels: vec!["Sky Sports".into()],
+    }];
+
+    let action = handle_key_event(&mut state, ctrl('e'));
+    assert!(action.is_none());
+    assert_eq!(state.search_input, "Chel", "Ctrl+e should not be typed");
+
+    let calendar = std::fs::read_to_string(dir.join("arsenal.ics")).unwrap();
+    assert!(calendar.contains("X-WR-CALNAME:Arsenal"));
+    assert!(calendar.contains("SUMMARY:Arsenal v Chelsea"));
+    assert!(state.status_message.as_ref().unwrap().contains("Exported 1 matches"));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_ctrl_e_without_kickoffs_exports_nothing() {
+    let mut state = make_state();
+    let dir = export_dir("empty-export");
+    state.config.export_dir = Some(dir.to_string_lossy().into_owned());
+    state.searched_team = Some("Arsenal".to_string());
+
+    handle_key_event(&mut state, ctrl('e'));
+    assert!(!dir.exists());
+    assert!(state.status_message.as_ref().unwrap().contains("Nothing to export"));
+}
+
 // ── TopMatches mode tests ────────────────────────────────────────────────
 
 #[test]
@@ -350,6 +820,327 @@ fn test_top_matches_unknown_key_does_nothing() {
     assert!(action.is_none());
 }
 
+#[test]
+fn test_top_matches_c_cycles_competition_filter() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+    handle_action(&mut state, &Action::TopMatchesFound(sample_top_matches()));
+    state.selected_top_match_index = 1;
+
+    handle_key_event(&mut state, key(KeyCode::Char('c')));
+    assert_eq!(state.top_match_competition.as_deref(), Some("Premier League"));
+    assert_eq!(state.top_matches.len(), 3);
+    assert_eq!(state.selected_top_match_index, 0);
+
+    handle_key_event(&mut state, key(KeyCode::Char('c')));
+    assert_eq!(state.top_match_competition.as_deref(), Some("LaLiga"));
+    assert_eq!(state.top_matches.len(), 1);
+    assert_eq!(state.top_matches[0].teams, "Team E - Team F");
+
+    handle_key_event(&mut state, key(KeyCode::Char('c')));
+    assert!(state.top_match_competition.is_none());
+    assert_eq!(state.top_matches.len(), 4);
+    assert!(state.status_message.as_ref().unwrap().contains("All"));
+}
+
+#[test]
+fn test_top_matches_filter_survives_refresh() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+    state.top_match_competition = Some("LaLiga".into());
+
+    handle_action(&mut state, &Action::TopMatchesFound(sample_top_matches()));
+    assert_eq!(state.all_top_matches.len(), 4);
+    assert_eq!(state.top_matches.len(), 1);
+
+    // A competition that's gone from the new list falls back to all matches
+    state.top_match_competition = Some("Serie A".into());
+    handle_action(&mut state, &Action::TopMatchesFound(sample_top_matches()));
+    assert!(state.top_match_competition.is_none());
+    assert_eq!(state.top_matches.len(), 4);
+}
+
+#[test]
+fn test_top_matches_bracket_opens_todays_schedule() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char(']')));
+    let today = Local::now().date_naive();
+    assert!(matches!(action, Some(Action::FetchSchedule(date)) if date == today));
+}
+
+#[test]
+fn test_top_matches_brackets_step_schedule_days() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+    state.schedule_date = NaiveDate::from_ymd_opt(2026, 2, 28);
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char(']')));
+    assert!(
+        matches!(action, Some(Action::FetchSchedule(date)) if date == NaiveDate::from_ymd_opt(2026, 3, 1).unwrap())
+    );
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char('[')));
+    assert!(
+        matches!(action, Some(Action::FetchSchedule(date)) if date == NaiveDate::from_ymd_opt(2026, 2, 27).unwrap())
+    );
+}
+
+#[test]
+fn test_top_matches_f_returns_to_featured() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+
+    // Already on the featured matches
+    assert!(handle_key_event(&mut state, key(KeyCode::Char('f'))).is_none());
+
+    state.schedule_date = NaiveDate::from_ymd_opt(2026, 2, 28);
+    let action = handle_key_event(&mut state, key(KeyCode::Char('f')));
+    assert!(matches!(action, Some(Action::FetchTopMatches)));
+}
+
+// ── League mode tests ────────────────────────────────────────────────────
+
+#[test]
+fn test_league_next_round_jumps_to_first_fixture_of_round() {
+    let mut state = league_state();
+    state.selected_fixture_index = 0;
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char(']')));
+    assert!(action.is_none());
+    assert_eq!(state.selected_fixture_index, 2);
+    assert_eq!(state.status_message.as_deref(), Some("Matchday 31"));
+}
+
+#[test]
+fn test_league_prev_round_jumps_back() {
+    let mut state = league_state();
+    state.selected_fixture_index = 3;
+
+    handle_key_event(&mut state, key(KeyCode::Char('[')));
+    assert_eq!(state.selected_fixture_index, 0);
+}
+
+#[test]
+fn test_league_prev_round_at_edge_fetches_previous_page() {
+    let mut state = league_state();
+    state.selected_fixture_index = 1;
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char('[')));
+    assert!(matches!(
+        action,
+        Some(Action::FetchLeagueFixturesPage(ref c)) if c.direction == PageDirection::Previous
+    ));
+}
+
+#[test]
+fn test_league_next_round_at_edge_without_page_does_nothing() {
+    let mut state = league_state();
+    state.selected_fixture_index = 2;
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char(']')));
+    assert!(action.is_none());
+    assert_eq!(state.selected_fixture_index, 2);
+    assert!(state.status_message.as_ref().unwrap().contains("No later"));
+}
+
+#[test]
+fn test_action_fixtures_page_found_prepends_and_selects_round() {
+    let mut state = league_state();
+    state.selected_fixture_index = 0;
+    state.is_loading = true;
+
+    let page = FixturesPage {
+        fixtures: vec![fixture("X", 29), fixture("Y", 29)],
+        previous_page: None,
+        next_page: None,
+    };
+    let should_spawn = handle_action(
+        &mut state,
+        &Action::LeagueFixturesPageFound(cursor(PageDirection::Previous), page),
+    );
+    assert!(!should_spawn);
+    assert!(!state.is_loading);
+
+    let stats = state.league_stats.as_ref().unwrap();
+    assert_eq!(stats.fixtures.len(), 6);
+    assert!(stats.previous_page.is_none());
+    assert_eq!(state.selected_fixture_index, 0);
+    assert_eq!(stats.fixtures[0].home_team, "X");
+}
+
+#[test]
+fn test_league_s_cycles_scorer_sort() {
+    let mut state = league_state();
+    state.league_tab = LeagueTab::TopScorers;
+    state.selected_scorer_index = 3;
+    assert_eq!(state.scorer_sort, ScorerSort::Goals);
+
+    let action = handle_key_event(&mut state, key(KeyCode::Char('s')));
+    assert!(action.is_none());
+    assert_eq!(state.scorer_sort, ScorerSort::NonPenaltyGoals);
+    assert_eq!(state.selected_scorer_index, 0);
+    assert!(state.status_message.as_ref().unwrap().contains("Sorted by"));
+}
+
+// ── Filter and sort tests ────────────────────────────────────────────────
+
+fn type_filter(state: &mut AppState, text: &str) {
+    for c in text.chars() {
+        handle_key_event(state, key(KeyCode::Char(c)));
+    }
+}
+
+fn standing(position: u8, team: &str, goal_diff: i16, form: &str) -> StandingRow {
+    StandingRow {
+        position,
+        team: team.into(),
+        played: 30,
+        won: 15,
+        drawn: 5,
+        lost: 10,
+        goals_for: 45,
+        goals_against: 40,
+        goal_diff,
+        points: 50,
+        form: form.chars().collect(),
+        zone: None,
+    }
+}
+
+#[test]
+fn test_ctrl_r_filters_results_until_esc() {
+    let mut state = state_with_results();
+
+    handle_key_event(&mut state, ctrl('r'));
+    type_filter(&mut state, "sky");
+    assert_eq!(state.matches.len(), 1, "Channels are filtered on");
+    assert_eq!(state.matches[0].teams, "Chelsea v Arsenal");
+    assert_eq!(state.search_input, "", "The filter isn't typed into the search box");
+    assert_eq!(state.status_line().as_deref(), Some("Filter: sky_ (1 of 2)"));
+
+    handle_key_event(&mut state, key(KeyCode::Enter));
+    assert!(!state.filtering);
+    assert_eq!(state.status_line().as_deref(), Some("Filter: sky (1 of 2)"));
+
+    handle_key_event(&mut state, ctrl('r'));
+    handle_key_event(&mut state, key(KeyCode::Esc));
+    assert!(!state.exit, "Esc closes the filter, not the app");
+    assert_eq!(state.matches.len(), 2);
+    assert_eq!(state.status_line(), None);
+}
+
+#[test]
+fn test_filter_words_match_any_field() {
+    let mut state = state_with_results();
+    handle_key_event(&mut state, ctrl('r'));
+    type_filter(&mut state, "ARSENAL bbc");
+    assert_eq!(state.matches.len(), 1);
+
+    // Arrows still move through what's left
+    handle_key_event(&mut state, key(KeyCode::Down));
+    assert_eq!(state.selected_match_index, 0);
+}
+
+#[test]
+fn test_ctrl_g_cycles_result_sort() {
+    let mut state = state_with_results();
+    for _ in 0..3 {
+        handle_key_event(&mut state, ctrl('g'));
+    }
+    assert_eq!(state.match_sort, MatchSort::Channel);
+    assert_eq!(state.status_message.as_deref(), Some("Sorted by: Channel"));
+    assert_eq!(state.matches[0].teams, "Chelsea v Arsenal", "Listings without channels go last");
+
+    handle_key_event(&mut state, ctrl('g'));
+    assert_eq!(state.matches[0].teams, "Arsenal v Spurs", "Back to the site's order");
+}
+
+#[test]
+fn test_new_search_clears_result_filter() {
+    let mut state = state_with_results();
+    handle_key_event(&mut state, ctrl('r'));
+    type_filter(&mut state, "spurs");
+    handle_key_event(&mut state, key(KeyCode::Enter));
+
+    handle_action(&mut state, &Action::Search("Chelsea".into()));
+    handle_action(&mut state, &Action::MatchesFound(uk_source(), vec![search_match("Chelsea v Arsenal")]));
+    assert!(state.match_filter.is_empty());
+    assert_eq!(state.matches.len(), 1);
+}
+
+#[test]
+fn test_slash_filters_top_matches_and_sort_stays_within_days() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+    let mut matches = sample_top_matches();
+    matches[2].competition = Some("Serie A".into());
+    handle_action(&mut state, &Action::TopMatchesFound(matches));
+
+    handle_key_event(&mut state, key(KeyCode::Char('/')));
+    type_filter(&mut state, "serie");
+    assert_eq!(state.top_matches.len(), 1);
+    handle_key_event(&mut state, key(KeyCode::Esc));
+    assert_eq!(state.top_matches.len(), 4);
+
+    handle_key_event(&mut state, key(KeyCode::Char('s')));
+    assert_eq!(state.top_match_sort, MatchSort::Competition);
+    let teams: Vec<&str> = state.top_matches.iter().map(|m| m.teams.as_str()).collect();
+    assert_eq!(teams, ["Team A - Team B", "Team C - Team D", "Team G - Team H", "Team E - Team F"]);
+}
+
+#[test]
+fn test_league_filter_applies_to_its_tab() {
+    let mut state = league_state();
+
+    handle_key_event(&mut state, key(KeyCode::Char('/')));
+    type_filter(&mut state, "c");
+    handle_key_event(&mut state, key(KeyCode::Enter));
+    assert_eq!(state.shown_fixtures().len(), 1);
+
+    let action = handle_key_event(&mut state, key(KeyCode::Enter));
+    assert!(matches!(action, Some(Action::Search(ref team)) if team == "C"), "Enter searches the fixture shown");
+
+    // Other tabs keep their own filter
+    state.view_mode = ViewMode::League;
+    handle_key_event(&mut state, key(KeyCode::Tab));
+    assert!(state.status_line().is_none_or(|line| !line.contains("Filter")));
+}
+
+#[test]
+fn test_league_s_sorts_table_by_goal_difference_then_form() {
+    let mut state = league_state();
+    state.league_tab = LeagueTab::Table;
+    if let Some(stats) = state.league_stats.as_mut() {
+        stats.table = vec![standing(1, "Arsenal", 30, "LDL"), standing(2, "Chelsea", 35, "WWW")];
+    }
+
+    handle_key_event(&mut state, key(KeyCode::Char('s')));
+    assert_eq!(state.table_sort, TableSort::GoalDifference);
+    assert_eq!(state.shown_table()[0].team, "Chelsea");
+
+    handle_key_event(&mut state, key(KeyCode::Char('s')));
+    assert_eq!(state.table_sort, TableSort::Form);
+    assert_eq!(state.shown_table()[0].team, "Chelsea");
+
+    handle_key_event(&mut state, key(KeyCode::Char('s')));
+    assert_eq!(state.shown_table()[0].team, "Arsenal");
+}
+
+#[test]
+fn test_league_fixtures_sorted_by_team_still_page() {
+    let mut state = league_state();
+    state.league_stats.as_mut().unwrap().fixtures.reverse();
+    handle_key_event(&mut state, key(KeyCode::Char('s')));
+    assert_eq!(state.fixture_sort, FixtureSort::Team);
+    let homes: Vec<&str> = state.shown_fixtures().iter().map(|f| f.home_team.as_str()).collect();
+    assert_eq!(homes, ["A", "B", "C", "D"]);
+
+    handle_key_event(&mut state, key(KeyCode::Char(']')));
+    assert_eq!(state.selected_fixture_index, 2, "The next round is found in the shown order");
+}
+
 // ── handle_action tests ──────────────────────────────────────────────────
 
 #[test]
@@ -361,6 +1152,7 @@ fn test_action_search_sets_loading() {
         competition: "Y".into(),
         date: "D".into(),
         time: "T".into(),
+        kickoff: None,
         channels: vec![],
     }];
 
@@ -381,10 +1173,11 @@ fn test_action_matches_found_stores_results() {
         competition: "Premier League".into(),
         date: "Sat 22 Feb".into(),
         time: "15:00".into(),
+        kickoff: None,
         channels: vec!["Sky Sports".into()],
     }];
 
-    let should_spawn = handle_action(&mut state, &Action::MatchesFound(matches.clone()));
+    let should_spawn = handle_action(&mut state, &Action::MatchesFound(uk_source(), matches.clone()));
     assert!(!should_spawn);
     assert!(!state.is_loading);
     assert_eq!(state.matches.len(), 1);
@@ -418,6 +1211,51 @@ fn test_action_fetch_top_matches_sets_loading() {
     assert_eq!(state.selected_top_match_index, 0);
 }
 
+#[test]
+fn test_action_fetch_schedule_sets_date_and_loading() {
+    let mut state = make_state();
+    state.top_matches = sample_top_matches();
+    let date = NaiveDate::from_ymd_opt(2026, 2, 28).unwrap();
+
+    let should_spawn = handle_action(&mut state, &Action::FetchSchedule(date));
+    assert!(should_spawn);
+    assert!(state.is_loading);
+    assert_eq!(state.schedule_date, Some(date));
+    assert!(state.top_matches.is_empty());
+
+    // Going back to the featured matches clears the day
+    handle_action(&mut state, &Action::FetchTopMatches);
+    assert!(state.schedule_date.is_none());
+}
+
+#[test]
+fn test_action_schedule_found_stores_results() {
+    let mut state = make_state();
+    let date = NaiveDate::from_ymd_opt(2026, 2, 28).unwrap();
+    handle_action(&mut state, &Action::FetchSchedule(date));
+
+    let should_spawn = handle_action(&mut state, &Action::ScheduleFound(date, sample_top_matches()));
+    assert!(!should_spawn);
+    assert!(!state.is_loading);
+    assert_eq!(state.top_matches.len(), 4);
+    assert!(state.status_message.as_ref().unwrap().contains("Sat 28 Feb 2026"));
+}
+
+#[test]
+fn test_action_schedule_found_for_another_day_is_ignored() {
+    let mut state = make_state();
+    let friday = NaiveDate::from_ymd_opt(2026, 2, 27).unwrap();
+    let saturday = NaiveDate::from_ymd_opt(2026, 2, 28).unwrap();
+    handle_action(&mut state, &Action::FetchSchedule(friday));
+    handle_action(&mut state, &Action::FetchSchedule(saturday));
+
+    // Friday's fetch lands after the user moved on to Saturday
+    handle_action(&mut state, &Action::ScheduleFound(friday, sample_top_matches()));
+    assert_eq!(state.schedule_date, Some(saturday));
+    assert!(state.top_matches.is_empty());
+    assert!(state.is_loading, "Still waiting for Saturday");
+}
+
 #[test]
 fn test_action_top_matches_found_stores_results() {
     let mut state = make_state();
@@ -431,3 +1269,480 @@ fn test_action_top_matches_found_stores_results() {
     assert_eq!(state.selected_top_match_index, 0);
     assert!(state.status_message.as_ref().unwrap().contains("4"));
 }
+
+#[test]
+fn test_action_tick_advances_clock() {
+    let mut state = make_state();
+    let now = "2026-02-21T18:00:00Z".parse().unwrap();
+
+    let should_spawn = handle_action(&mut state, &Action::Tick(now));
+    assert!(!should_spawn);
+    assert_eq!(state.now, now);
+}
+
+#[test]
+fn test_action_changes_found_summarizes_in_status() {
+    let mut state = make_state();
+    handle_action(&mut state, &Action::Search("Arsenal".into()));
+    let key = changes::team_key("Arsenal", state.get_current_provider().country());
+    let listing = Listing::from_match(&Match {
+        teams: "Arsenal v Chelsea".into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "17:30".into(),
+        kickoff: None,
+        channels: vec![],
+    });
+    let changes = vec![Change {
+        kind: ChangeKind::Added,
+        listing,
+    }];
+
+    let should_spawn = handle_action(&mut state, &Action::ChangesFound(key.clone(), changes.clone()));
+    assert!(!should_spawn);
+    assert_eq!(state.changes.len(), 1);
+    assert_eq!(state.status_message.as_deref(), Some("Changed since last look: 1 new"));
+
+    // A new search forgets the previous look's changes, and ignores them
+    // if they arrive late
+    handle_action(&mut state, &Action::Search("Chelsea".into()));
+    assert!(state.changes.is_empty());
+    handle_action(&mut state, &Action::ChangesFound(key, changes));
+    assert!(state.changes.is_empty());
+}
+
+// ── Vim key tests ────────────────────────────────────────────────────────
+
+fn vim_state() -> AppState {
+    let mut state = make_state();
+    state.config.keys = Some("vim".into());
+    state.keymap = state.config.keymap().0;
+    state
+}
+
+fn search_match(teams: &str) -> Match {
+    Match {
+        teams: teams.into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "15:00".into(),
+        kickoff: None,
+        channels: vec![],
+    }
+}
+
+#[test]
+fn test_vim_esc_stops_typing_instead_of_quitting() {
+    let mut state = vim_state();
+    handle_key_event(&mut state, key(KeyCode::Char('j')));
+    assert_eq!(state.search_input, "j", "Insert mode types letters");
+
+    handle_key_event(&mut state, key(KeyCode::Esc));
+    assert!(!state.exit);
+    assert_eq!(state.input_mode, InputMode::Normal);
+
+    // Letters are keys now, not text
+    handle_key_event(&mut state, key(KeyCode::Char('x')));
+    assert_eq!(state.search_input, "j");
+
+    handle_key_event(&mut state, key(KeyCode::Char('i')));
+    assert_eq!(state.input_mode, InputMode::Insert);
+
+    handle_key_event(&mut state, key(KeyCode::Esc));
+    handle_key_event(&mut state, key(KeyCode::Char('q')));
+    assert!(state.exit);
+}
+
+#[test]
+fn test_vim_submit_moves_to_normal_mode() {
+    let mut state = vim_state();
+    state.search_input = "Arsenal".into();
+
+    let action = handle_key_event(&mut state, key(KeyCode::Enter));
+    assert!(matches!(action, Some(Action::Search(ref team)) if team == "Arsenal"));
+    assert_eq!(state.input_mode, InputMode::Normal);
+}
+
+#[test]
+fn test_vim_moves_through_search_results() {
+    let mut state = vim_state();
+    state.input_mode = InputMode::Normal;
+    handle_action(
+        &mut state,
+        &Action::MatchesFound(uk_source(), vec![search_match("A v B"), search_match("C v D"), search_match("E v F")]),
+    );
+
+    handle_key_event(&mut state, key(KeyCode::Char('j')));
+    handle_key_event(&mut state, key(KeyCode::Char('j')));
+    handle_key_event(&mut state, key(KeyCode::Char('j')));
+    assert_eq!(state.selected_match_index, 2, "j stops at the last match");
+
+    handle_key_event(&mut state, key(KeyCode::Char('k')));
+    assert_eq!(state.selected_match_index, 1);
+
+    // gg needs both presses
+    handle_key_event(&mut state, key(KeyCode::Char('g')));
+    assert_eq!(state.selected_match_index, 1);
+    handle_key_event(&mut state, key(KeyCode::Char('g')));
+    assert_eq!(state.selected_match_index, 0);
+
+    handle_key_event(&mut state, key(KeyCode::Char('G')));
+    assert_eq!(state.selected_match_index, 2);
+
+    handle_key_event(&mut state, key(KeyCode::Enter));
+    assert_eq!(state.match_detail.as_ref().map(|m| m.teams.as_str()), Some("E v F"));
+    handle_key_event(&mut state, key(KeyCode::Char('q')));
+    assert!(state.match_detail.is_none());
+    assert!(!state.exit, "q closes the popup first");
+}
+
+#[test]
+fn test_vim_single_g_is_forgotten_after_another_key() {
+    let mut state = vim_state();
+    state.view_mode = ViewMode::TopMatches;
+    state.top_matches = sample_top_matches();
+    state.selected_top_match_index = 3;
+
+    handle_key_event(&mut state, key(KeyCode::Char('g')));
+    handle_key_event(&mut state, key(KeyCode::Char('k')));
+    handle_key_event(&mut state, key(KeyCode::Char('g')));
+    assert_eq!(state.selected_top_match_index, 2, "g, k, g is not gg");
+}
+
+#[test]
+fn test_vim_hjkl_in_top_matches_columns() {
+    let mut state = vim_state();
+    state.view_mode = ViewMode::TopMatches;
+    state.top_matches = sample_top_matches();
+
+    handle_key_event(&mut state, key(KeyCode::Char('j')));
+    assert_eq!(state.selected_top_match_index, 1);
+    handle_key_event(&mut state, key(KeyCode::Char('l')));
+    assert_eq!(state.selected_top_match_index, 3, "Same row of the next day");
+    handle_key_event(&mut state, key(KeyCode::Char('h')));
+    assert_eq!(state.selected_top_match_index, 1);
+    handle_key_event(&mut state, key(KeyCode::Char('G')));
+    assert_eq!(state.selected_top_match_index, 3);
+}
+
+#[test]
+fn test_vim_h_l_switch_league_tabs() {
+    let mut state = league_state();
+    state.keymap = Keymap::vim();
+
+    handle_key_event(&mut state, key(KeyCode::Char('l')));
+    assert_eq!(state.league_tab, LeagueTab::Table);
+    handle_key_event(&mut state, key(KeyCode::Char('h')));
+    handle_key_event(&mut state, key(KeyCode::Char('h')));
+    assert_eq!(state.league_tab, LeagueTab::TopScorers);
+
+    handle_key_event(&mut state, key(KeyCode::Char('l')));
+    handle_key_event(&mut state, key(KeyCode::Char('G')));
+    assert_eq!(state.selected_fixture_index, 3);
+}
+
+#[test]
+fn test_vim_slash_focuses_search_from_any_view() {
+    let mut state = league_state();
+    state.keymap = Keymap::vim();
+    state.input_mode = InputMode::Normal;
+
+    handle_key_event(&mut state, key(KeyCode::Char('/')));
+    assert_eq!(state.view_mode, ViewMode::Search);
+    assert_eq!(state.input_mode, InputMode::Insert);
+
+    handle_key_event(&mut state, key(KeyCode::Char('l')));
+    assert_eq!(state.search_input, "l");
+}
+
+#[test]
+fn test_vim_capital_f_filters_the_list() {
+    let mut state = league_state();
+    state.keymap = Keymap::vim();
+    state.input_mode = InputMode::Normal;
+
+    handle_key_event(&mut state, key(KeyCode::Char('F')));
+    assert!(state.filtering);
+    assert_eq!(state.view_mode, ViewMode::League);
+}
+
+#[test]
+fn test_letters_are_not_movement_without_vim_keys() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::TopMatches;
+    state.top_matches = sample_top_matches();
+
+    handle_key_event(&mut state, key(KeyCode::Char('j')));
+    assert_eq!(state.selected_top_match_index, 0);
+}
+
+// ── Command palette tests ────────────────────────────────────────────────
+
+fn run_command(state: &mut AppState, command: &str) -> Option<Action> {
+    handle_key_event(state, ctrl('p'));
+    assert!(state.palette.is_some());
+    for c in command.chars() {
+        handle_key_event(state, key(KeyCode::Char(c)));
+    }
+    handle_key_event(state, key(KeyCode::Enter))
+}
+
+#[test]
+fn test_palette_types_without_touching_the_search_box() {
+    let mut state = make_state();
+    handle_key_event(&mut state, ctrl('p'));
+    handle_key_event(&mut state, key(KeyCode::Char('x')));
+    assert_eq!(state.palette.as_ref().unwrap().input, "x");
+    assert!(state.search_input.is_empty());
+
+    handle_key_event(&mut state, key(KeyCode::Esc));
+    assert!(state.palette.is_none());
+    assert!(!state.exit, "Esc closes the palette, not the app");
+}
+
+#[test]
+fn test_palette_league_with_argument() {
+    let mut state = make_state();
+    let action = run_command(&mut state, "league serie a");
+
+    assert!(state.palette.is_none());
+    assert_eq!(state.view_mode, ViewMode::League);
+    assert!(matches!(
+        action,
+        Some(Action::FetchLeagueStats(ref url)) if url == "https://www.livesoccertv.com/competitions/italy/serie-a/"
+    ));
+    assert_eq!(state.config.recent_commands.first().map(String::as_str), Some("league"));
+}
+
+#[test]
+fn test_palette_search_with_country() {
+    let mut state = make_state();
+    let action = run_command(&mut state, "search lyon fr");
+
+    assert!(matches!(action, Some(Action::Search(ref team)) if team == "lyon"));
+    assert_eq!(state.search_input, "lyon");
+    assert_eq!(state.get_current_provider().country(), Country::FR);
+}
+
+#[test]
+fn test_palette_unknown_competition_is_reported() {
+    let mut state = make_state();
+    let action = run_command(&mut state, "league quidditch cup");
+
+    assert!(action.is_none());
+    assert_eq!(state.view_mode, ViewMode::Search);
+    assert_eq!(state.status_message.as_deref(), Some("Unknown competition: quidditch cup"));
+}
+
+#[test]
+fn test_palette_runs_highlighted_command() {
+    let mut state = make_state();
+    state.view_mode = ViewMode::League;
+    handle_key_event(&mut state, ctrl('p'));
+    for c in "top".chars() {
+        handle_key_event(&mut state, key(KeyCode::Char(c)));
+    }
+
+    let action = handle_key_event(&mut state, key(KeyCode::Enter));
+    assert!(matches!(action, Some(Action::FetchTopMatches)));
+    assert_eq!(state.view_mode, ViewMode::TopMatches);
+}
+
+#[test]
+fn test_palette_tab_completes_for_arguments() {
+    let mut state = make_state();
+    handle_key_event(&mut state, ctrl('p'));
+    for c in "cou".chars() {
+        handle_key_event(&mut state, key(KeyCode::Char(c)));
+    }
+    handle_key_event(&mut state, key(KeyCode::Tab));
+    assert_eq!(state.palette.as_ref().unwrap().input, "country ");
+
+    for c in "us".chars() {
+        handle_key_event(&mut state, key(KeyCode::Char(c)));
+    }
+    handle_key_event(&mut state, key(KeyCode::Enter));
+    assert_eq!(state.get_current_provider().country(), Country::US);
+}
+
+#[test]
+fn test_palette_theme_switches_and_remembers() {
+    let mut state = make_state();
+    state.theme = Theme::dark().for_depth(ColorDepth::Ansi256);
+    run_command(&mut state, "theme solarized");
+
+    assert_eq!(state.theme.name, "solarized");
+    assert_eq!(state.theme.depth, ColorDepth::Ansi256, "The terminal's depth is kept");
+    assert_eq!(state.theme, Theme::solarized().for_depth(ColorDepth::Ansi256));
+    assert_eq!(state.config.theme.as_deref(), Some("solarized"));
+    assert_eq!(state.status_message.as_deref(), Some("Theme: solarized"));
+}
+
+#[test]
+fn test_palette_theme_without_name_cycles() {
+    let mut state = make_state();
+    state.theme = Theme::dark();
+    run_command(&mut state, "theme");
+    assert_eq!(state.theme.name, "light");
+}
+
+#[test]
+fn test_palette_unknown_theme_is_reported() {
+    let mut state = make_state();
+    state.theme = Theme::dark();
+    run_command(&mut state, "theme neon");
+
+    assert_eq!(state.theme.name, "dark");
+    assert_eq!(state.status_message.as_deref(), Some("Unknown theme 'neon'"));
+}
+
+// ── Dashboard tests ──────────────────────────────────────────────────────
+
+fn dashboard_state() -> AppState {
+    let mut state = state_with_favorites(&[
+        ("Arsenal", Country::UK),
+        ("Lyon", Country::FR),
+        ("Inter Miami", Country::US),
+    ]);
+    state.view_mode = ViewMode::Dashboard;
+    state
+}
+
+fn shift(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::SHIFT)
+}
+
+fn favorite_teams(state: &AppState) -> Vec<&str> {
+    state.config.favorites.iter().map(|f| f.team.as_str()).collect()
+}
+
+#[test]
+fn test_start_opens_dashboard_only_with_favorites() {
+    let mut state = state_with_favorites(&[]);
+    assert!(handlers::start(&mut state).is_none());
+    assert_eq!(state.view_mode, ViewMode::Search);
+
+    let mut state = dashboard_state();
+    state.view_mode = ViewMode::Search;
+    let action = handlers::start(&mut state);
+    assert_eq!(state.view_mode, ViewMode::Dashboard);
+    assert!(matches!(action, Some(Action::FetchDashboard(ref favorites)) if *favorites == state.config.favorites));
+}
+
+#[test]
+fn test_dashboard_fetch_results_are_applied_per_favorite() {
+    let mut state = dashboard_state();
+    let lyon = Favorite::new("Lyon", Country::FR);
+    let fetch = Action::FetchDashboard(state.config.favorites.clone());
+    assert!(handle_action(&mut state, &fetch));
+    assert!(matches!(state.favorite_status(&lyon), Some(FavoriteStatus::Loading)));
+
+    handle_action(&mut state, &Action::FavoriteMatchesFound(lyon.clone(), vec![search_match("Lyon v Nice")]));
+    handle_action(
+        &mut state,
+        &Action::FavoriteError(Favorite::new("Arsenal", Country::UK), "Team not found".into()),
+    );
+    assert!(matches!(state.favorite_status(&lyon), Some(FavoriteStatus::Matches(m)) if m.len() == 1));
+    assert!(matches!(
+        state.favorite_status(&Favorite::new("Arsenal", Country::UK)),
+        Some(FavoriteStatus::Error(e)) if e == "Team not found"
+    ));
+
+    // Reopening only fetches what was never fetched
+    state.view_mode = ViewMode::Search;
+    state.config.favorites.push(Favorite::new("Chelsea", Country::UK));
+    let action = handle_key_event(&mut state, ctrl('f'));
+    assert!(matches!(
+        action,
+        Some(Action::FetchDashboard(ref favorites)) if *favorites == [Favorite::new("Chelsea", Country::UK)]
+    ));
+}
+
+#[test]
+fn test_dashboard_ignores_results_of_removed_favorites() {
+    let mut state = dashboard_state();
+    let gone = Favorite::new("Chelsea", Country::UK);
+    handle_action(&mut state, &Action::FavoriteMatchesFound(gone.clone(), vec![]));
+    assert!(state.favorite_status(&gone).is_none());
+}
+
+#[test]
+fn test_dashboard_reorders_and_removes_selected_favorite() {
+    let mut state = dashboard_state();
+    handle_key_event(&mut state, key(KeyCode::Down));
+    handle_key_event(&mut state, shift(KeyCode::Up));
+    assert_eq!(favorite_teams(&state), ["Lyon", "Arsenal", "Inter Miami"]);
+    assert_eq!(state.selected_favorite_index, 0, "The moved favourite stays selected");
+
+    handle_key_event(&mut state, shift(KeyCode::Up));
+    assert_eq!(favorite_teams(&state), ["Lyon", "Arsenal", "Inter Miami"], "Already first");
+
+    handle_key_event(&mut state, shift(KeyCode::Down));
+    handle_key_event(&mut state, shift(KeyCode::Down));
+    assert_eq!(favorite_teams(&state), ["Arsenal", "Inter Miami", "Lyon"]);
+    assert_eq!(state.selected_favorite_index, 2);
+
+    handle_key_event(&mut state, key(KeyCode::Delete));
+    assert_eq!(favorite_teams(&state), ["Arsenal", "Inter Miami"]);
+    assert_eq!(state.selected_favorite_index, 1, "Selection stays in the list");
+    assert_eq!(state.status_message.as_deref(), Some("Removed favorite: Lyon"));
+}
+
+#[test]
+fn test_dashboard_enter_searches_favorite_in_its_country() {
+    let mut state = dashboard_state();
+    handle_key_event(&mut state, key(KeyCode::Down));
+    let action = handle_key_event(&mut state, key(KeyCode::Enter));
+
+    assert_eq!(state.view_mode, ViewMode::Search);
+    assert_eq!(state.search_input, "Lyon");
+    assert_eq!(state.get_current_provider().country(), Country::FR);
+    assert!(matches!(action, Some(Action::Search(ref team)) if team == "Lyon"));
+}
+
+#[test]
+fn test_dashboard_refresh_and_back() {
+    let mut state = dashboard_state();
+    let action = handle_key_event(&mut state, key(KeyCode::Char('r')));
+    assert!(matches!(action, Some(Action::FetchDashboard(ref favorites)) if favorites.len() == 3));
+
+    handle_key_event(&mut state, key(KeyCode::Esc));
+    assert_eq!(state.view_mode, ViewMode::Search);
+    assert!(!state.exit);
+}
+
+#[test]
+fn test_palette_adds_removes_and_moves_favorites() {
+    let mut state = dashboard_state();
+    run_command(&mut state, "add nice fr");
+    assert_eq!(state.config.favorites.last(), Some(&Favorite::new("nice", Country::FR)));
+
+    run_command(&mut state, "move nice 1");
+    assert_eq!(favorite_teams(&state), ["nice", "Arsenal", "Lyon", "Inter Miami"]);
+
+    run_command(&mut state, "move arsenal down");
+    assert_eq!(favorite_teams(&state), ["nice", "Lyon", "Arsenal", "Inter Miami"]);
+
+    run_command(&mut state, "remove LYON");
+    assert_eq!(favorite_teams(&state), ["nice", "Arsenal", "Inter Miami"]);
+
+    run_command(&mut state, "remove celtic");
+    assert_eq!(state.status_message.as_deref(), Some("Not a favorite: celtic"));
+}
+
+#[test]
+fn test_palette_favorite_commands_use_dashboard_selection() {
+    let mut state = dashboard_state();
+    handle_key_event(&mut state, key(KeyCode::Down));
+    run_command(&mut state, "move down");
+    assert_eq!(favorite_teams(&state), ["Arsenal", "Inter Miami", "Lyon"]);
+
+    run_command(&mut state, "remove");
+    assert_eq!(favorite_teams(&state), ["Arsenal", "Inter Miami"]);
+
+    // Outside the dashboard there is no selection to fall back on
+    state.view_mode = ViewMode::Search;
+    run_command(&mut state, "remove");
+    assert_eq!(favorite_teams(&state), ["Arsenal", "Inter Miami"]);
+    assert!(state.status_message.as_ref().unwrap().starts_with("Usage: remove"));
+}
@@ -0,0 +1,184 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use foot_info_tui::keymap::{KeyAction, KeyBinding, Keymap, Scope};
+use std::collections::HashMap;
+
+fn overrides(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+    entries
+        .iter()
+        .map(|(name, keys)| (name.to_string(), keys.iter().map(|k| k.to_string()).collect()))
+        .collect()
+}
+
+// ── KeyBinding tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_key_names() {
+    let cases = [
+        ("ctrl+c", KeyCode::Char('c'), KeyModifiers::CONTROL),
+        ("Ctrl+C", KeyCode::Char('c'), KeyModifiers::CONTROL),
+        ("esc", KeyCode::Esc, KeyModifiers::NONE),
+        ("shift+tab", KeyCode::BackTab, KeyModifiers::NONE),
+        ("f5", KeyCode::F(5), KeyModifiers::NONE),
+        ("[", KeyCode::Char('['), KeyModifiers::NONE),
+        ("G", KeyCode::Char('G'), KeyModifiers::NONE),
+        ("shift+g", KeyCode::Char('G'), KeyModifiers::NONE),
+        ("alt+space", KeyCode::Char(' '), KeyModifiers::ALT),
+        ("+", KeyCode::Char('+'), KeyModifiers::NONE),
+    ];
+    for (text, code, modifiers) in cases {
+        assert_eq!(KeyBinding::parse(text), Some(KeyBinding::new(code, modifiers)), "{}", text);
+    }
+
+    assert_eq!(KeyBinding::parse("hyper+x"), None);
+    assert_eq!(KeyBinding::parse("banana"), None);
+}
+
+#[test]
+fn test_display_round_trips_to_hint_text() {
+    assert_eq!(KeyBinding::parse("ctrl+t").unwrap().to_string(), "Ctrl+t");
+    assert_eq!(KeyBinding::parse("shift+tab").unwrap().to_string(), "Shift+Tab");
+    assert_eq!(KeyBinding::parse("up").unwrap().to_string(), "↑");
+}
+
+// ── Keymap tests ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_defaults_have_no_conflicts() {
+    assert!(Keymap::default().conflicts().is_empty());
+}
+
+#[test]
+fn test_shifted_characters_match_their_binding() {
+    let keymap = Keymap::default();
+    let question = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT);
+    assert_eq!(keymap.action(Scope::League, &question), Some(KeyAction::Help));
+}
+
+#[test]
+fn test_scoped_override_leaves_other_views_alone() {
+    let (keymap, problems) = Keymap::with_overrides(&overrides(&[("league.refresh", &["f5"])]));
+    assert!(problems.is_empty());
+
+    let f5 = KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE);
+    assert_eq!(keymap.action(Scope::League, &f5), Some(KeyAction::Refresh));
+    let r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+    assert_eq!(keymap.action(Scope::League, &r), None);
+}
+
+#[test]
+fn test_conflicting_override_is_rejected() {
+    let (keymap, problems) = Keymap::with_overrides(&overrides(&[("cycle_sort", &["r"])]));
+    assert_eq!(problems, ["Key r is bound to both cycle_sort and refresh in league"]);
+
+    let s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE);
+    assert_eq!(keymap.action(Scope::League, &s), Some(KeyAction::CycleSort), "Default kept");
+}
+
+#[test]
+fn test_global_override_clashing_with_a_view_is_rejected() {
+    let (_, problems) = Keymap::with_overrides(&overrides(&[("switch_country", &["ctrl+t"])]));
+    assert_eq!(
+        problems,
+        [
+            "Key Ctrl+t is bound to both switch_country and back in top_matches",
+            "Key Ctrl+t is bound to both switch_country and top_matches in dashboard",
+            "Key Ctrl+t is bound to both switch_country and top_matches in search",
+        ]
+    );
+
+    let (_, problems) = Keymap::with_overrides(&overrides(&[("global.help", &["h"])]));
+    assert_eq!(problems, ["Global key h would block typing a search"]);
+}
+
+#[test]
+fn test_unknown_names_are_reported() {
+    let (_, problems) = Keymap::with_overrides(&overrides(&[
+        ("fly", &["x"]),
+        ("menu.quit", &["q"]),
+        ("quit", &["ctrl+q", "nope"]),
+    ]));
+    assert_eq!(
+        problems,
+        ["Unknown key action 'fly'", "Unknown key scope 'menu'", "Unknown key 'nope' for quit"]
+    );
+}
+
+#[test]
+fn test_override_of_an_unbound_action_needs_a_scope() {
+    let (_, problems) = Keymap::with_overrides(&overrides(&[("normal_mode", &["f6"])]));
+    assert_eq!(
+        problems,
+        ["Key action 'normal_mode' isn't bound anywhere to rebind; name a view, as in 'search.normal_mode'"]
+    );
+
+    let (keymap, problems) = Keymap::with_overrides(&overrides(&[("search.normal_mode", &["f6"])]));
+    assert!(problems.is_empty());
+    let f6 = KeyEvent::new(KeyCode::F(6), KeyModifiers::NONE);
+    assert_eq!(keymap.action(Scope::Search, &f6), Some(KeyAction::NormalMode));
+}
+
+#[test]
+fn test_conflicts_are_reported_once() {
+    let (_, problems) = Keymap::with_overrides(&overrides(&[("global.help", &["z", "y", "z"])]));
+    assert_eq!(
+        problems,
+        ["Global key y would block typing a search", "Global key z would block typing a search"]
+    );
+}
+
+#[test]
+fn test_hints_follow_the_active_bindings() {
+    let (keymap, _) = Keymap::with_overrides(&overrides(&[("switch_country", &["ctrl+o"])]));
+    let hints = keymap.hints(Scope::Search);
+
+    assert_eq!(hints[0], ("Quit", "Esc".to_string(), true));
+    assert!(hints.contains(&("Country", "Ctrl+o".to_string(), false)));
+
+    let hints = keymap.hints(Scope::League);
+    assert!(hints.contains(&("Tabs", "Tab/Shift+Tab".to_string(), false)));
+}
+
+#[test]
+fn test_help_lists_view_then_global_bindings() {
+    let help = Keymap::default().help(Scope::TopMatches);
+    assert_eq!(help[0], ("Esc, Ctrl+t".to_string(), "Back to search"));
+    let f1 = help.iter().position(|entry| *entry == ("F1".to_string(), "Show or hide this help"));
+    let question = help.iter().position(|entry| *entry == ("?".to_string(), "Show or hide this help"));
+    assert!(question.is_some() && question < f1, "View bindings come before global ones");
+}
+
+// ── Vim keys tests ───────────────────────────────────────────────────────
+
+#[test]
+fn test_vim_keys_have_no_conflicts() {
+    assert!(Keymap::vim().conflicts().is_empty());
+}
+
+#[test]
+fn test_vim_normal_mode_falls_back_to_search_bindings() {
+    let keymap = Keymap::vim();
+    let j = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+    let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+    assert_eq!(keymap.lookup(Scope::Normal, &j), Some(KeyAction::Down));
+    assert_eq!(keymap.lookup(Scope::Search, &j), None, "Insert mode types it");
+    assert_eq!(keymap.lookup(Scope::Normal, &ctrl_s), Some(KeyAction::SaveFavorite));
+}
+
+#[test]
+fn test_vim_hints_and_help() {
+    let keymap = Keymap::vim();
+
+    let hints = keymap.hints(Scope::Normal);
+    assert!(hints.contains(&("First/Last", "gg/G".to_string(), false)));
+    assert!(hints.contains(&("Quit", "q".to_string(), true)));
+
+    // Esc stops typing, so insert mode shows no Quit hint
+    let hints = keymap.hints(Scope::Search);
+    assert!(!hints.iter().any(|(label, _, _)| *label == "Quit"));
+    assert!(hints.contains(&("Normal", "Esc".to_string(), false)));
+
+    let help = keymap.help(Scope::Normal);
+    assert!(help.contains(&("gg".to_string(), "Jump to the first row")));
+    assert!(!help.iter().any(|(_, text)| *text == "Stop typing (normal mode)"));
+}
@@ -0,0 +1,115 @@
+use foot_info_tui::palette::{candidates, fuzzy_score, remember, Candidate, Command, MAX_RECENT};
+
+fn commands(input: &str, recent: &[String]) -> Vec<Command> {
+    candidates(input, recent).into_iter().map(|c| c.command).collect()
+}
+
+// ── fuzzy_score tests ────────────────────────────────────────────────────
+
+#[test]
+fn test_fuzzy_matches_subsequences_only() {
+    assert!(fuzzy_score("swc", "Switch country").is_some());
+    assert!(fuzzy_score("SWITCH", "Switch country").is_some());
+    assert!(fuzzy_score("cs", "Switch country").is_none(), "Order matters");
+    assert!(fuzzy_score("xyz", "Switch country").is_none());
+}
+
+#[test]
+fn test_fuzzy_prefers_word_starts_and_runs() {
+    let prefix = fuzzy_score("exp", "Export calendar").unwrap();
+    let scattered = fuzzy_score("exp", "Next zone help").unwrap_or(i32::MIN);
+    assert!(prefix > scattered);
+
+    let word_start = fuzzy_score("tm", "Top matches").unwrap();
+    let mid_word = fuzzy_score("tm", "Autumn").unwrap();
+    assert!(word_start > mid_word);
+}
+
+// ── candidates tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_empty_input_lists_recent_commands_first() {
+    let recent = vec!["export".to_string(), "league".to_string()];
+    let listed = commands("", &recent);
+
+    assert_eq!(listed.len(), Command::ALL.len());
+    assert_eq!(&listed[..3], &[Command::Export, Command::League, Command::Search]);
+}
+
+#[test]
+fn test_fuzzy_query_ranks_best_match_first() {
+    assert_eq!(commands("exp", &[])[0], Command::Export);
+    assert_eq!(commands("top matches", &[])[0], Command::TopMatches);
+    assert_eq!(commands("swc", &[])[0], Command::Country);
+}
+
+#[test]
+fn test_recent_commands_win_ties() {
+    // "s" starts Search, Switch country, Save favorite and Show keys alike
+    assert_eq!(commands("s", &[])[0], Command::Search);
+    assert_eq!(commands("s", &["help".to_string()])[0], Command::Help);
+}
+
+#[test]
+fn test_keyword_with_arguments() {
+    assert_eq!(
+        candidates("league serie a", &[]),
+        [Candidate {
+            command: Command::League,
+            args: "serie a".into()
+        }]
+    );
+    assert_eq!(
+        candidates("Search  lyon fr ", &[]),
+        [Candidate {
+            command: Command::Search,
+            args: "lyon fr".into()
+        }]
+    );
+}
+
+#[test]
+fn test_unmatched_input_is_offered_as_a_search() {
+    assert_eq!(
+        candidates("zzz", &[]),
+        [Candidate {
+            command: Command::Search,
+            args: "zzz".into()
+        }]
+    );
+}
+
+// ── remember tests ───────────────────────────────────────────────────────
+
+#[test]
+fn test_remember_moves_command_to_front_and_caps() {
+    let mut recent: Vec<String> = Command::ALL.iter().map(|c| c.keyword().to_string()).collect();
+    recent.truncate(MAX_RECENT);
+
+    remember(&mut recent, Command::Quit);
+    assert_eq!(recent[0], "quit");
+    assert_eq!(recent.len(), MAX_RECENT);
+
+    remember(&mut recent, Command::League);
+    assert_eq!(recent[..2], ["league", "quit"]);
+    assert_eq!(recent.iter().filter(|r| *r == "league").count(), 1);
+}
+
+#[test]
+fn test_favorite_commands_take_arguments() {
+    assert_eq!(
+        candidates("add lyon fr", &[]),
+        [Candidate {
+            command: Command::AddFavorite,
+            args: "lyon fr".into()
+        }]
+    );
+    assert_eq!(
+        candidates("move arsenal 2", &[]),
+        [Candidate {
+            command: Command::MoveFavorite,
+            args: "arsenal 2".into()
+        }]
+    );
+    assert_eq!(commands("fav", &[])[0], Command::Favorites);
+}
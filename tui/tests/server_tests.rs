@@ -0,0 +1,297 @@
+use async_trait::async_trait;
+use axum::body::{self, Body};
+use axum::http::{header, Request, StatusCode};
+use foot_info_core::client::FootballClient;
+use foot_info_core::error::AppError;
+use foot_info_core::models::{Country, Match};
+use foot_info_core::providers::FootballProvider;
+use foot_info_core::utils::time::DisplayZone;
+use foot_info_tui::server::{self, FeedTeam, ServerState, TtlCache};
+use serde_json::Value;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tower::ServiceExt;
+
+/// Stand-in provider: knows "Arsenal" only, and counts its fetches.
+struct FakeProvider {
+    country: Country,
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl FootballProvider for FakeProvider {
+    async fn fetch_matches_channels(&self, team: &str) -> Result<Vec<Match>, AppError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if !team.eq_ignore_ascii_case("arsenal") {
+            return Err(AppError::TeamNotFound(team.to_string()));
+        }
+        if self.country == Country::FR {
+            return Err(AppError::ProviderError("Layout changed".to_string()));
+        }
+        Ok(vec![Match {
+            teams: "Arsenal v Chelsea".into(),
+            competition: "Premier League".into(),
+            date: "Sat 21 Feb 2026".into(),
+            time: "15:00".into(),
+            kickoff: Some("2026-02-21T15:00:00Z".parse().unwrap()),
+            channels: vec![format!("{} TV", self.country)],
+        }])
+    }
+
+    fn team_url(&self, team: &str) -> String {
+        format!("https://fake.test/{}", team)
+    }
+
+    fn country(&self) -> Country {
+        self.country
+    }
+
+    fn name(&self) -> &str {
+        "Fake"
+    }
+}
+
+fn fake_state(calls: &Arc<AtomicUsize>) -> Arc<ServerState> {
+    let providers: Vec<Arc<dyn FootballProvider>> = [Country::UK, Country::US, Country::FR]
+        .into_iter()
+        .map(|country| {
+            Arc::new(FakeProvider {
+                country,
+                calls: calls.clone(),
+            }) as Arc<dyn FootballProvider>
+        })
+        .collect();
+    let client = FootballClient::with_providers(providers, DisplayZone::Local);
+    Arc::new(ServerState::new(client, Duration::from_secs(60)))
+}
+
+async fn get(state: &Arc<ServerState>, uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = server::router(state.clone()).oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+async fn get_text(state: &Arc<ServerState>, uri: &str) -> (StatusCode, String, String) {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = server::router(state.clone()).oneshot(request).await.unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let bytes = body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+// ── Route tests ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_search_country_returns_matches() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    let (status, json) = get(&state, "/api/search/us/Arsenal").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json[0]["teams"], "Arsenal v Chelsea");
+    assert_eq!(json[0]["channels"][0], "US TV");
+    assert_eq!(json[0]["kickoff"], "2026-02-21T15:00:00Z");
+}
+
+#[tokio::test]
+async fn test_search_country_maps_errors_to_status_codes() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    let (status, json) = get(&state, "/api/search/uk/Nobody").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"]["kind"], "team_not_found");
+
+    let (status, json) = get(&state, "/api/search/fr/Arsenal").await;
+    assert_eq!(status, StatusCode::BAD_GATEWAY);
+    assert_eq!(json["error"]["kind"], "provider_error");
+
+    let (status, json) = get(&state, "/api/search/de/Arsenal").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"]["kind"], "bad_request");
+}
+
+#[tokio::test]
+async fn test_search_all_reports_each_country() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    let (status, json) = get(&state, "/api/search/Arsenal").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let results = json.as_array().unwrap();
+    let countries: Vec<&str> = results.iter().map(|r| r["country"].as_str().unwrap()).collect();
+    assert_eq!(countries, ["UK", "US", "FR"]);
+    assert_eq!(results[0]["matches"][0]["channels"][0], "UK TV");
+    assert!(results[0]["error"].is_null());
+    assert_eq!(results[2]["matches"].as_array().unwrap().len(), 0);
+    assert_eq!(results[2]["error"]["kind"], "provider_error");
+}
+
+#[tokio::test]
+async fn test_unknown_competition_is_not_found() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    let (status, json) = get(&state, "/api/league?competition=nowhere").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"]["kind"], "not_found");
+}
+
+#[tokio::test]
+async fn test_off_site_urls_are_rejected() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    for uri in [
+        "/api/league?competition=http://192.168.1.1/admin",
+        "/api/league?competition=https://evil.test/premier-league/",
+        "/api/match?url=http://www.livesoccertv.com/match/arsenal-vs-chelsea/1",
+        "/api/match?url=https://livesoccertv.com.evil.test/match/1",
+        "/api/match?url=https://www.livesoccertv.com:8080/match/1",
+    ] {
+        let (status, json) = get(&state, uri).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+        assert_eq!(json["error"]["kind"], "bad_request");
+    }
+}
+
+#[tokio::test]
+async fn test_repeated_search_is_served_from_cache() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    get(&state, "/api/search/uk/Arsenal").await;
+    get(&state, "/api/search/uk/arsenal").await;
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "Second search should hit the cache");
+
+    // Failures are fetched again every time
+    get(&state, "/api/search/uk/Nobody").await;
+    get(&state, "/api/search/uk/Nobody").await;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+// ── Feed tests ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_ics_feed_lists_team_matches() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    let (status, content_type, body) = get_text(&state, "/feeds/arsenal.ics?country=us").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "text/calendar; charset=utf-8");
+    assert!(body.contains("X-WR-CALNAME:Arsenal"));
+    assert!(body.contains("DTSTART:20260221T150000Z"));
+    assert!(body.contains("US TV"));
+}
+
+#[tokio::test]
+async fn test_atom_feed_shares_the_search_cache() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    get(&state, "/api/search/uk/Arsenal").await;
+    let (status, content_type, body) = get_text(&state, "/feeds/arsenal.atom").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/atom+xml; charset=utf-8");
+    assert!(body.contains("<title>Arsenal v Chelsea</title>"));
+    assert!(body.contains("UK TV"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "Feed should be answered from the cache");
+}
+
+#[tokio::test]
+async fn test_atom_entries_stay_put_across_countries() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+    let updated = |body: &str| {
+        let start = body.find("<entry>").unwrap();
+        body[start..].split("<updated>").nth(1).unwrap().split('<').next().unwrap().to_string()
+    };
+
+    let (_, _, uk) = get_text(&state, "/feeds/arsenal.atom?country=uk").await;
+    // Entry times are to the second
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let (_, _, us) = get_text(&state, "/feeds/arsenal.atom?country=us").await;
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let (_, _, uk_again) = get_text(&state, "/feeds/arsenal.atom?country=uk").await;
+    let (_, _, us_again) = get_text(&state, "/feeds/arsenal.atom?country=us").await;
+
+    assert_eq!(updated(&uk), updated(&uk_again));
+    assert_eq!(updated(&us), updated(&us_again));
+}
+
+#[tokio::test]
+async fn test_feed_errors() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    let (status, json) = get(&state, "/feeds/arsenal.rss").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"]["kind"], "not_found");
+
+    let (status, json) = get(&state, "/feeds/nobody.ics").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"]["kind"], "team_not_found");
+
+    let (status, _) = get(&state, "/feeds/arsenal.ics?country=de").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_refresh_team_bypasses_the_cache() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+
+    state.search_team("Arsenal", Country::UK).await.unwrap();
+    state.search_team("Arsenal", Country::UK).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    state.refresh_team("Arsenal", Country::UK).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_feed_refresh_runs_on_a_schedule() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let state = fake_state(&calls);
+    let teams = vec![FeedTeam { team: "Arsenal".into(), country: Country::UK }];
+
+    let refresher = server::spawn_feed_refresh(state.clone(), teams, Duration::from_millis(10));
+    tokio::time::sleep(Duration::from_millis(35)).await;
+    refresher.abort();
+
+    assert!(calls.load(Ordering::SeqCst) >= 2, "Feeds should be refetched every interval");
+}
+
+// ── TtlCache tests ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_cache_expires_after_ttl() {
+    let cache: TtlCache<&str, u32> = TtlCache::new(Duration::from_millis(20));
+    cache.insert("key", 1);
+    assert_eq!(cache.get(&"key"), Some(1));
+
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(cache.get(&"key"), None);
+
+    let value = cache.get_or_fetch("key", || async { Ok(2) }).await.unwrap();
+    assert_eq!(value, 2);
+    assert_eq!(cache.get(&"key"), Some(2));
+}
+
+#[test]
+fn test_status_codes_follow_error_kinds() {
+    assert_eq!(server::status_code(&AppError::TeamNotFound("x".into())), StatusCode::NOT_FOUND);
+    assert_eq!(server::status_code(&AppError::NoMatchesScheduled("x".into())), StatusCode::NOT_FOUND);
+    assert_eq!(server::status_code(&AppError::ProviderError("x".into())), StatusCode::BAD_GATEWAY);
+}
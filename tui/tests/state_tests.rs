@@ -1,5 +1,7 @@
 use foot_info_core::models::Country;
-use foot_info_tui::config::Config;
+use foot_info_core::utils::format::{HourCycle, Locale};
+use foot_info_core::utils::time::DisplayZone;
+use foot_info_tui::config::{Config, Favorite};
 use foot_info_tui::models::ViewMode;
 use foot_info_tui::state::AppState;
 
@@ -102,7 +104,7 @@ fn test_country_display_fr() {
 #[test]
 fn test_config_default_has_no_favorite() {
     let config = Config::default();
-    assert!(config.favorite_team.is_none());
+    assert!(config.favorites.is_empty());
 }
 
 #[test]
@@ -110,23 +112,89 @@ fn test_config_load_returns_config() {
     // Config::load() should always return a Config (either from file or default)
     let config = Config::load();
     // Just verify it doesn't panic and returns something
-    let _ = config.favorite_team;
+    let _ = config.favorites;
 }
 
 #[test]
 fn test_config_save_and_load_roundtrip() {
-    // Load current config to restore later
-    let original = Config::load();
+    let path = std::env::temp_dir().join(format!("foot-info-config-roundtrip-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut config = Config::at(path.clone());
+    assert!(config.favorites.is_empty(), "A missing file reads as the defaults");
+    config.favorites = vec![Favorite::new("TestTeam123", Country::FR)];
+    config.save().expect("Failed to save config");
+
+    let loaded = Config::at(path.clone());
+    assert_eq!(loaded.favorites, [Favorite::new("TestTeam123", Country::FR)]);
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_config_timezone_maps_to_display_zone() {
+    let mut config = Config::default();
+    assert_eq!(config.display_zone(), DisplayZone::Local);
+
+    config.timezone = Some("Europe/Bucharest".to_string());
+    assert_eq!(config.display_zone(), DisplayZone::parse("Europe/Bucharest").unwrap());
+    assert!(matches!(config.display_zone(), DisplayZone::Named(_)));
+
+    // Unknown names fall back to the machine's zone
+    config.timezone = Some("Nowhere/Special".to_string());
+    assert_eq!(config.display_zone(), DisplayZone::Local);
+}
+
+#[test]
+fn test_config_locale_and_clock_map_to_format() {
+    let mut config = Config::default();
+    assert_eq!(config.locale(), Locale::En);
+    assert_eq!(config.hour_cycle(), HourCycle::H24);
+
+    config.locale = Some("fr-FR".to_string());
+    config.clock = Some("12h".to_string());
+    assert_eq!(config.locale(), Locale::Fr);
+    assert_eq!(config.hour_cycle(), HourCycle::H12);
 
-    // Save a test value
-    let mut test_config = Config::default();
-    test_config.favorite_team = Some("TestTeam123".to_string());
-    test_config.save().expect("Failed to save config");
+    // Unsupported values fall back to the defaults
+    config.locale = Some("it".to_string());
+    config.clock = Some("ampm".to_string());
+    assert_eq!(config.locale(), Locale::En);
+    assert_eq!(config.hour_cycle(), HourCycle::H24);
+}
+
+#[test]
+fn test_config_without_timezone_field_still_loads() {
+    let config: Config = serde_json::from_str(r#"{"favorite_team":"Arsenal"}"#).unwrap();
+    assert_eq!(config.favorite_team.as_deref(), Some("Arsenal"));
+    assert!(config.timezone.is_none());
+}
+
+#[test]
+fn test_config_single_favorite_becomes_first_of_list() {
+    let mut config: Config = serde_json::from_str(r#"{"favorite_team":"Arsenal"}"#).unwrap();
+    config.migrate_favorite();
+    assert_eq!(config.favorites, [Favorite::new("Arsenal", Country::UK)]);
+    assert!(config.favorite_team.is_none());
+
+    let saved = serde_json::to_string(&config).unwrap();
+    assert!(!saved.contains("favorite_team"), "Only the list is saved: {}", saved);
+    assert!(saved.contains(r#""country":"uk""#), "{}", saved);
+}
+
+#[test]
+fn test_config_favorites_are_unique_and_movable() {
+    let mut config = Config::default();
+    assert!(config.add_favorite("Arsenal", Country::UK));
+    assert!(config.add_favorite(" Lyon ", Country::FR));
+    assert!(!config.add_favorite("arsenal", Country::UK), "Same team and country");
+    assert!(config.add_favorite("Arsenal", Country::US), "Another country's listings");
+    assert!(!config.add_favorite("  ", Country::UK));
 
-    // Load it back
-    let loaded = Config::load();
-    assert_eq!(loaded.favorite_team, Some("TestTeam123".to_string()));
+    assert_eq!(config.find_favorite("LYON"), Some(1));
+    assert_eq!(config.move_favorite(2, 0), Some(0));
+    assert_eq!(config.move_favorite(0, 10), Some(2), "Clamped to the end");
+    assert_eq!(config.move_favorite(5, 0), None);
 
-    // Restore original config
-    original.save().expect("Failed to restore config");
+    let teams: Vec<(&str, Country)> = config.favorites.iter().map(|f| (f.team.as_str(), f.country)).collect();
+    assert_eq!(teams, [("Arsenal", Country::UK), ("Lyon", Country::FR), ("Arsenal", Country::US)]);
 }
@@ -0,0 +1,113 @@
+use foot_info_core::models::{LeagueStats, StandingRow};
+use foot_info_tui::suggest::{self, index_teams, remember, suggest, Source, MAX_HISTORY, MAX_SUGGESTIONS};
+
+fn teams(list: &[&str]) -> Vec<String> {
+    list.iter().map(|t| t.to_string()).collect()
+}
+
+fn table(teams: &[&str]) -> LeagueStats {
+    LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![],
+        table: teams
+            .iter()
+            .enumerate()
+            .map(|(i, team)| StandingRow {
+                position: i as u8 + 1,
+                team: team.to_string(),
+                played: 0,
+                won: 0,
+                drawn: 0,
+                lost: 0,
+                goals_for: 0,
+                goals_against: 0,
+                goal_diff: 0,
+                points: 0,
+                form: vec![],
+                zone: None,
+            })
+            .collect(),
+        top_scorers: vec![],
+        previous_page: None,
+        next_page: None,
+    }
+}
+
+// ── remember tests ───────────────────────────────────────────────────────
+
+#[test]
+fn test_remember_moves_repeat_to_front_ignoring_case() {
+    let mut history = teams(&["Chelsea", "Arsenal"]);
+    remember(&mut history, " arsenal ");
+    assert_eq!(history, ["arsenal", "Chelsea"]);
+
+    remember(&mut history, "  ");
+    assert_eq!(history.len(), 2, "Blank searches aren't kept");
+}
+
+#[test]
+fn test_remember_caps_history() {
+    let mut history = Vec::new();
+    for i in 0..MAX_HISTORY + 5 {
+        remember(&mut history, &format!("Team {}", i));
+    }
+    assert_eq!(history.len(), MAX_HISTORY);
+    assert_eq!(history[0], format!("Team {}", MAX_HISTORY + 4));
+}
+
+// ── suggest tests ────────────────────────────────────────────────────────
+
+#[test]
+fn test_suggest_ranks_fuzzy_matches() {
+    let index = teams(&["Manchester City", "Manchester United", "Newcastle United"]);
+    let suggested = suggest("man utd", &[], &[], &index);
+    assert_eq!(suggested[0].team, "Manchester United");
+    assert!(suggested.iter().all(|s| s.team != "Newcastle United"));
+    assert!(suggest("", &[], &[], &index).is_empty());
+}
+
+#[test]
+fn test_suggest_prefers_history_then_favorites() {
+    let history = teams(&["Lens"]);
+    let index = teams(&["Leeds United", "Lens", "Lyon"]);
+    let suggested = suggest("le", &history, &["Leeds United"], &index);
+
+    assert_eq!(
+        suggested.iter().map(|s| (s.team.as_str(), s.source)).collect::<Vec<_>>(),
+        [("Lens", Source::History), ("Leeds United", Source::Favorite)],
+        "Each team is suggested once, from where it ranks best"
+    );
+}
+
+#[test]
+fn test_suggest_skips_what_is_typed_and_caps() {
+    let index = teams(&["Arsenal", "Aston Villa", "Atalanta", "Athletic Club", "Atletico Madrid", "Augsburg", "Auxerre"]);
+    let suggested = suggest("a", &[], &[], &index);
+    assert_eq!(suggested.len(), MAX_SUGGESTIONS);
+
+    assert!(suggest("ARSENAL", &[], &[], &index).iter().all(|s| s.team != "Arsenal"));
+}
+
+// ── Team index tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_index_teams_adds_new_teams_sorted() {
+    let mut index = teams(&["Chelsea"]);
+    assert!(index_teams(&mut index, &table(&["Liverpool", "arsenal", "CHELSEA"])));
+    assert_eq!(index, ["arsenal", "Chelsea", "Liverpool"]);
+
+    assert!(!index_teams(&mut index, &table(&["Liverpool"])), "Nothing new");
+}
+
+#[test]
+fn test_index_roundtrips_through_file() {
+    let path = std::env::temp_dir().join(format!("foot-info-teams-{}.json", std::process::id()));
+    let index = teams(&["Arsenal", "Chelsea"]);
+    suggest::save_index(&path, &index).unwrap();
+    assert_eq!(suggest::load_index(Some(&path)), index);
+
+    std::fs::write(&path, "not json").unwrap();
+    assert!(suggest::load_index(Some(&path)).is_empty());
+    assert!(suggest::load_index(None).is_empty());
+    let _ = std::fs::remove_file(&path);
+}
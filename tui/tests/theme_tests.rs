@@ -0,0 +1,138 @@
+use foot_info_tui::ui::theme::{ColorDepth, Theme, BUNDLED};
+use ratatui::style::{Color, Modifier};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    move |key| vars.get(key).cloned()
+}
+
+fn themes_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("foot-info-themes-{}-{}", test, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// ── ColorDepth tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_depth_from_env() {
+    assert_eq!(ColorDepth::from_env(env(&[("COLORTERM", "truecolor")])), ColorDepth::TrueColor);
+    assert_eq!(ColorDepth::from_env(env(&[("COLORTERM", "24bit")])), ColorDepth::TrueColor);
+    assert_eq!(ColorDepth::from_env(env(&[("TERM", "xterm-256color")])), ColorDepth::Ansi256);
+    assert_eq!(ColorDepth::from_env(env(&[("TERM", "xterm")])), ColorDepth::Ansi16);
+    assert_eq!(ColorDepth::from_env(env(&[])), ColorDepth::Ansi16);
+}
+
+#[test]
+fn test_no_color_wins_over_everything() {
+    let vars = env(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")]);
+    assert_eq!(ColorDepth::from_env(vars), ColorDepth::NoColor);
+
+    // An empty NO_COLOR doesn't count
+    let vars = env(&[("NO_COLOR", ""), ("TERM", "xterm-256color")]);
+    assert_eq!(ColorDepth::from_env(vars), ColorDepth::Ansi256);
+}
+
+#[test]
+fn test_adapt_downgrades_colours() {
+    let gold = Color::Rgb(240, 165, 0);
+    assert_eq!(ColorDepth::TrueColor.adapt(gold), gold);
+    assert_eq!(ColorDepth::Ansi256.adapt(gold), Color::Indexed(214));
+    assert_eq!(ColorDepth::Ansi16.adapt(gold), Color::Yellow);
+    assert_eq!(ColorDepth::Ansi16.adapt(Color::Indexed(214)), Color::Yellow);
+    assert_eq!(ColorDepth::Ansi256.adapt(Color::Rgb(128, 128, 128)), Color::Indexed(244));
+    assert_eq!(ColorDepth::Ansi16.adapt(Color::Green), Color::Green, "Basic colours are kept");
+    assert_eq!(ColorDepth::NoColor.adapt(gold), Color::Reset);
+}
+
+// ── Theme tests ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_bundled_themes_load_by_name() {
+    for name in BUNDLED {
+        let theme = Theme::bundled(name).unwrap_or_else(|| panic!("{} should be bundled", name));
+        assert_eq!(theme.name, name);
+    }
+    assert_eq!(Theme::default(), Theme::dark());
+    assert!(Theme::bundled("neon").is_none());
+}
+
+#[test]
+fn test_no_color_theme_has_no_colours() {
+    let theme = Theme::solarized().for_depth(ColorDepth::NoColor);
+    for color in [theme.background, theme.text, theme.accent, theme.warning, theme.selection, theme.live] {
+        assert_eq!(color, Color::Reset);
+    }
+    assert!(
+        theme.selected().add_modifier.contains(Modifier::REVERSED),
+        "Without colours the selection is shown reversed"
+    );
+    assert_eq!(theme.fixed(Color::Red), Color::Reset);
+}
+
+#[test]
+fn test_16_colour_theme_uses_basic_colours() {
+    let theme = Theme::dark().for_depth(ColorDepth::Ansi16);
+    assert!(
+        !matches!(theme.background, Color::Rgb(..) | Color::Indexed(_)),
+        "{:?} isn't one of the 16 colours",
+        theme.background
+    );
+    assert_eq!(theme.accent, Color::Yellow);
+    assert!(!theme.selected().add_modifier.contains(Modifier::REVERSED));
+}
+
+#[test]
+fn test_user_theme_overrides_base() {
+    let dir = themes_dir("user");
+    std::fs::write(
+        dir.join("pitch.json"),
+        r##"{ "base": "light", "accent": "#00AA00", "warning": "red", "selection": "240" }"##,
+    )
+    .unwrap();
+
+    let theme = Theme::named("pitch", Some(&dir)).unwrap();
+    assert_eq!(theme.name, "pitch");
+    assert_eq!(theme.accent, Color::Rgb(0, 170, 0));
+    assert_eq!(theme.warning, Color::Red);
+    assert_eq!(theme.selection, Color::Indexed(240));
+    assert_eq!(theme.text, Theme::light().text, "Roles left out come from the base");
+
+    assert_eq!(Theme::available(Some(&dir)).last().map(String::as_str), Some("pitch"));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_user_theme_shadows_bundled_one() {
+    let dir = themes_dir("shadow");
+    std::fs::write(dir.join("dark.json"), r#"{ "accent": "magenta" }"#).unwrap();
+
+    let theme = Theme::named("dark", Some(&dir)).unwrap();
+    assert_eq!(theme.accent, Color::Magenta);
+    assert_eq!(
+        Theme::available(Some(&dir)).iter().filter(|name| *name == "dark").count(),
+        1,
+        "A shadowed theme is listed once"
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_bad_themes_are_reported() {
+    let dir = themes_dir("bad");
+    std::fs::write(dir.join("broken.json"), "{ accent: ").unwrap();
+    std::fs::write(dir.join("odd.json"), r#"{ "accent": "sparkly" }"#).unwrap();
+    std::fs::write(dir.join("orphan.json"), r#"{ "base": "neon" }"#).unwrap();
+
+    assert!(Theme::named("broken", Some(&dir)).unwrap_err().starts_with("Bad theme 'broken'"));
+    assert_eq!(
+        Theme::named("odd", Some(&dir)).unwrap_err(),
+        "Theme 'odd': 'sparkly' is not a colour for accent"
+    );
+    assert_eq!(Theme::named("orphan", Some(&dir)).unwrap_err(), "Theme 'orphan': unknown base 'neon'");
+    assert_eq!(Theme::named("neon", Some(&dir)).unwrap_err(), "Unknown theme 'neon'");
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -1,8 +1,24 @@
-use foot_info_core::models::{Match, TopMatch};
+use chrono_tz::UTC;
+use foot_info_core::changes::{Change, ChangeKind, Listing};
+use foot_info_core::models::{Country, LeagueFixture, LeagueStats, Match, MatchStatus, StandingRow, TopMatch, Zone};
+use foot_info_tui::ui::components::{command_palette, help_overlay, league_table};
+use foot_info_tui::ui::components::league_fixtures::{self, FixtureList};
+use foot_info_tui::ui::components::favorites_dashboard::{self, FavoriteCard};
+use foot_info_tui::ui::components::match_detail::{self, MatchDetail};
 use foot_info_tui::ui::components::match_list::{self, ResultsState};
-use foot_info_tui::ui::components::search_bar;
+use foot_info_tui::ui::components::{search_bar, search_suggestions};
 use foot_info_tui::ui::components::status_bar;
 use foot_info_tui::ui::components::top_matches_list::{self, TopMatchesState};
+use foot_info_core::utils::format::{HourCycle, KickoffFormat, Locale};
+use foot_info_core::utils::time::DisplayZone;
+use foot_info_tui::config::Favorite;
+use foot_info_tui::keymap::{Keymap, Scope};
+use foot_info_tui::models::{FavoriteStatus, FixtureSort, InputMode, TableSort};
+use foot_info_tui::palette::Palette;
+use foot_info_tui::scroll::ListPosition;
+use foot_info_tui::suggest::{Source, Suggestion};
+use foot_info_tui::ui::kickoff::KickoffLabels;
+use foot_info_tui::ui::theme::Theme;
 use ratatui::backend::TestBackend;
 use ratatui::layout::Rect;
 use ratatui::Terminal;
@@ -29,7 +45,7 @@ fn test_search_bar_renders_prompt() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 60, 3);
-            search_bar::render(f, area, "Arsenal");
+            search_bar::render(f, area, "Arsenal", None, &Theme::default());
         })
         .unwrap();
 
@@ -49,7 +65,7 @@ fn test_search_bar_renders_empty_input() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 60, 3);
-            search_bar::render(f, area, "");
+            search_bar::render(f, area, "", None, &Theme::default());
         })
         .unwrap();
 
@@ -60,6 +76,61 @@ fn test_search_bar_renders_empty_input() {
     );
 }
 
+#[test]
+fn test_search_bar_shows_vim_mode() {
+    let backend = TestBackend::new(60, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 60, 3);
+            search_bar::render(f, area, "Arsenal", Some(InputMode::Normal), &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("-- NORMAL --"));
+}
+
+// ── search_suggestions tests ─────────────────────────────────────────────
+
+#[test]
+fn test_search_suggestions_render_under_input_with_source() {
+    let backend = TestBackend::new(60, 12);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let suggestions = vec![
+        Suggestion { team: "Manchester United".into(), source: Source::History },
+        Suggestion { team: "Manchester City".into(), source: Source::League },
+    ];
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 60, 12);
+            search_suggestions::render(f, area, Rect::new(0, 0, 60, 3), &suggestions, Some(1), &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[4].contains("Manchester United") && lines[4].contains("recent"));
+    assert!(lines[5].contains(">> Manchester City") && lines[5].contains("league"));
+    assert!(lines[2].trim().is_empty(), "The input is left alone");
+}
+
+#[test]
+fn test_search_suggestions_render_nothing_when_empty() {
+    let backend = TestBackend::new(60, 8);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|f| {
+            search_suggestions::render(f, Rect::new(0, 0, 60, 8), Rect::new(0, 0, 60, 3), &[], None, &Theme::default());
+        })
+        .unwrap();
+
+    assert!(buffer_to_string(&terminal).trim().is_empty());
+}
+
 // ── status_bar tests ─────────────────────────────────────────────────────
 
 #[test]
@@ -70,7 +141,7 @@ fn test_status_bar_renders_message() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 60, 1);
-            status_bar::render(f, area, Some("Saved favorite: Arsenal"));
+            status_bar::render(f, area, Some("Saved favorite: Arsenal"), &Theme::default());
         })
         .unwrap();
 
@@ -86,7 +157,7 @@ fn test_status_bar_renders_nothing_when_none() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 60, 1);
-            status_bar::render(f, area, None);
+            status_bar::render(f, area, None, &Theme::default());
         })
         .unwrap();
 
@@ -105,7 +176,7 @@ fn test_match_list_renders_loading() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 80, 10);
-            match_list::render(f, area, &ResultsState::Loading);
+            match_list::render(f, area, &ResultsState::Loading, &KickoffLabels::default(), &[], None, &Theme::default());
         })
         .unwrap();
 
@@ -121,7 +192,7 @@ fn test_match_list_renders_error() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 80, 10);
-            match_list::render(f, area, &ResultsState::Error("Team not found"));
+            match_list::render(f, area, &ResultsState::Error("Team not found"), &KickoffLabels::default(), &[], None, &Theme::default());
         })
         .unwrap();
 
@@ -141,6 +212,7 @@ fn test_match_list_renders_matches() {
             competition: "Premier League".into(),
             date: "Sat 22 Feb".into(),
             time: "15:00".into(),
+            kickoff: None,
             channels: vec!["Sky Sports".into()],
         },
         Match {
@@ -148,6 +220,7 @@ fn test_match_list_renders_matches() {
             competition: "FA Cup".into(),
             date: "Sun 23 Feb".into(),
             time: "14:00".into(),
+            kickoff: None,
             channels: vec![],
         },
     ];
@@ -155,7 +228,7 @@ fn test_match_list_renders_matches() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 100, 15);
-            match_list::render(f, area, &ResultsState::Matches(&matches));
+            match_list::render(f, area, &ResultsState::Matches(&matches), &KickoffLabels::default(), &[], None, &Theme::default());
         })
         .unwrap();
 
@@ -171,6 +244,110 @@ fn test_match_list_renders_matches() {
     );
 }
 
+#[test]
+fn test_match_list_renders_kickoff_in_configured_locale() {
+    let backend = TestBackend::new(100, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let matches = vec![Match {
+        teams: "PSG v Marseille".into(),
+        competition: "Ligue 1".into(),
+        date: "Sun 22 Feb".into(),
+        time: "20:45".into(),
+        kickoff: Some("2026-02-22T20:45:00Z".parse().unwrap()),
+        channels: vec![],
+    }];
+    let labels = KickoffLabels::new(
+        KickoffFormat::new(Locale::Fr, HourCycle::H24, DisplayZone::Source),
+        UTC,
+        "2026-02-22T09:00:00Z".parse().unwrap(),
+    );
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 100, 10);
+            match_list::render(f, area, &ResultsState::Matches(&matches), &labels, &[], None, &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Ce soir"), "Should show the relative day in French");
+    assert!(output.contains("20h45"), "Should use the French time format");
+    assert!(!output.contains("Sun 22 Feb"), "Should not fall back to the site's text");
+}
+
+#[test]
+fn test_match_list_marks_changes_since_last_look() {
+    let backend = TestBackend::new(100, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let matches = vec![Match {
+        teams: "Arsenal v Chelsea".into(),
+        competition: "Premier League".into(),
+        date: "Sat 22 Feb".into(),
+        time: "17:30".into(),
+        kickoff: None,
+        channels: vec!["TNT Sports".into()],
+    }];
+    let listing = Listing::from_match(&matches[0]);
+    let changes = vec![
+        Change {
+            kind: ChangeKind::Rescheduled {
+                from_date: "Sat 22 Feb".into(),
+                from_time: "15:00".into(),
+                from_kickoff: None,
+            },
+            listing: listing.clone(),
+        },
+        Change {
+            kind: ChangeKind::ChannelsChanged {
+                added: vec!["TNT Sports".into()],
+                removed: vec!["Sky Sports".into()],
+            },
+            listing,
+        },
+    ];
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 100, 10);
+            match_list::render(f, area, &ResultsState::Matches(&matches), &KickoffLabels::default(), &changes, None, &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("● rescheduled, channels changed"), "Should mark the changed match");
+}
+
+#[test]
+fn test_match_list_highlights_selected_match() {
+    let backend = TestBackend::new(100, 15);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let matches: Vec<Match> = ["Arsenal v Chelsea", "Liverpool v Man City"]
+        .into_iter()
+        .map(|teams| Match {
+            teams: teams.into(),
+            competition: "Premier League".into(),
+            date: "Sat 22 Feb".into(),
+            time: "15:00".into(),
+            kickoff: None,
+            channels: vec![],
+        })
+        .collect();
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 100, 15);
+            match_list::render(f, area, &ResultsState::Matches(&matches), &KickoffLabels::default(), &[], Some(ListPosition::at(1)), &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    let selected = output.lines().find(|l| l.contains(">> ")).expect("A row should be highlighted");
+    assert!(selected.contains("Liverpool v Man City"));
+}
+
 #[test]
 fn test_match_list_renders_empty() {
     let backend = TestBackend::new(80, 10);
@@ -179,7 +356,7 @@ fn test_match_list_renders_empty() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 80, 10);
-            match_list::render(f, area, &ResultsState::Empty);
+            match_list::render(f, area, &ResultsState::Empty, &KickoffLabels::default(), &[], None, &Theme::default());
         })
         .unwrap();
 
@@ -188,6 +365,72 @@ fn test_match_list_renders_empty() {
     assert_eq!(output.trim(), "");
 }
 
+// ── match_detail tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_match_detail_shows_teams_times_channels_and_source() {
+    let backend = TestBackend::new(90, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let listing = Match {
+        teams: "Paris Saint-Germain - Olympique de Marseille".into(),
+        competition: "Ligue 1".into(),
+        date: "Sun 22 Feb".into(),
+        time: "20:45".into(),
+        kickoff: Some("2026-02-22T19:45:00Z".parse().unwrap()),
+        channels: vec!["Canal+".into(), "France 2".into()],
+    };
+    let detail = MatchDetail {
+        listing: &listing,
+        format: KickoffFormat::new(Locale::En, HourCycle::H24, DisplayZone::Named(UTC)),
+        source: Country::FR.source_zone(),
+        url: Some("https://matchs.tv/club/psg/".into()),
+    };
+
+    terminal
+        .draw(|f| match_detail::render(f, Rect::new(0, 0, 90, 20), &detail, &Theme::default()))
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Home        Paris Saint-Germain"));
+    assert!(output.contains("Away        Olympique de Marseille"));
+    assert!(output.contains("Competition Ligue 1"));
+    assert!(output.contains("Kickoff     Sun 22 Feb 2026 19:45 (UTC+00:00, UTC)"));
+    assert!(output.contains("Listed as   Sun 22 Feb 2026 20:45 (UTC+01:00, Europe/Paris)"));
+    assert!(output.contains("Canal+  pay TV"));
+    assert!(output.contains("France 2  free-to-air"));
+    assert!(output.contains("https://matchs.tv/club/psg/"));
+}
+
+#[test]
+fn test_match_detail_keeps_unparsed_kickoff_as_listed() {
+    let backend = TestBackend::new(90, 16);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let listing = Match {
+        teams: "Premier League Review".into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb".into(),
+        time: "TBC".into(),
+        kickoff: None,
+        channels: vec![],
+    };
+    let detail = MatchDetail {
+        listing: &listing,
+        format: KickoffFormat::default(),
+        source: Country::UK.source_zone(),
+        url: None,
+    };
+
+    terminal
+        .draw(|f| match_detail::render(f, Rect::new(0, 0, 90, 16), &detail, &Theme::default()))
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Teams       Premier League Review"));
+    assert!(output.contains("Kickoff     Sat 21 Feb TBC (as listed)"));
+    assert!(output.contains("No TV info"));
+    assert!(!output.contains("Source"));
+}
+
 // ── top_matches_list tests ───────────────────────────────────────────────
 
 #[test]
@@ -198,7 +441,7 @@ fn test_top_matches_list_renders_loading() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 80, 10);
-            top_matches_list::render(f, area, &TopMatchesState::Loading);
+            top_matches_list::render(f, area, &TopMatchesState::Loading, &KickoffLabels::default(), &Theme::default());
         })
         .unwrap();
 
@@ -214,7 +457,7 @@ fn test_top_matches_list_renders_error() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 80, 10);
-            top_matches_list::render(f, area, &TopMatchesState::Error("Network error"));
+            top_matches_list::render(f, area, &TopMatchesState::Error("Network error"), &KickoffLabels::default(), &Theme::default());
         })
         .unwrap();
 
@@ -233,13 +476,21 @@ fn test_top_matches_list_renders_matches_with_columns() {
             teams: "Team A - Team B".into(),
             date: "Mon 20 Feb 2026".into(),
             time: "20:00".into(),
+            kickoff: None,
             match_url: "/match/1".into(),
+            competition: None,
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
         TopMatch {
             teams: "Team C - Team D".into(),
             date: "Tue 21 Feb 2026".into(),
             time: "18:00".into(),
+            kickoff: None,
             match_url: "/match/2".into(),
+            competition: None,
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
     ];
 
@@ -251,8 +502,10 @@ fn test_top_matches_list_renders_matches_with_columns() {
                 area,
                 &TopMatchesState::Matches {
                     matches: &matches,
-                    selected_index: 0,
+                    position: ListPosition::at(0),
                 },
+                &KickoffLabels::default(),
+                &Theme::default(),
             );
         })
         .unwrap();
@@ -276,6 +529,142 @@ fn test_top_matches_list_renders_matches_with_columns() {
     );
 }
 
+#[test]
+fn test_top_matches_list_renders_competition_channels_and_status() {
+    let backend = TestBackend::new(100, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let matches = vec![TopMatch {
+        teams: "Team A - Team B".into(),
+        date: "Mon 20 Feb 2026".into(),
+        time: "20:00".into(),
+        kickoff: None,
+        match_url: "/match/a-vs-b/1#1".into(),
+        competition: Some("England - Premier League".into()),
+        channels: vec!["Sky Sports".into(), "NBC".into(), "Peacock".into()],
+        status: MatchStatus::Live,
+    }];
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 100, 20);
+            top_matches_list::render(
+                f,
+                area,
+                &TopMatchesState::Matches {
+                    matches: &matches,
+                    position: ListPosition::at(0),
+                },
+                &KickoffLabels::default(),
+                &Theme::default(),
+            );
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("England - Premier League"), "Should show competition");
+    assert!(output.contains("Sky Sports, NBC +1"), "Should summarise channels");
+    assert!(output.contains("Live"), "Should show live status");
+}
+
+fn countdown_match(teams: &str, kickoff: &str, status: MatchStatus) -> TopMatch {
+    TopMatch {
+        teams: teams.into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "".into(),
+        kickoff: Some(kickoff.parse().unwrap()),
+        match_url: "".into(),
+        competition: None,
+        channels: vec![],
+        status,
+    }
+}
+
+fn labels_at(now: &str) -> KickoffLabels {
+    KickoffLabels::new(
+        KickoffFormat::new(Locale::En, HourCycle::H24, DisplayZone::Source),
+        UTC,
+        now.parse().unwrap(),
+    )
+}
+
+#[test]
+fn test_top_matches_list_renders_countdowns() {
+    let backend = TestBackend::new(120, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let matches = vec![
+        countdown_match("Early - Game", "2026-02-21T12:30:00Z", MatchStatus::Finished),
+        countdown_match("Now - Playing", "2026-02-21T16:30:00Z", MatchStatus::Live),
+        countdown_match("Soon - Starting", "2026-02-21T17:45:00Z", MatchStatus::Upcoming),
+        countdown_match("Late - Kickoff", "2026-02-21T20:15:00Z", MatchStatus::Upcoming),
+    ];
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 120, 30);
+            top_matches_list::render(
+                f,
+                area,
+                &TopMatchesState::Matches {
+                    matches: &matches,
+                    position: ListPosition::at(0),
+                },
+                &labels_at("2026-02-21T17:00:00Z"),
+                &Theme::default(),
+            );
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("finished 2h ago"), "Should count from the final whistle");
+    assert!(output.contains("LIVE"), "Should show live matches");
+    assert!(output.contains("in 45m"), "Should count down to kickoff");
+    assert!(output.contains("in 3h 15m"), "Should show hours and minutes");
+}
+
+#[test]
+fn test_top_matches_list_highlights_matches_within_the_hour() {
+    let backend = TestBackend::new(100, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let matches = vec![
+        countdown_match("Soon - Starting", "2026-02-21T17:45:00Z", MatchStatus::Upcoming),
+        countdown_match("Late - Kickoff", "2026-02-21T20:15:00Z", MatchStatus::Upcoming),
+    ];
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 100, 20);
+            top_matches_list::render(
+                f,
+                area,
+                &TopMatchesState::Matches {
+                    matches: &matches,
+                    position: ListPosition::at(0),
+                },
+                &labels_at("2026-02-21T17:00:00Z"),
+                &Theme::default(),
+            );
+        })
+        .unwrap();
+
+    let buf = terminal.backend().buffer();
+    let row_of = |needle: &str| {
+        (0..buf.area.height)
+            .find(|&y| {
+                let line: String = (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect();
+                line.contains(needle)
+            })
+            .unwrap()
+    };
+
+    let soon = row_of("Soon - Starting");
+    let late = row_of("Late - Kickoff");
+    assert_eq!(buf[(5, soon)].bg, Theme::default().soon, "Imminent match should be highlighted");
+    assert_ne!(buf[(5, late)].bg, Theme::default().soon, "Later matches should not be highlighted");
+}
+
 #[test]
 fn test_top_matches_list_renders_selection_marker() {
     let backend = TestBackend::new(100, 20);
@@ -286,13 +675,21 @@ fn test_top_matches_list_renders_selection_marker() {
             teams: "Team A - Team B".into(),
             date: "Mon 20 Feb 2026".into(),
             time: "20:00".into(),
+            kickoff: None,
             match_url: "/match/1".into(),
+            competition: None,
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
         TopMatch {
             teams: "Team C - Team D".into(),
             date: "Mon 20 Feb 2026".into(),
             time: "21:00".into(),
+            kickoff: None,
             match_url: "/match/2".into(),
+            competition: None,
+            channels: vec![],
+            status: MatchStatus::Upcoming,
         },
     ];
 
@@ -304,8 +701,10 @@ fn test_top_matches_list_renders_selection_marker() {
                 area,
                 &TopMatchesState::Matches {
                     matches: &matches,
-                    selected_index: 0,
+                    position: ListPosition::at(0),
                 },
+                &KickoffLabels::default(),
+                &Theme::default(),
             );
         })
         .unwrap();
@@ -325,10 +724,310 @@ fn test_top_matches_list_renders_empty() {
     terminal
         .draw(|f| {
             let area = Rect::new(0, 0, 80, 10);
-            top_matches_list::render(f, area, &TopMatchesState::Empty);
+            top_matches_list::render(f, area, &TopMatchesState::Empty, &KickoffLabels::default(), &Theme::default());
         })
         .unwrap();
 
     let output = buffer_to_string(&terminal);
     assert_eq!(output.trim(), "");
 }
+
+// ── league_table tests ───────────────────────────────────────────────────
+
+fn standing(position: u8, team: &str, zone: Option<&str>) -> StandingRow {
+    StandingRow {
+        position,
+        team: team.into(),
+        played: 10,
+        won: 5,
+        drawn: 3,
+        lost: 2,
+        goals_for: 15,
+        goals_against: 10,
+        goal_diff: 5,
+        points: 18,
+        form: vec![],
+        zone: zone.and_then(Zone::from_label),
+    }
+}
+
+#[test]
+fn test_league_table_renders_zone_legend_and_colours() {
+    let backend = TestBackend::new(80, 12);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let stats = LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![],
+        table: vec![
+            standing(1, "Arsenal", Some("Champions League")),
+            standing(2, "Chelsea", None),
+            standing(3, "Burnley", Some("Relegation")),
+        ],
+        top_scorers: vec![],
+        previous_page: None,
+        next_page: None,
+    };
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 80, 12);
+            let rows: Vec<&StandingRow> = stats.table.iter().collect();
+            league_table::draw(f, area, &stats, &rows, ListPosition::at(1), TableSort::Position, &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Champions League"), "Legend should list zones");
+    assert!(output.contains("Relegation"), "Legend should list zones");
+
+    let colors = league_table::zone_colors(&stats.zones());
+    assert_eq!(colors.len(), 2);
+    assert_ne!(colors[0].1, colors[1].1, "Zones should get distinct colours");
+}
+
+#[test]
+fn test_league_table_marks_sort_column_and_shows_form() {
+    let backend = TestBackend::new(80, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let mut leader = standing(1, "Arsenal", None);
+    leader.form = vec!['L', 'L', 'D'];
+    let mut chaser = standing(2, "Chelsea", None);
+    chaser.form = vec!['W', 'W', 'W'];
+    let stats = LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![],
+        table: vec![leader, chaser],
+        top_scorers: vec![],
+        previous_page: None,
+        next_page: None,
+    };
+
+    terminal
+        .draw(|f| {
+            let rows = TableSort::Form.sorted(&stats.table);
+            league_table::draw(f, Rect::new(0, 0, 80, 10), &stats, &rows, ListPosition::at(0), TableSort::Form, &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Form▼"), "Sorted column should be marked");
+    let chelsea = output.find("Chelsea").unwrap();
+    assert!(chelsea < output.find("Arsenal").unwrap(), "Better form should come first");
+    assert!(output.contains("WWW"));
+}
+
+// ── league_fixtures tests ────────────────────────────────────────────────
+
+fn fixture(home: &str, away: &str, score: Option<&str>, matchday: Option<u16>) -> LeagueFixture {
+    LeagueFixture {
+        home_team: home.into(),
+        away_team: away.into(),
+        date: "Sat 21 Mar 2026".into(),
+        time: "15:00".into(),
+        kickoff: None,
+        score: score.map(Into::into),
+        channels: vec![],
+        match_url: "/match/1".into(),
+        matchday,
+    }
+}
+
+#[test]
+fn test_league_fixtures_groups_by_round() {
+    let backend = TestBackend::new(80, 12);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let stats = LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![
+            fixture("Everton", "Chelsea", Some("3 - 0"), Some(31)),
+            fixture("Arsenal", "Fulham", None, Some(32)),
+        ],
+        table: vec![],
+        top_scorers: vec![],
+        previous_page: None,
+        next_page: None,
+    };
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 80, 12);
+            let fixtures: Vec<&LeagueFixture> = stats.fixtures.iter().collect();
+            let list = FixtureList { fixtures: &fixtures, by_round: true, selected_index: 1, offset: 0 };
+            league_fixtures::draw(f, area, &list, &KickoffLabels::default(), &[], &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Matchday 31 · Results"));
+    assert!(output.contains("Matchday 32 · Upcoming"));
+    assert!(output.contains("Everton 3 - 0 Chelsea"), "Results should show the score");
+    assert!(output.contains(">> "), "Selected fixture should be highlighted");
+}
+
+#[test]
+fn test_league_fixtures_sorted_by_team_drop_round_headers() {
+    let backend = TestBackend::new(80, 12);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let everton = fixture("Everton", "Chelsea", Some("3 - 0"), Some(31));
+    let arsenal = fixture("Arsenal", "Fulham", None, Some(32));
+
+    terminal
+        .draw(|f| {
+            let fixtures = FixtureSort::Team.sorted([&everton, &arsenal]);
+            let list = FixtureList { fixtures: &fixtures, by_round: false, selected_index: 0, offset: 0 };
+            league_fixtures::draw(f, Rect::new(0, 0, 80, 12), &list, &KickoffLabels::default(), &[], &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(!output.contains("Matchday"), "Rounds aren't together once sorted by team");
+    assert!(output.find("Arsenal").unwrap() < output.find("Everton").unwrap());
+}
+
+// ── help_overlay tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_help_overlay_lists_active_bindings() {
+    let backend = TestBackend::new(80, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let help = Keymap::default().help(Scope::League);
+
+    terminal
+        .draw(|f| {
+            help_overlay::render(f, Rect::new(0, 0, 80, 30), &help, &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("any key to close"));
+    assert!(output.contains("Shift+Tab"));
+    assert!(output.contains("Refresh"), "View bindings should be listed");
+    assert!(output.contains("Ctrl+c"), "Global bindings should be listed");
+}
+
+// ── command_palette tests ────────────────────────────────────────────────
+
+#[test]
+fn test_command_palette_lists_recent_commands_first() {
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let recent = vec!["export".to_string()];
+
+    terminal
+        .draw(|f| {
+            command_palette::render(f, Rect::new(0, 0, 80, 24), &Palette::default(), &recent, &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    let first = output.lines().find(|l| l.contains(">> ")).expect("A command should be highlighted");
+    assert!(first.contains("Export calendar") && first.contains("recent"));
+    assert!(output.contains("search <team> [country]"), "Argument usage should be shown");
+}
+
+#[test]
+fn test_command_palette_shows_arguments() {
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let palette = Palette {
+        input: "league serie a".into(),
+        selected: 0,
+    };
+
+    terminal
+        .draw(|f| {
+            command_palette::render(f, Rect::new(0, 0, 80, 24), &palette, &[], &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("> league serie a"));
+    assert!(output.contains("Open league · serie a"));
+}
+
+// ── favorites_dashboard tests ────────────────────────────────────────────
+
+fn listing(teams: &str, kickoff: &str, channels: &[&str]) -> Match {
+    Match {
+        teams: teams.into(),
+        competition: "Premier League".into(),
+        date: "Sat 21 Feb 2026".into(),
+        time: "".into(),
+        kickoff: Some(kickoff.parse().unwrap()),
+        channels: channels.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_favorites_dashboard_shows_next_match_of_each_favorite() {
+    let backend = TestBackend::new(100, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let arsenal = Favorite::new("Arsenal", Country::UK);
+    let lyon = Favorite::new("Lyon", Country::FR);
+    let miami = Favorite::new("Inter Miami", Country::US);
+    let arsenal_matches = FavoriteStatus::Matches(vec![
+        listing("Arsenal v Spurs", "2026-02-14T15:00:00Z", &["Sky Sports"]),
+        listing("Chelsea v Arsenal", "2026-02-21T19:00:00Z", &["TNT Sports", "Amazon"]),
+    ]);
+    let miami_error = FavoriteStatus::Error("Team not found".into());
+    let labels = labels_at("2026-02-21T17:00:00Z");
+    let cards = [
+        FavoriteCard { favorite: &arsenal, status: Some(&arsenal_matches), labels },
+        FavoriteCard { favorite: &lyon, status: Some(&FavoriteStatus::Loading), labels },
+        FavoriteCard { favorite: &miami, status: Some(&miami_error), labels },
+    ];
+
+    terminal
+        .draw(|f| {
+            favorites_dashboard::render(f, Rect::new(0, 0, 100, 20), &cards, ListPosition::at(0), Some("Ctrl+s"), &Theme::default());
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("1. Arsenal (UK)"));
+    assert!(output.contains("Chelsea v Arsenal"), "Next match, not the one played");
+    assert!(!output.contains("Arsenal v Spurs"));
+    assert!(output.contains("19:00"));
+    assert!(output.contains("2h"), "Countdown to kickoff");
+    assert!(output.contains("TNT Sports, Amazon"));
+    assert!(output.contains("2. Lyon (FR)"));
+    assert!(output.contains("Fetching..."));
+    assert!(output.contains("Error: Team not found"));
+    assert!(output.contains(">> "), "Selected favourite is marked");
+}
+
+#[test]
+fn test_favorites_dashboard_without_upcoming_match() {
+    let backend = TestBackend::new(100, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let arsenal = Favorite::new("Arsenal", Country::UK);
+    let played = FavoriteStatus::Matches(vec![listing("Arsenal v Spurs", "2026-02-14T15:00:00Z", &[])]);
+    let cards = [FavoriteCard { favorite: &arsenal, status: Some(&played), labels: labels_at("2026-02-21T17:00:00Z") }];
+
+    terminal
+        .draw(|f| {
+            favorites_dashboard::render(f, Rect::new(0, 0, 100, 10), &cards, ListPosition::at(0), Some("Ctrl+s"), &Theme::default());
+        })
+        .unwrap();
+
+    assert!(buffer_to_string(&terminal).contains("No upcoming match listed"));
+}
+
+#[test]
+fn test_favorites_dashboard_empty_explains_how_to_add() {
+    let backend = TestBackend::new(100, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|f| {
+            favorites_dashboard::render(f, Rect::new(0, 0, 100, 10), &[], ListPosition::at(0), Some("Ctrl+s"), &Theme::default());
+        })
+        .unwrap();
+
+    assert!(buffer_to_string(&terminal).contains("No favorites yet"));
+}
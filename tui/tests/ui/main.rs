@@ -1,4 +1,23 @@
 mod components;
 mod layout;
+mod mouse;
 mod render;
+mod scroll;
 mod views;
+
+use foot_info_tui::config::Config;
+use foot_info_tui::state::AppState;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A state on a scratch config of its own, so the developer's keys and
+/// theme don't change what is drawn, and nothing is saved over them.
+fn new_state() -> AppState {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let path = std::env::temp_dir().join(format!(
+        "foot-info-ui-config-{}-{}.json",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::SeqCst)
+    ));
+    let _ = std::fs::remove_file(&path);
+    AppState::with_config(Config::at(path))
+}
@@ -0,0 +1,185 @@
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use foot_info_core::models::{LeagueStats, Match, MatchStatus, TopMatch};
+use foot_info_tui::handlers;
+use foot_info_tui::models::{InputMode, LeagueTab, ViewMode};
+use foot_info_tui::state::AppState;
+use std::time::{Duration, Instant};
+
+fn mouse(state: &mut AppState, kind: MouseEventKind, column: u16, row: u16, at: Instant) {
+    let event = MouseEvent { kind, column, row, modifiers: KeyModifiers::NONE };
+    handlers::handle_mouse_event(state, event, at);
+}
+
+fn click(state: &mut AppState, column: u16, row: u16, at: Instant) {
+    mouse(state, MouseEventKind::Down(MouseButton::Left), column, row, at);
+}
+
+fn results(count: usize) -> Vec<Match> {
+    (0..count)
+        .map(|i| Match {
+            teams: format!("Team {} v Rival", i),
+            competition: "PL".into(),
+            date: "Sat 22 Feb".into(),
+            time: "15:00".into(),
+            kickoff: None,
+            channels: vec!["Sky".into()],
+        })
+        .collect()
+}
+
+fn top_match(teams: &str, date: &str) -> TopMatch {
+    TopMatch {
+        teams: teams.into(),
+        date: date.into(),
+        time: "20:00".into(),
+        kickoff: None,
+        match_url: String::new(),
+        competition: None,
+        channels: vec![],
+        status: MatchStatus::Upcoming,
+    }
+}
+
+/// 80x24: inside the main border, the search bar takes lines 1-3, the
+/// status line 4, and the results, four lines each, start on line 5.
+fn search_state(count: usize) -> AppState {
+    let mut state = crate::new_state();
+    state.view_mode = ViewMode::Search;
+    state.matches = results(count);
+    handlers::handle_resize(&mut state, 80, 24);
+    state
+}
+
+// ── Click tests ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_click_selects_the_result_under_it() {
+    let mut state = search_state(10);
+
+    click(&mut state, 10, 14, Instant::now());
+    assert_eq!(state.selected_match_index, 2);
+    assert!(state.match_detail.is_none());
+}
+
+#[test]
+fn test_click_in_a_scrolled_list_counts_from_the_offset() {
+    let mut state = search_state(10);
+    state.selected_match_index = 9;
+    handlers::handle_resize(&mut state, 80, 24);
+    assert_eq!(state.match_offset, 6);
+
+    click(&mut state, 10, 5, Instant::now());
+    assert_eq!(state.selected_match_index, 6);
+}
+
+#[test]
+fn test_double_click_opens_the_result_details() {
+    let mut state = search_state(10);
+    let start = Instant::now();
+
+    click(&mut state, 10, 14, start);
+    click(&mut state, 10, 15, start + Duration::from_millis(200));
+    assert_eq!(state.match_detail.as_ref().map(|m| m.teams.as_str()), Some("Team 2 v Rival"));
+}
+
+#[test]
+fn test_double_click_follows_the_row_when_the_list_scrolls() {
+    let mut state = search_state(10);
+    let start = Instant::now();
+
+    click(&mut state, 10, 14, start);
+    // Scrolled a row between the clicks: the result moved up with it
+    state.match_offset = 1;
+    click(&mut state, 10, 10, start + Duration::from_millis(200));
+    assert_eq!(state.match_detail.as_ref().map(|m| m.teams.as_str()), Some("Team 2 v Rival"));
+}
+
+#[test]
+fn test_slow_or_different_clicks_are_not_a_double_click() {
+    let mut state = search_state(10);
+    let start = Instant::now();
+
+    click(&mut state, 10, 14, start);
+    click(&mut state, 10, 14, start + Duration::from_secs(1));
+    assert!(state.match_detail.is_none(), "Too slow");
+
+    click(&mut state, 10, 18, start + Duration::from_millis(1100));
+    assert!(state.match_detail.is_none(), "Another row");
+    assert_eq!(state.selected_match_index, 3);
+}
+
+#[test]
+fn test_click_on_the_search_bar_starts_typing() {
+    let mut state = search_state(3);
+    state.input_mode = InputMode::Normal;
+
+    click(&mut state, 40, 2, Instant::now());
+    assert_eq!(state.input_mode, InputMode::Insert);
+}
+
+#[test]
+fn test_clicks_wait_for_the_help_to_close() {
+    let mut state = search_state(10);
+    state.show_help = true;
+
+    click(&mut state, 10, 14, Instant::now());
+    assert_eq!(state.selected_match_index, 0);
+}
+
+#[test]
+fn test_click_switches_league_tabs() {
+    let mut state = crate::new_state();
+    state.view_mode = ViewMode::League;
+    state.league_stats = Some(LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![],
+        table: vec![],
+        top_scorers: vec![],
+        previous_page: None,
+        next_page: None,
+    });
+    handlers::handle_resize(&mut state, 80, 24);
+
+    // " Fixtures | Table | Top Scorers " inside the tabs' border
+    click(&mut state, 15, 2, Instant::now());
+    assert_eq!(state.league_tab, LeagueTab::Table);
+    click(&mut state, 25, 2, Instant::now());
+    assert_eq!(state.league_tab, LeagueTab::TopScorers);
+    click(&mut state, 3, 2, Instant::now());
+    assert_eq!(state.league_tab, LeagueTab::Fixtures);
+}
+
+#[test]
+fn test_click_selects_a_top_match_card_in_another_column() {
+    let mut state = crate::new_state();
+    state.view_mode = ViewMode::TopMatches;
+    state.top_matches = vec![
+        top_match("A - B", "Mon 20 Feb 2026"),
+        top_match("C - D", "Mon 20 Feb 2026"),
+        top_match("E - F", "Tue 21 Feb 2026"),
+        top_match("G - H", "Tue 21 Feb 2026"),
+    ];
+    handlers::handle_resize(&mut state, 100, 24);
+
+    // The second day's column starts halfway, its cards three lines each
+    // under its top border
+    click(&mut state, 70, 9, Instant::now());
+    assert_eq!(state.selected_top_match_index, 3);
+}
+
+// ── Scroll wheel tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_wheel_moves_through_the_list_and_scrolls_it() {
+    let mut state = search_state(10);
+    let now = Instant::now();
+
+    for _ in 0..5 {
+        mouse(&mut state, MouseEventKind::ScrollDown, 10, 10, now);
+    }
+    assert_eq!(state.selected_match_index, 5);
+    assert_eq!(state.match_offset, 2);
+
+    mouse(&mut state, MouseEventKind::ScrollUp, 10, 10, now);
+    assert_eq!(state.selected_match_index, 4);
+}
@@ -1,5 +1,4 @@
 use foot_info_tui::models::ViewMode;
-use foot_info_tui::state::AppState;
 use foot_info_tui::ui;
 use ratatui::backend::TestBackend;
 use ratatui::Terminal;
@@ -23,7 +22,7 @@ fn test_draw_search_mode_shows_title_and_instructions() {
     let backend = TestBackend::new(120, 30);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let state = AppState::new();
+    let state = crate::new_state();
 
     terminal
         .draw(|f| {
@@ -39,7 +38,8 @@ fn test_draw_search_mode_shows_title_and_instructions() {
     assert!(output.contains("Esc"), "Should show Esc shortcut");
     assert!(output.contains("Enter"), "Should show Enter shortcut");
     assert!(output.contains("Ctrl+t"), "Should show Ctrl+t shortcut");
-    assert!(output.contains("Ctrl+c"), "Should show Ctrl+c shortcut");
+    assert!(output.contains("Ctrl+n"), "Should show the switch country shortcut");
+    assert!(output.contains("F1"), "Should show the help shortcut");
 }
 
 #[test]
@@ -47,7 +47,7 @@ fn test_draw_search_mode_shows_country_in_title() {
     let backend = TestBackend::new(120, 30);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let state = AppState::new();
+    let state = crate::new_state();
 
     terminal
         .draw(|f| {
@@ -67,7 +67,7 @@ fn test_draw_top_matches_mode_shows_different_instructions() {
     let backend = TestBackend::new(120, 30);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.view_mode = ViewMode::TopMatches;
 
     terminal
@@ -94,7 +94,7 @@ fn test_draw_with_different_provider_shows_country() {
     let backend = TestBackend::new(120, 30);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.current_provider_index = 1; // US provider
 
     terminal
@@ -112,7 +112,7 @@ fn test_draw_fr_provider_shows_country() {
     let backend = TestBackend::new(120, 30);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.current_provider_index = 2; // FR provider
 
     terminal
@@ -130,7 +130,7 @@ fn test_draw_search_contains_search_bar() {
     let backend = TestBackend::new(120, 30);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.search_input = "Liverpool".into();
 
     terminal
@@ -0,0 +1,202 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use foot_info_core::models::{LeagueStats, Match, StandingRow};
+use foot_info_tui::handlers;
+use foot_info_tui::models::{LeagueTab, ViewMode};
+use foot_info_tui::scroll::Viewport;
+use foot_info_tui::state::AppState;
+use foot_info_tui::ui;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::Terminal;
+
+fn buffer_to_string(terminal: &Terminal<TestBackend>) -> String {
+    let buf = terminal.backend().buffer().clone();
+    let mut output = String::new();
+    for y in 0..buf.area.height {
+        for x in 0..buf.area.width {
+            output.push_str(buf[(x, y)].symbol());
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn press(state: &mut AppState, code: KeyCode) {
+    handlers::handle_key_event(state, KeyEvent::new(code, KeyModifiers::NONE));
+}
+
+/// `count` results, "Team 0 v Rival" and on.
+fn results(count: usize) -> Vec<Match> {
+    (0..count)
+        .map(|i| Match {
+            teams: format!("Team {} v Rival", i),
+            competition: "PL".into(),
+            date: "Sat 22 Feb".into(),
+            time: "15:00".into(),
+            kickoff: None,
+            channels: vec!["Sky".into()],
+        })
+        .collect()
+}
+
+fn standing(position: u8) -> StandingRow {
+    StandingRow {
+        position,
+        team: format!("Club {}", position),
+        played: 30,
+        won: 10,
+        drawn: 10,
+        lost: 10,
+        goals_for: 40,
+        goals_against: 40,
+        goal_diff: 0,
+        points: 40,
+        form: Vec::new(),
+        zone: None,
+    }
+}
+
+/// An 80x24 terminal holds four results: 18 lines below the search bar
+/// and status, four lines each.
+fn search_state(count: usize) -> AppState {
+    let mut state = crate::new_state();
+    state.view_mode = ViewMode::Search;
+    state.matches = results(count);
+    handlers::handle_resize(&mut state, 80, 24);
+    state
+}
+
+// ── Viewport tests ───────────────────────────────────────────────────────
+
+#[test]
+fn test_viewport_follow_scrolls_just_enough() {
+    let viewport = Viewport::new(Rect::new(0, 0, 10, 10), vec![2; 10]);
+
+    assert_eq!(viewport.visible(0), 5);
+    assert_eq!(viewport.follow(0, 4), 0, "Already shown");
+    assert_eq!(viewport.follow(0, 5), 1, "One row down shows it");
+    assert_eq!(viewport.follow(3, 1), 1, "Scrolls back up to it");
+    assert_eq!(viewport.follow(0, 9), 5);
+    assert_eq!(viewport.max_offset(), 5);
+}
+
+#[test]
+fn test_viewport_follow_pulls_rows_back_once_there_is_room() {
+    let viewport = Viewport::new(Rect::new(0, 0, 10, 30), vec![2; 10]);
+
+    // Scrolled to the end on a shorter screen, everything fits now
+    assert_eq!(viewport.follow(5, 9), 0);
+    assert!(viewport.fits());
+}
+
+#[test]
+fn test_viewport_page_skips_headers() {
+    let viewport = Viewport {
+        area: Rect::new(0, 0, 10, 3),
+        heights: vec![1; 6],
+        indices: vec![None, Some(0), Some(1), None, Some(2), Some(3)],
+    };
+
+    assert_eq!(viewport.page(0, 0, true), Some(2), "Lands past the header");
+    assert_eq!(viewport.page(3, 3, false), Some(1));
+    assert_eq!(viewport.page(3, 2, true), Some(3), "Stops at the last item");
+}
+
+// ── Search results scrolling tests ───────────────────────────────────────
+
+#[test]
+fn test_end_scrolls_results_to_the_last_match() {
+    let mut state = search_state(20);
+
+    press(&mut state, KeyCode::End);
+    assert_eq!(state.selected_match_index, 19);
+    assert_eq!(state.match_offset, 16);
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| ui::draw(f, &state)).unwrap();
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Team 19 v Rival"));
+    assert!(!output.contains("Team 0 v Rival"));
+
+    press(&mut state, KeyCode::Home);
+    assert_eq!((state.selected_match_index, state.match_offset), (0, 0));
+}
+
+#[test]
+fn test_page_down_moves_a_screenful() {
+    let mut state = search_state(20);
+
+    press(&mut state, KeyCode::PageDown);
+    assert_eq!(state.selected_match_index, 4);
+    assert_eq!(state.match_offset, 1, "Scrolled just enough to show it");
+
+    press(&mut state, KeyCode::PageDown);
+    press(&mut state, KeyCode::PageUp);
+    assert_eq!(state.selected_match_index, 4);
+
+    for _ in 0..10 {
+        press(&mut state, KeyCode::PageDown);
+    }
+    assert_eq!(state.selected_match_index, 19);
+}
+
+#[test]
+fn test_resize_keeps_the_selection_visible() {
+    let mut state = search_state(20);
+    handlers::handle_resize(&mut state, 80, 60);
+    state.selected_match_index = 10;
+    press(&mut state, KeyCode::Down);
+    assert_eq!(state.match_offset, 0, "Shown without scrolling");
+
+    handlers::handle_resize(&mut state, 80, 24);
+    assert_eq!(state.match_offset, 8, "Scrolled to the selection");
+
+    handlers::handle_resize(&mut state, 80, 120);
+    assert_eq!(state.match_offset, 0, "Everything fits again");
+}
+
+#[test]
+fn test_results_show_a_scrollbar_only_when_they_overflow() {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+
+    let state = search_state(3);
+    terminal.draw(|f| ui::draw(f, &state)).unwrap();
+    assert!(!buffer_to_string(&terminal).contains('█'));
+
+    let state = search_state(20);
+    terminal.draw(|f| ui::draw(f, &state)).unwrap();
+    assert!(buffer_to_string(&terminal).contains('█'));
+}
+
+// ── League scrolling tests ───────────────────────────────────────────────
+
+#[test]
+fn test_league_table_pages_and_jumps() {
+    let mut state = crate::new_state();
+    state.view_mode = ViewMode::League;
+    state.league_tab = LeagueTab::Table;
+    state.league_stats = Some(LeagueStats {
+        competition: "Premier League".into(),
+        fixtures: vec![],
+        table: (1..=30).map(standing).collect(),
+        top_scorers: vec![],
+        previous_page: None,
+        next_page: None,
+    });
+    // 22 lines inside the main border: tabs and status leave 18, and the
+    // table's border and header 14 rows
+    handlers::handle_resize(&mut state, 100, 24);
+
+    press(&mut state, KeyCode::PageDown);
+    assert_eq!(state.selected_table_index, 14);
+    assert_eq!(state.table_offset, 1);
+
+    press(&mut state, KeyCode::End);
+    assert_eq!((state.selected_table_index, state.table_offset), (29, 16));
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+    terminal.draw(|f| ui::draw(f, &state)).unwrap();
+    let output = buffer_to_string(&terminal);
+    assert!(output.contains("Club 30"));
+    assert!(!output.contains("Club 16 "));
+}
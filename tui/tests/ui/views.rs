@@ -1,6 +1,6 @@
-use foot_info_core::models::{Match, TopMatch};
-use foot_info_tui::models::ViewMode;
-use foot_info_tui::state::AppState;
+use foot_info_core::models::{Country, Match, MatchStatus, TopMatch};
+use foot_info_tui::config::Favorite;
+use foot_info_tui::models::{FavoriteStatus, ViewMode};
 use foot_info_tui::ui::views;
 use ratatui::backend::TestBackend;
 use ratatui::layout::Rect;
@@ -25,7 +25,7 @@ fn test_search_view_renders_search_bar() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.search_input = "Arsenal".into();
 
     terminal
@@ -45,7 +45,7 @@ fn test_search_view_with_loading_state() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.is_loading = true;
 
     terminal
@@ -64,7 +64,7 @@ fn test_search_view_with_error() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.error_message = Some("Team not found".into());
 
     terminal
@@ -83,12 +83,13 @@ fn test_search_view_with_matches() {
     let backend = TestBackend::new(100, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.matches = vec![Match {
         teams: "Arsenal v Chelsea".into(),
         competition: "PL".into(),
         date: "Sat 22 Feb".into(),
         time: "15:00".into(),
+        kickoff: None,
         channels: vec!["Sky".into()],
     }];
 
@@ -108,7 +109,7 @@ fn test_search_view_with_status_message() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.status_message = Some("Saved favorite: Arsenal".into());
 
     terminal
@@ -129,7 +130,7 @@ fn test_top_matches_view_renders_loading() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.view_mode = ViewMode::TopMatches;
     state.is_loading = true;
 
@@ -149,7 +150,7 @@ fn test_top_matches_view_renders_error() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.view_mode = ViewMode::TopMatches;
     state.error_message = Some("Network error".into());
 
@@ -169,13 +170,17 @@ fn test_top_matches_view_renders_matches() {
     let backend = TestBackend::new(100, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = AppState::new();
+    let mut state = crate::new_state();
     state.view_mode = ViewMode::TopMatches;
     state.top_matches = vec![TopMatch {
         teams: "Team A - Team B".into(),
         date: "Mon 20 Feb 2026".into(),
         time: "20:00".into(),
+        kickoff: None,
         match_url: "/match/1".into(),
+        competition: None,
+        channels: vec![],
+        status: MatchStatus::Upcoming,
     }];
 
     terminal
@@ -188,3 +193,30 @@ fn test_top_matches_view_renders_matches() {
     let output = buffer_to_string(&terminal);
     assert!(output.contains("Team A - Team B"));
 }
+
+// ── Dashboard view tests ─────────────────────────────────────────────────
+
+#[test]
+fn test_dashboard_view_lists_favorites_in_order() {
+    let backend = TestBackend::new(100, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let mut state = crate::new_state();
+    state.view_mode = ViewMode::Dashboard;
+    state.config.favorites = vec![Favorite::new("Lyon", Country::FR), Favorite::new("Arsenal", Country::UK)];
+    state.set_favorite_status(&state.config.favorites[1].clone(), FavoriteStatus::Error("Network error".into()));
+
+    terminal
+        .draw(|f| {
+            let area = Rect::new(0, 0, 100, 24);
+            views::dashboard::draw(f, area, &state);
+        })
+        .unwrap();
+
+    let output = buffer_to_string(&terminal);
+    let lyon = output.find("1. Lyon (FR)").expect("Lyon listed first");
+    let arsenal = output.find("2. Arsenal (UK)").expect("Arsenal listed second");
+    assert!(lyon < arsenal);
+    assert!(output.contains("Fetching..."), "Lyon hasn't been fetched yet");
+    assert!(output.contains("Error: Network error"));
+}